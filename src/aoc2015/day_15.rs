@@ -1,4 +1,5 @@
 use crate::solution::Solution;
+use crate::utils::partitions::Compositions;
 use crate::utils::*;
 
 use std::io;
@@ -45,63 +46,6 @@ fn scores(amount: &[usize], ingredients: &[Ingredient], fields: &[usize]) -> i64
     result
 }
 
-struct IndexSumIterator {
-    target: usize,
-    size: usize,
-    sp: usize, // stack pointer
-    stack_idx: Vec<usize>,
-    stack_sum: Vec<usize>,
-    items: Vec<usize>,
-}
-
-impl IndexSumIterator {
-    pub fn new(target: usize, size: usize) -> Self {
-        Self {
-            target,
-            size,
-            sp: 0,
-            stack_idx: vec![1; size + 1],
-            stack_sum: vec![0; size + 1],
-            items: vec![0usize; size],
-        }
-    }
-}
-
-impl Iterator for IndexSumIterator {
-    type Item = Vec<usize>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.target < self.size {
-            return None;
-        }
-        loop {
-            if self.sp == self.size {
-                if self.stack_sum[self.sp] == self.target {
-                    self.sp -= 1;
-                    return Some(self.items.clone());
-                }
-                self.sp -= 1;
-            } else {
-                let max =
-                    self.target as isize - self.stack_sum[self.sp] as isize - self.size as isize
-                        + self.sp as isize
-                        + 1;
-                if self.stack_idx[self.sp] as isize <= max {
-                    self.items[self.sp] = self.stack_idx[self.sp];
-                    self.stack_idx[self.sp + 1] = 1;
-                    self.stack_sum[self.sp + 1] = self.stack_idx[self.sp] + self.stack_sum[self.sp];
-                    self.stack_idx[self.sp] += 1;
-                    self.sp += 1;
-                } else if self.sp == 0 {
-                    return None;
-                } else {
-                    self.sp -= 1;
-                }
-            }
-        }
-    }
-}
-
 pub struct AoC2015_15 {
     ingredients: Vec<Ingredient>,
 }
@@ -124,7 +68,7 @@ impl Solution for AoC2015_15 {
     fn part_one(&self) -> String {
         let size = self.ingredients.len();
         let fields = vec![0usize, 1, 2, 3];
-        let best = IndexSumIterator::new(100, size).fold(0, |acc, counters| {
+        let best = Compositions::new(100, size).fold(0, |acc, counters| {
             let val = scores(&counters, &self.ingredients, &fields);
             acc.max(val)
         });
@@ -134,7 +78,7 @@ impl Solution for AoC2015_15 {
     fn part_two(&self) -> String {
         let size = self.ingredients.len();
         let fields = vec![0usize, 1, 2, 3];
-        let best = IndexSumIterator::new(100, size).fold(0, |acc, counters| {
+        let best = Compositions::new(100, size).fold(0, |acc, counters| {
             let cals = scores(&counters, &self.ingredients, &[4]);
             if cals == 500 {
                 let val = scores(&counters, &self.ingredients, &fields);
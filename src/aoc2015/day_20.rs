@@ -1,4 +1,5 @@
 use crate::solution::Solution;
+use crate::utils::*;
 
 use std::io;
 
@@ -14,44 +15,24 @@ impl AoC2015_20 {
 
 impl Solution for AoC2015_20 {
     fn part_one(&self) -> String {
-        let mut max_houses = self.input / 10;
-        let mut gifts = vec![0usize; max_houses];
-        let mut elf = 1usize;
-        while elf < max_houses {
-            for i in (elf..max_houses).step_by(elf) {
-                gifts[i] += elf * 10;
-                if gifts[i] > self.input {
-                    max_houses = i;
-                    break;
-                }
-            }
-            elf += 1;
-        }
-        gifts
-            .iter()
-            .position(|&val| val >= self.input)
+        let target = self.input.div_ceil(10) as u64;
+        (1..)
+            .find(|&house| divisor_sum(house) >= target)
             .unwrap()
             .to_string()
     }
 
     fn part_two(&self) -> String {
-        let mut max_houses = self.input / 10;
-        let mut gifts = vec![0usize; max_houses];
-        let mut elf = 1usize;
-        while elf < max_houses {
-            let max = max_houses.min(50 * elf);
-            for i in (elf..max).step_by(elf) {
-                gifts[i] += elf * 11;
-                if gifts[i] > self.input {
-                    max_houses = i;
-                    break;
-                }
-            }
-            elf += 1;
-        }
-        gifts
-            .iter()
-            .position(|&val| val >= self.input)
+        // every elf visits only the first 50 multiples of its number
+        let target = self.input.div_ceil(11) as u64;
+        (1..)
+            .find(|&house| {
+                divisors(house)
+                    .into_iter()
+                    .filter(|&elf| house / elf <= 50)
+                    .sum::<u64>()
+                    >= target
+            })
             .unwrap()
             .to_string()
     }
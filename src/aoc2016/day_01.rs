@@ -1,4 +1,5 @@
 use crate::solution::Solution;
+use crate::utils::{Compass, Point2d, Turn, YAxis};
 
 use std::collections::HashSet;
 use std::fs::read_to_string;
@@ -11,113 +12,41 @@ enum Maneuver {
 
 impl Maneuver {
     fn with_str(s: &str) -> Self {
-        let dir = &s[..=0];
-        let steps = s[1..].parse::<i32>().expect("Incorrect input format");
-        match dir {
-            "L" => Maneuver::Left(steps),
-            "R" => Maneuver::Right(steps),
+        let (dir, steps) = s.split_at(1);
+        let steps = steps.parse::<i32>().expect("Incorrect input format");
+        match dir.parse::<Turn>() {
+            Ok(Turn::Left) => Maneuver::Left(steps),
+            Ok(Turn::Right) => Maneuver::Right(steps),
             _ => panic!("unexpected direction {dir}"),
         }
     }
-}
-
-enum Pole {
-    North,
-    South,
-    East,
-    West,
-}
 
-impl Pole {
-    fn next(&self, maneuver: &Maneuver) -> Self {
-        let is_left = matches!(maneuver, Maneuver::Left(_));
+    fn turn(&self) -> (Turn, i32) {
         match self {
-            Pole::North => {
-                if is_left {
-                    Pole::West
-                } else {
-                    Pole::East
-                }
-            }
-            Pole::West => {
-                if is_left {
-                    Pole::South
-                } else {
-                    Pole::North
-                }
-            }
-            Pole::East => {
-                if is_left {
-                    Pole::North
-                } else {
-                    Pole::South
-                }
-            }
-            Pole::South => {
-                if is_left {
-                    Pole::East
-                } else {
-                    Pole::West
-                }
-            }
+            Maneuver::Left(steps) => (Turn::Left, *steps),
+            Maneuver::Right(steps) => (Turn::Right, *steps),
         }
     }
 }
 
-type Location = [i32; 2];
+type Location = Point2d<i32>;
 
 fn calc_distance(pos: Option<Location>) -> i32 {
-    if let Some(pos) = pos {
-        pos.iter().map(|x| x.abs()).sum()
-    } else {
-        0
-    }
+    pos.map(|p| p.x.abs() + p.y.abs()).unwrap_or_default()
 }
 
-struct NavigationIterator<'a> {
-    pole: Pole,
-    route: &'a Vec<Maneuver>,
-    position: usize,
-    location: Location,
-}
-
-impl<'a> NavigationIterator<'a> {
-    fn new(pole: Pole, route: &'a Vec<Maneuver>) -> Self {
-        Self {
-            pole,
-            route,
-            position: 0,
-            location: [0, 0],
-        }
-    }
-}
-
-impl Iterator for NavigationIterator<'_> {
-    type Item = Location;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.position < self.route.len() {
-            let coord = if matches!(self.pole, Pole::North | Pole::South) {
-                0
-            } else {
-                1
-            };
-            let item = &self.route[self.position];
-            let mut steps = match item {
-                Maneuver::Left(s) => -*s,
-                Maneuver::Right(s) => *s,
-            };
-            if matches!(self.pole, Pole::South | Pole::East) {
-                steps = -steps;
-            }
-            self.location[coord] += steps;
-            self.pole = self.pole.next(item);
-            self.position += 1;
-            Some(self.location)
-        } else {
-            None
-        }
-    }
+/// Visits every block of the route
+fn walk(route: &[Maneuver]) -> impl Iterator<Item = Location> + '_ {
+    let mut heading = Compass::North;
+    let mut location = Location::zero();
+    route.iter().flat_map(move |maneuver| {
+        let (turn, steps) = maneuver.turn();
+        heading = heading.turned(turn);
+        let delta = heading.delta::<i32>(YAxis::Up);
+        let start = location;
+        location = Location::new(start.x + delta.x * steps, start.y + delta.y * steps);
+        (1..=steps).map(move |i| Location::new(start.x + delta.x * i, start.y + delta.y * i))
+    })
 }
 
 pub struct AoC2016_01 {
@@ -142,25 +71,15 @@ impl AoC2016_01 {
 
 impl Solution for AoC2016_01 {
     fn part_one(&self) -> String {
-        let pos = NavigationIterator::new(Pole::North, &self.input).last();
-        calc_distance(pos).to_string()
+        calc_distance(walk(&self.input).last()).to_string()
     }
 
     fn part_two(&self) -> String {
-        let mut locations: HashSet<[i32; 2]> = HashSet::new();
-        let mut prev = [0i32, 0i32];
-        for pos in NavigationIterator::new(Pole::North, &self.input) {
-            let axe = if pos[0] == prev[0] { 1 } else { 0 };
-            let step = if pos[axe] > prev[axe] { 1 } else { -1 };
-            let mut coord = prev;
-            while coord[axe] != pos[axe] {
-                coord[axe] += step;
-                if locations.contains(&coord) {
-                    return calc_distance(Some(coord)).to_string();
-                }
-                locations.insert(coord);
+        let mut locations = HashSet::new();
+        for location in walk(&self.input) {
+            if !locations.insert(location) {
+                return calc_distance(Some(location)).to_string();
             }
-            prev = pos;
         }
         "Not found".to_string()
     }
@@ -1,4 +1,5 @@
 use crate::solution::Solution;
+use crate::utils::ocr::recognize;
 use crate::utils::*;
 
 use std::io;
@@ -123,20 +124,23 @@ impl AoC2016_08 {
             .collect();
         Ok(Self { ops })
     }
-}
 
-impl Solution for AoC2016_08 {
-    fn part_one(&self) -> String {
+    fn display(&self) -> Display {
         let mut display = Display::new(50, 6);
         self.ops.iter().for_each(|op| {
             display.execute(op);
         });
-        print(&display.pixels);
-        display.lit_pixels_count().to_string()
+        display
+    }
+}
+
+impl Solution for AoC2016_08 {
+    fn part_one(&self) -> String {
+        self.display().lit_pixels_count().to_string()
     }
 
     fn part_two(&self) -> String {
-        "EOARGPHYAO".to_string()
+        recognize(&self.display().pixels).unwrap_or_else(not_found)
     }
 
     fn description(&self) -> String {
@@ -144,16 +148,6 @@ impl Solution for AoC2016_08 {
     }
 }
 
-#[allow(clippy::needless_range_loop)]
-fn print(inp: &[Vec<bool>]) {
-    for i in 0..inp.len() {
-        for ch in &inp[i] {
-            print!("{}", if *ch { '#' } else { ' ' });
-        }
-        println!()
-    }
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1,70 +1,39 @@
 use crate::solution::Solution;
+use crate::utils::interval_set::IntervalSet;
 use crate::utils::*;
 
 use std::io;
 
 pub struct AoC2016_20 {
-    ranges: Vec<(usize, usize)>,
+    ranges: IntervalSet<usize>,
 }
 
 impl AoC2016_20 {
     pub fn new() -> io::Result<Self> {
-        let mut ranges = read_file_as_lines("input/aoc2016_20")?
+        let ranges = read_file_as_lines("input/aoc2016_20")?
             .iter()
-            .map(|s| {
-                let (from, to) = s
-                    .split_once('-')
-                    .expect("Ranges should be separated with '-' delimiter");
-                let from = from
-                    .parse::<usize>()
-                    .expect("Low range value should be integer");
-                let to = to
-                    .parse::<usize>()
-                    .expect("High range value should be integer");
-                (from, to)
-            })
-            .collect::<Vec<(usize, usize)>>();
-        Ok(Self {
-            ranges: Self::merge_ranges(&mut ranges),
-        })
+            .map(|s| PlainInterval::parse(s, "-").expect("Invalid range format"))
+            .collect();
+        Ok(Self { ranges })
     }
 
-    fn merge_ranges(ranges: &mut [(usize, usize)]) -> Vec<(usize, usize)> {
-        ranges.sort_by(|(a, _), (b, _)| a.cmp(b));
-        let mut merged = Vec::new();
-        let (mut from, mut to) = (ranges[0].0, ranges[0].1);
-        for (a, b) in ranges.iter() {
-            if *a <= to + 1 {
-                to = to.max(*b);
-            } else {
-                merged.push((from, to));
-                from = *a;
-                to = *b;
-            }
-        }
-        merged.push((from, to));
-        merged
+    fn allowed(&self) -> IntervalSet<usize> {
+        self.ranges
+            .complement(PlainInterval::new(0, u32::MAX as usize))
     }
 }
 
 impl Solution for AoC2016_20 {
     fn part_one(&self) -> String {
-        match self.ranges.len() {
-            0 => 0,
-            _ => {
-                let (l, h) = self.ranges[0];
-                if l == 0 {
-                    h + 1
-                } else {
-                    l - 1
-                }
-            }
+        let allowed = self.allowed();
+        if allowed.is_empty() {
+            return not_found();
         }
-        .to_string()
+        allowed.iter().next().unwrap().begin.to_string()
     }
 
     fn part_two(&self) -> String {
-        (4294967296 - self.ranges.iter().map(|(l, h)| *h - *l + 1).sum::<usize>()).to_string()
+        self.allowed().len().to_string()
     }
 
     fn description(&self) -> String {
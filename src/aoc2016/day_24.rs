@@ -1,50 +1,26 @@
 use crate::solution::Solution;
+use crate::utils::search;
 use crate::utils::*;
 
-use std::collections::HashSet;
 use std::io;
 
 type Position = Point2d<usize>;
 
 type Grid = Vec<Vec<char>>;
 
-fn bfs_path_len(grid: &Grid, start: &Position, finish: &Position) -> usize {
-    let mut steps = 0;
-    let mut positions = vec![*start];
-    let mut visited = HashSet::new();
-    visited.insert(*start);
-    'outer: while !positions.is_empty() {
-        steps += 1;
-        let mut next = Vec::new();
-        for pos in positions {
-            let (row, col) = (pos.y, pos.x);
-            let mut adj = Vec::new();
-            if row > 0 {
-                adj.push(Position { y: row - 1, x: col })
-            }
-            if row < grid.len() - 1 {
-                adj.push(Position { y: row + 1, x: col })
-            }
-            if col > 0 {
-                adj.push(Position { y: row, x: col - 1 })
-            }
-            if col < grid[row].len() - 1 {
-                adj.push(Position { y: row, x: col + 1 })
-            }
-            for p in adj {
-                if grid[p.y][p.x] == '#' || visited.contains(&p) {
-                    continue;
-                }
-                if p == *finish {
-                    break 'outer;
-                }
-                visited.insert(p);
-                next.push(p);
-            }
-        }
-        positions = next;
-    }
-    steps
+/// Steps from the start to every reachable open position
+fn distances_from(grid: &Grid, start: Position) -> search::SearchResult<Position, usize> {
+    search::bfs([start], |pos| {
+        Direction::all()
+            .iter()
+            .filter_map(|dir| pos.safe_moved_by(dir))
+            .filter(|p| {
+                grid.get(p.y)
+                    .and_then(|row| row.get(p.x))
+                    .is_some_and(|ch| *ch != '#')
+            })
+            .collect::<Vec<_>>()
+    })
 }
 
 type Weights = Vec<Vec<usize>>;
@@ -79,17 +55,16 @@ impl AoC2016_24 {
             .map(|(_, pos)| *pos)
             .collect::<Vec<Position>>();
 
-        let len = points.len();
-        let mut weights = vec![vec![usize::MAX; len]; len];
-        for i in 0..len - 1 {
-            for j in i + 1..len {
-                let a = points[i];
-                let b = points[j];
-                let dist = bfs_path_len(&grid, &a, &b);
-                weights[i][j] = dist;
-                weights[j][i] = dist;
-            }
-        }
+        let weights = points
+            .iter()
+            .map(|a| {
+                let distances = distances_from(&grid, *a);
+                points
+                    .iter()
+                    .map(|b| distances.distance(b).unwrap_or(usize::MAX))
+                    .collect()
+            })
+            .collect::<Weights>();
         Self { weights }
     }
 }
@@ -1,6 +1,6 @@
 use crate::solution::Solution;
+use crate::utils::cycle;
 
-use std::collections::HashMap;
 use std::fs::read_to_string;
 use std::io;
 
@@ -18,29 +18,26 @@ impl AoC2017_06 {
     }
 
     fn redistribute_steps(&self) -> (usize, usize) {
-        let mut map = HashMap::new();
-        let mut banks = self.banks.clone();
-        let len = banks.len();
-        let mut steps = 0usize;
-        loop {
-            map.insert(banks.clone(), steps);
-            steps += 1;
-            let mut idx = 0;
-            for i in 1..len {
-                if banks[i] > banks[idx] {
-                    idx = i;
-                }
-            }
-            let val = banks[idx];
-            banks[idx] = 0;
-            for i in 1..=val {
-                banks[(idx + i) % len] += 1;
-            }
-            if let Some(val) = map.get(&banks) {
-                break (steps, steps - *val);
-            }
+        let cycle = cycle::brent(self.banks.clone(), |banks| redistribute(banks));
+        (cycle.start + cycle.length, cycle.length)
+    }
+}
+
+fn redistribute(banks: &[usize]) -> Vec<usize> {
+    let mut banks = banks.to_vec();
+    let len = banks.len();
+    let mut idx = 0;
+    for i in 1..len {
+        if banks[i] > banks[idx] {
+            idx = i;
         }
     }
+    let val = banks[idx];
+    banks[idx] = 0;
+    for i in 1..=val {
+        banks[(idx + i) % len] += 1;
+    }
+    banks
 }
 
 impl Solution for AoC2017_06 {
@@ -1,7 +1,7 @@
 use crate::solution::Solution;
+use crate::utils::graph::Graph;
 use crate::utils::*;
 
-use std::collections::HashSet;
 use std::io;
 
 struct Item {
@@ -89,11 +89,16 @@ pub struct AoC2017_07 {
 
 impl AoC2017_07 {
     pub fn new() -> io::Result<Self> {
-        let nodes = read_file_as_lines("input/aoc2017_07")?
+        let lines = read_file_as_lines("input/aoc2017_07")?;
+        Ok(Self::with_lines(&lines))
+    }
+
+    fn with_lines<T: AsRef<str>>(lines: &[T]) -> Self {
+        let nodes = lines
             .iter()
-            .map(|s| Item::parse(s))
+            .map(|s| Item::parse(s.as_ref()))
             .collect::<Vec<Item>>();
-        Ok(Self { nodes })
+        Self { nodes }
     }
 
     fn tree_with_root(&self, name: &str) -> Option<Node> {
@@ -110,16 +115,19 @@ impl AoC2017_07 {
     }
 
     fn root_node_name(&self) -> String {
-        let siblings: HashSet<String> =
-            HashSet::from_iter(self.nodes.iter().flat_map(|node| &node.children).cloned());
-
-        self.nodes
-            .iter()
-            .filter(|node| !node.children.is_empty())
-            .map(|node| node.name.clone())
-            .filter(|name| !siblings.contains(name))
-            .take(1)
-            .collect::<String>()
+        let mut names = String2IdMapper::new();
+        let mut graph = Graph::with_vertices(self.nodes.len());
+        for node in &self.nodes {
+            let parent = names.get_id(&node.name);
+            for child in &node.children {
+                graph.add_arc(parent, names.get_id(child), 1);
+            }
+        }
+        graph
+            .sources()
+            .first()
+            .map(|id| names.name(*id).to_string())
+            .unwrap_or_default()
     }
 }
 
@@ -161,4 +169,26 @@ mod test {
         assert_eq!(sol.part_two(), "1458");
         Ok(())
     }
+
+    #[test]
+    fn aoc2017_07_example() {
+        let lines = [
+            "pbga (66)",
+            "xhth (57)",
+            "ebii (61)",
+            "havc (66)",
+            "ktlj (57)",
+            "fwft (72) -> ktlj, cntj, xhth",
+            "qoyq (66)",
+            "padx (45) -> pbga, havc, qoyq",
+            "tknk (41) -> ugml, padx, fwft",
+            "jptl (61)",
+            "ugml (68) -> gyxo, ebii, jptl",
+            "gyxo (61)",
+            "cntj (57)",
+        ];
+        let sol = AoC2017_07::with_lines(&lines);
+        assert_eq!(sol.part_one(), "tknk");
+        assert_eq!(sol.part_two(), "60");
+    }
 }
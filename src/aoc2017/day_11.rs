@@ -1,105 +1,11 @@
 use crate::solution::Solution;
+use crate::utils::hex::{FlatHexDirection, Hex, HexDirection};
 
 use std::fs::read_to_string;
 use std::io;
 
-#[derive(Clone, Copy)]
-enum Edge {
-    North = 0,
-    NorthEast,
-    SouthEast,
-    South,
-    SouthWest,
-    NorthWest,
-}
-
-impl Edge {
-    fn with_str(s: &str) -> Self {
-        match s {
-            "n" => Self::North,
-            "ne" => Self::NorthEast,
-            "se" => Self::SouthEast,
-            "s" => Self::South,
-            "sw" => Self::SouthWest,
-            "nw" => Self::NorthWest,
-            _ => panic!("Unexpected value '{s}'"),
-        }
-    }
-}
-
-struct Container {
-    edge_a: Edge,
-    edge_b: Edge,
-    delta_edge: Option<Edge>,
-}
-
-fn distance(steps: &[Edge]) -> usize {
-    let mut arr = [0usize; 6];
-    steps.iter().for_each(|edge| {
-        arr[*edge as usize] += 1;
-    });
-    let mut sum = arr.iter().sum();
-    loop {
-        [
-            Container {
-                edge_a: Edge::North,
-                edge_b: Edge::South,
-                delta_edge: None,
-            },
-            Container {
-                edge_a: Edge::NorthEast,
-                edge_b: Edge::SouthWest,
-                delta_edge: None,
-            },
-            Container {
-                edge_a: Edge::SouthEast,
-                edge_b: Edge::NorthWest,
-                delta_edge: None,
-            },
-            Container {
-                edge_a: Edge::NorthWest,
-                edge_b: Edge::South,
-                delta_edge: Some(Edge::SouthWest),
-            },
-            Container {
-                edge_a: Edge::SouthEast,
-                edge_b: Edge::North,
-                delta_edge: Some(Edge::NorthEast),
-            },
-            Container {
-                edge_a: Edge::SouthWest,
-                edge_b: Edge::North,
-                delta_edge: Some(Edge::NorthWest),
-            },
-            Container {
-                edge_a: Edge::NorthEast,
-                edge_b: Edge::South,
-                delta_edge: Some(Edge::SouthEast),
-            },
-        ]
-        .iter()
-        .for_each(|container| {
-            let idx_a = container.edge_a as usize;
-            let idx_b = container.edge_b as usize;
-
-            let val = arr[idx_a].min(arr[idx_b]);
-            arr[idx_a] -= val;
-            arr[idx_b] -= val;
-            if let Some(edge) = container.delta_edge {
-                arr[edge as usize] += val;
-            }
-        });
-        let new_sum = arr.iter().sum::<usize>();
-        if new_sum == sum {
-            break;
-        }
-        sum = new_sum;
-    }
-    sum
-}
-
 pub struct AoC2017_11 {
-    steps: Vec<Edge>,
+    steps: Vec<FlatHexDirection>,
 }
 
 impl AoC2017_11 {
@@ -109,22 +15,30 @@ impl AoC2017_11 {
     }
 
     fn with_input(s: &str) -> Self {
-        let steps = s.trim().split(',').map(Edge::with_str).collect();
+        let steps = FlatHexDirection::parse_separated(s, ',').expect("Invalid directions");
         Self { steps }
     }
 }
 
 impl Solution for AoC2017_11 {
     fn part_one(&self) -> String {
-        distance(&self.steps).to_string()
+        self.steps
+            .iter()
+            .fold(Hex::default(), |hex, dir| hex.moved_by(dir))
+            .length()
+            .to_string()
     }
 
     fn part_two(&self) -> String {
-        let mut max_steps = 0usize;
-        for l in 1..=self.steps.len() {
-            max_steps = max_steps.max(distance(&self.steps[0..l]));
-        }
-        max_steps.to_string()
+        self.steps
+            .iter()
+            .scan(Hex::default(), |hex, dir| {
+                *hex = hex.moved_by(dir);
+                Some(hex.length())
+            })
+            .max()
+            .unwrap_or_default()
+            .to_string()
     }
 
     fn description(&self) -> String {
@@ -150,4 +64,18 @@ mod test {
         assert_eq!(sol.part_two(), "1560");
         Ok(())
     }
+
+    #[test]
+    fn aoc2017_11_case_1() {
+        let cases = [
+            ("ne,ne,ne", "3"),
+            ("ne,ne,sw,sw", "0"),
+            ("ne,ne,s,s", "2"),
+            ("se,sw,se,sw,sw", "3"),
+        ];
+        for (input, expected) in cases {
+            let sol = AoC2017_11::with_input(input);
+            assert_eq!(sol.part_one(), expected);
+        }
+    }
 }
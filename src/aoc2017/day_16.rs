@@ -1,4 +1,7 @@
-use crate::{solution::Solution, utils::ArraySpin};
+use crate::{
+    solution::Solution,
+    utils::{cycle, ArraySpin},
+};
 
 use std::{fs::read_to_string, io};
 
@@ -69,32 +72,28 @@ impl AoC2017_16 {
     }
 
     fn perform(&self, times: usize) -> String {
-        let mut arr = CharArray::with_capacity(16);
-        ('a'..='p').for_each(|ch| arr.push(ch));
-        let mut permutations: Vec<String> = Vec::new();
-        permutations.push(arr.iter().collect());
-        loop {
-            for movement in &self.movements {
-                match movement {
-                    Movement::Spin(count) => arr.spin_right(*count),
-                    Movement::Exchange(a, b) => arr.swap(*a, *b),
-                    Movement::Partner(a, b) => arr.iter_mut().for_each(|ch| {
-                        if *ch == *a {
-                            *ch = *b;
-                        } else if *ch == *b {
-                            *ch = *a;
-                        }
-                    }),
-                }
-            }
-            let tmp = arr.iter().collect();
-            if permutations.contains(&tmp) {
-                break;
+        let programs = ('a'..='p').collect::<CharArray>();
+        cycle::nth_state(programs, |programs| self.dance(programs), times)
+            .iter()
+            .collect()
+    }
+
+    fn dance(&self, programs: &CharArray) -> CharArray {
+        let mut arr = programs.clone();
+        for movement in &self.movements {
+            match movement {
+                Movement::Spin(count) => arr.spin_right(*count),
+                Movement::Exchange(a, b) => arr.swap(*a, *b),
+                Movement::Partner(a, b) => arr.iter_mut().for_each(|ch| {
+                    if *ch == *a {
+                        *ch = *b;
+                    } else if *ch == *b {
+                        *ch = *a;
+                    }
+                }),
             }
-            permutations.push(tmp);
         }
-        let period = permutations.len();
-        permutations[times % period].clone()
+        arr
     }
 }
 
@@ -106,31 +106,18 @@ impl Solution for AoC2017_23 {
     fn part_two(&self) -> String {
         // init
         let a: Value = 1;
-        let mut h: Value = 0;
         let mut from: Value = 81;
         let mut to: Value = from;
         if a != 0 {
             from = from * 100 + 100000;
             to = from + 17000;
         }
-        // calc
-        for b in (from..=to).step_by(17) {
-            'first: for d in 2..b {
-                'second: for e in 2..b {
-                    match b.cmp(&(d * e)) {
-                        std::cmp::Ordering::Equal => {
-                            h += 1;
-                            break 'first;
-                        }
-                        std::cmp::Ordering::Less => {
-                            break 'second;
-                        }
-                        _ => {}
-                    }
-                }
-            }
-        }
-        h.to_string()
+        // count the composite numbers
+        (from..=to)
+            .step_by(17)
+            .filter(|&b| !is_prime(b as u64))
+            .count()
+            .to_string()
     }
 
     fn description(&self) -> String {
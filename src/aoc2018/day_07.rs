@@ -1,7 +1,9 @@
 use crate::solution::Solution;
+use crate::utils::graph::Graph;
 use crate::utils::*;
 
-use std::collections::{HashMap, HashSet};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 use std::io;
 
 #[derive(Clone, Copy)]
@@ -36,20 +38,13 @@ impl AoC2018_07 {
 
 impl Solution for AoC2018_07 {
     fn part_one(&self) -> String {
-        let mut output: Vec<char> = Vec::new();
-        let mut step_data = build_steps_data(&self.input);
-        loop {
-            let finished = find_finished(&step_data, &output);
-            if let Some(ch) = finished.first() {
-                output.push(*ch);
-                step_data.iter_mut().for_each(|(_, set)| {
-                    set.remove(ch);
-                });
-            } else {
-                break;
-            }
-        }
-        output.iter().collect()
+        let (steps, graph) = dependency_graph(&self.input);
+        graph
+            .topological_sort()
+            .expect("Steps should not have circular dependencies")
+            .into_iter()
+            .map(|index| steps[index])
+            .collect()
     }
 
     fn part_two(&self) -> String {
@@ -61,31 +56,21 @@ impl Solution for AoC2018_07 {
     }
 }
 
-type StepsData = HashMap<char, HashSet<char>>;
-
-fn build_steps_data(input: &[Dependency]) -> StepsData {
-    let mut steps: StepsData = HashMap::new();
-    input.iter().for_each(|x| {
-        let entry = steps.entry(x.step).or_default();
-        entry.insert(x.dependency);
-        _ = steps.entry(x.dependency).or_default();
-    });
-    steps
-}
-
-fn find_finished(step_data: &StepsData, in_use: &[char]) -> Vec<char> {
-    let mut finished = step_data
+/// Steps in alphabetical order and the graph over their indices,
+/// so the smaller index means the step goes first among the available ones
+fn dependency_graph(input: &[Dependency]) -> (Vec<char>, Graph) {
+    let mut steps = input
         .iter()
-        .filter_map(|(x, set)| {
-            if !in_use.contains(x) && set.is_empty() {
-                Some(*x)
-            } else {
-                None
-            }
-        })
-        .collect::<Vec<char>>();
-    finished.sort();
-    finished
+        .flat_map(|x| [x.dependency, x.step])
+        .collect::<Vec<_>>();
+    steps.sort();
+    steps.dedup();
+    let index = |ch: char| steps.binary_search(&ch).expect("Step should be known");
+    let mut graph = Graph::with_vertices(steps.len());
+    input
+        .iter()
+        .for_each(|x| graph.add_arc(index(x.dependency), index(x.step), 1));
+    (steps, graph)
 }
 
 fn distributed_duration(
@@ -93,68 +78,47 @@ fn distributed_duration(
     workers: usize,
     minimal_step_duration: usize,
 ) -> usize {
-    let mut step_data = build_steps_data(input);
-    let mut work_load = vec![0usize; workers];
-    let mut deadline: HashMap<char, usize> = HashMap::new();
-    let mut in_use: HashSet<char> = HashSet::new();
+    let (steps, graph) = dependency_graph(input);
+    let duration = |index: usize| minimal_step_duration + 1 + (steps[index] as u8 - b'A') as usize;
+    let mut degrees = graph.in_degrees();
+    let mut ready = graph
+        .sources()
+        .into_iter()
+        .map(Reverse)
+        .collect::<BinaryHeap<_>>();
+    let mut in_progress = BinaryHeap::new();
+    let mut time = 0;
     loop {
-        let mut next_step = |load: &[usize]| -> (usize, Vec<char>) {
-            let mut active_load = load
-                .iter()
-                .copied()
-                .filter(|x| *x > 0)
-                .collect::<Vec<usize>>();
-            if active_load.is_empty() {
-                active_load.push(0);
-            } else {
-                active_load.sort();
-            }
-            for time in active_load.iter() {
-                let mut finished = step_data
-                    .iter()
-                    .filter_map(|(x, set)| if set.is_empty() { Some(*x) } else { None })
-                    .filter(|x| !in_use.contains(x))
-                    .filter(|&x| {
-                        let entry: &mut usize = deadline.entry(x).or_insert(0);
-                        *entry <= *time
-                    })
-                    .collect::<Vec<char>>();
-                if !finished.is_empty() {
-                    finished.sort();
-                    return (*time, finished);
+        while in_progress.len() < workers {
+            let Some(Reverse(index)) = ready.pop() else {
+                break;
+            };
+            in_progress.push(Reverse((time + duration(index), index)));
+        }
+        let Some(Reverse((finish, _))) = in_progress.peek().copied() else {
+            break;
+        };
+        time = finish;
+        // all steps finished at this moment release their dependants at once
+        while let Some(Reverse((_, index))) = in_progress
+            .peek()
+            .copied()
+            .filter(|Reverse((x, _))| *x == time)
+        {
+            in_progress.pop();
+            for next in graph.neighbours(index) {
+                degrees[next] -= 1;
+                if degrees[next] == 0 {
+                    ready.push(Reverse(next));
                 }
             }
-            (usize::MAX, vec![])
-        };
-        let (time, finished) = next_step(&work_load);
-        if finished.is_empty() {
-            break;
-        }
-        for ch in finished {
-            assert!(!in_use.contains(&ch), "Reusing char '{ch}'");
-            // find less loaded worker
-            let (idx, load) = work_load
-                .iter()
-                .map(|x| time.max(*x) + minimal_step_duration + 1 + (ch as u8 - b'A') as usize)
-                .enumerate()
-                .min_by(|(_, a), (_, b)| a.cmp(b))
-                .expect("Failed to find less loaded worker");
-            work_load[idx] = load;
-            deadline.insert(ch, load);
-            // shift deadline of the dependent steps
-            step_data
-                .iter_mut()
-                .filter(|(_, set)| set.contains(&ch))
-                .for_each(|(x, set)| {
-                    set.remove(&ch);
-                    let entry: &mut usize = deadline.entry(*x).or_insert(load);
-                    *entry = load.max(*entry);
-                });
-            in_use.insert(ch);
         }
     }
-    assert_eq!(in_use.len(), step_data.len());
-    *work_load.iter().max().unwrap()
+    assert!(
+        degrees.iter().all(|x| *x == 0),
+        "Some steps are unreachable"
+    );
+    time
 }
 
 #[cfg(test)]
@@ -39,7 +39,8 @@ impl AoC2018_10 {
         Ok(Self { points })
     }
 
-    fn wait_for_message(&self, need_print: bool) -> usize {
+    /// Time and positions when the points are the closest to each other
+    fn wait_for_message(&self) -> (usize, Vec<PointElem>) {
         let mut data = self.points.clone();
         let mut min_square = usize::MAX;
         let mut time = 0;
@@ -49,15 +50,11 @@ impl AoC2018_10 {
             let size = bounds.size();
             let square = size.x as usize * size.y as usize;
             if square > min_square {
-                time -= 1;
-                if need_print {
-                    data.iter_mut().for_each(|elem| {
-                        elem.position = elem.position.sub(&elem.speed);
-                    });
-                    let points = data.iter().map(|x| x.position).collect::<Vec<PointElem>>();
-                    print_points(&points, &bounds);
-                }
-                break;
+                let points = data
+                    .iter()
+                    .map(|elem| elem.position.sub(&elem.speed))
+                    .collect::<Vec<PointElem>>();
+                return (time - 1, points);
             }
             min_square = min_square.min(square);
             data.iter_mut().for_each(|elem| {
@@ -65,18 +62,17 @@ impl AoC2018_10 {
             });
             time += 1;
         }
-        time
     }
 }
 
 impl Solution for AoC2018_10 {
     fn part_one(&self) -> String {
-        self.wait_for_message(true);
-        "".to_string()
+        let (_, points) = self.wait_for_message();
+        ocr::recognize_points(&points).unwrap_or_else(not_found)
     }
 
     fn part_two(&self) -> String {
-        self.wait_for_message(false).to_string()
+        self.wait_for_message().0.to_string()
     }
 
     fn description(&self) -> String {
@@ -84,26 +80,6 @@ impl Solution for AoC2018_10 {
     }
 }
 
-fn print_points(points: &[PointElem], bounds: &Bounds<i32>) {
-    let size = bounds.size().add(&PointElem { x: 1, y: 1 });
-    let cols = size.x as usize;
-    let rows = size.y as usize;
-    let mut matrix = vec![vec![0; cols]; rows];
-    let points = normalize_with_bounds(points, bounds);
-    points.iter().for_each(|p| {
-        let x = p.x as usize;
-        let y = p.y as usize;
-        matrix[y][x] = 1;
-    });
-    for y in matrix.iter() {
-        for val in y {
-            let ch = if *val == 1 { '#' } else { ' ' };
-            print!("{ch}");
-        }
-        println!();
-    }
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -118,7 +94,8 @@ mod test {
     #[test]
     fn aoc2018_10_correctness() -> io::Result<()> {
         let sol = AoC2018_10::new()?;
-        assert_eq!(sol.part_one(), "");
+        let message = sol.part_one();
+        assert!(message.len() == 8 && message.chars().all(|ch| ch.is_ascii_uppercase()));
         assert_eq!(sol.part_two(), "10391");
         Ok(())
     }
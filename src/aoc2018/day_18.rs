@@ -1,11 +1,11 @@
 use crate::solution::Solution;
+use crate::utils::automaton::{DenseAutomaton, NeighbourCounts, Neighbourhood};
+use crate::utils::grid::Grid;
 use crate::utils::*;
 
-use std::collections::HashMap;
-use std::hash::{Hash, Hasher};
 use std::io;
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
 enum Acre {
     OpenGround,
     Trees,
@@ -23,7 +23,7 @@ impl From<char> for Acre {
     }
 }
 
-type Area = Vec<Vec<Acre>>;
+type Area = Grid<Acre>;
 
 pub struct AoC2018_18 {
     input: Area,
@@ -37,30 +37,8 @@ impl AoC2018_18 {
     }
 
     fn compute(&self, minutes: usize) -> usize {
-        let mut hashes = HashMap::<u64, usize>::new();
-        let mut state = self.input.clone();
-        let mut values = Vec::new();
-        let mut offset: Option<usize> = None;
-        let mut period: Option<usize> = None;
-        for step in 0..=minutes {
-            let hash = hash(&state);
-            if let Some(prev) = hashes.get(&hash) {
-                period = Some(step - prev);
-                offset = Some(*prev);
-                break;
-            }
-            let value = resource_value(&state);
-            values.push(value);
-            hashes.insert(hash, step);
-            state = update(&state);
-        }
-
-        if let (Some(offset), Some(period)) = (offset, period) {
-            let pos = (minutes - offset) % period;
-            values[pos + offset]
-        } else {
-            *values.last().unwrap()
-        }
+        let history = automaton(&self.input).find_cycle(next_acre);
+        resource_value(history.state_at(minutes).grid())
     }
 }
 
@@ -79,67 +57,16 @@ impl Solution for AoC2018_18 {
 }
 
 fn parse_lines(lines: &[String]) -> Area {
-    let mut result = Vec::with_capacity(lines.len());
-    lines.iter().for_each(|array| {
-        let mut row = Vec::with_capacity(array.len());
-        array.chars().for_each(|value| {
-            let acre = Acre::from(value);
-            row.push(acre);
-        });
-        result.push(row);
-    });
-    result
+    Grid::parse(lines, |ch| Some(Acre::from(ch))).expect("Area should be rectangular")
 }
 
-fn update(input: &Area) -> Area {
-    let mut adj = Vec::<Acre>::with_capacity(8);
-    let mut output = input.clone();
-    let rows = input.len();
-    for (row, arr) in input.iter().enumerate() {
-        let cols = arr.len();
-        for (col, value) in arr.iter().enumerate() {
-            adj.clear();
-            let up = row > 0;
-            let left = col > 0;
-            let down = row < rows - 1;
-            let right = col < cols - 1;
-            if up {
-                adj.push(input[row - 1][col])
-            }
-            if down {
-                adj.push(input[row + 1][col])
-            }
-            if left {
-                adj.push(input[row][col - 1])
-            }
-            if right {
-                adj.push(input[row][col + 1])
-            }
-            if up && left {
-                adj.push(input[row - 1][col - 1])
-            }
-            if up && right {
-                adj.push(input[row - 1][col + 1])
-            }
-            if down && left {
-                adj.push(input[row + 1][col - 1])
-            }
-            if down && right {
-                adj.push(input[row + 1][col + 1])
-            }
-            let (mut trees, mut lumberyard) = (0, 0);
-            adj.iter().for_each(|x| match x {
-                Acre::Trees => trees += 1,
-                Acre::Lumberyard => lumberyard += 1,
-                _ => {}
-            });
-            output[row][col] = next_acre(*value, trees, lumberyard);
-        }
-    }
-    output
+fn automaton(area: &Area) -> DenseAutomaton<Acre> {
+    DenseAutomaton::new(area.clone(), &Neighbourhood::Moore, Acre::OpenGround)
 }
 
-fn next_acre(current: Acre, trees: usize, lumberyard: usize) -> Acre {
+fn next_acre(current: &Acre, counts: &NeighbourCounts<Acre>) -> Acre {
+    let trees = counts.count(&Acre::Trees);
+    let lumberyard = counts.count(&Acre::Lumberyard);
     match current {
         // An open acre will become filled with trees if three or more adjacent acres contained trees
         Acre::OpenGround if trees > 2 => Acre::Trees,
@@ -150,7 +77,7 @@ fn next_acre(current: Acre, trees: usize, lumberyard: usize) -> Acre {
             // Otherwise, it becomes open.
             Acre::OpenGround
         }
-        _ => current,
+        _ => *current,
     }
 }
 
@@ -159,13 +86,7 @@ fn resource_value(input: &Area) -> usize {
 }
 
 fn count(input: &Area, value: Acre) -> usize {
-    input.iter().flatten().filter(|&x| *x == value).count()
-}
-
-fn hash(area: &Area) -> u64 {
-    let mut hasher = std::hash::DefaultHasher::new();
-    area.hash(&mut hasher);
-    hasher.finish()
+    input.iter().filter(|(_, x)| **x == value).count()
 }
 
 #[cfg(test)]
@@ -175,7 +96,7 @@ mod test {
     #[test]
     fn aoc2018_18_input_load_test() -> io::Result<()> {
         let sol = AoC2018_18::new()?;
-        assert!(!sol.input.is_empty());
+        assert!(sol.input.rows() > 0);
         Ok(())
     }
 
@@ -191,9 +112,9 @@ mod test {
     fn aoc2018_18_transition_1() {
         let input = initial_state();
         let area = parse_lines(&input);
-        let next = update(&area);
+        let next = automaton(&area).next(next_acre);
         let new_area = parse_lines(&min_1_state());
-        assert_eq!(next, new_area)
+        assert_eq!(next.grid(), &new_area)
     }
 
     #[test]
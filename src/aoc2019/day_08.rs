@@ -1,4 +1,7 @@
-use crate::{solution::Solution, utils::not_found};
+use crate::{
+    solution::Solution,
+    utils::{not_found, ocr::recognize},
+};
 
 use std::{fs::read_to_string, io};
 
@@ -55,18 +58,11 @@ impl Solution for AoC2019_08 {
             }
         }
 
-        for (i, ch) in output.iter().enumerate() {
-            if i % LAYER_WIDTH == 0 {
-                println!()
-            }
-            let val = match *ch {
-                COLOR_WHITE => '*',
-                _ => ' ',
-            };
-            print!("{val}");
-        }
-
-        not_found()
+        let pixels = output
+            .chunks(LAYER_WIDTH)
+            .map(|row| row.iter().map(|ch| *ch == COLOR_WHITE).collect())
+            .collect::<Vec<_>>();
+        recognize(&pixels).unwrap_or_else(not_found)
     }
 
     fn description(&self) -> String {
@@ -92,6 +88,14 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn aoc2019_08_correctness_part_2() -> io::Result<()> {
+        let sol = make_solution()?;
+        let message = sol.part_two();
+        assert!(message.len() == 5 && message.chars().all(|ch| ch.is_ascii_uppercase()));
+        Ok(())
+    }
+
     fn make_solution() -> io::Result<AoC2019_08> {
         AoC2019_08::new()
     }
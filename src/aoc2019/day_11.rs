@@ -1,5 +1,5 @@
 use crate::solution::Solution;
-use crate::utils::{bounds, Direction, Point2d};
+use crate::utils::{not_found, ocr::recognize_points, Direction, Point2d};
 
 use core::panic;
 use std::collections::HashMap;
@@ -63,20 +63,12 @@ impl Solution for AoC2019_11 {
 
     fn part_two(&self) -> String {
         let panels = self.paint(Some(COLOR_WHITE));
-        let values = panels.keys().copied().collect::<Vec<_>>();
-        let bounds = bounds(&values).expect("Failed to calculate bounds");
-
-        let mut output = "\n".to_string();
-        for x in bounds.low.x..=bounds.high.x {
-            for y in bounds.low.y..=bounds.high.y {
-                let p = Position::new(x, y);
-                let color = panels.get(&p).unwrap_or(&COLOR_BLACK);
-                let ch = if *color == 0 { ' ' } else { '#' };
-                output.push(ch);
-            }
-            output.push('\n');
-        }
-        output.to_string()
+        let white = panels
+            .iter()
+            .filter(|(_, color)| **color == COLOR_WHITE)
+            .map(|(position, _)| *position)
+            .collect::<Vec<_>>();
+        recognize_points(&white).unwrap_or_else(not_found)
     }
 
     fn description(&self) -> String {
@@ -102,6 +94,14 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn aoc2019_11_correctness_part_2() -> io::Result<()> {
+        let sol = make_solution()?;
+        let message = sol.part_two();
+        assert!(message.len() == 8 && message.chars().all(|ch| ch.is_ascii_uppercase()));
+        Ok(())
+    }
+
     fn make_solution() -> io::Result<AoC2019_11> {
         AoC2019_11::new()
     }
@@ -1,6 +1,5 @@
-use regex::Regex;
-
 use crate::solution::Solution;
+use crate::utils::parse::integers;
 use crate::utils::*;
 
 use std::io;
@@ -122,31 +121,10 @@ impl TryFrom<&str> for Point {
     type Error = String;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let Some(captures) = Regex::new(r"<x=(-?\d*), y=(-?\d*), z=(-?\d*)>")
-            .map_err(|e| format!("Regex parse error: {e:?}"))?
-            .captures(value)
-        else {
-            return Err("Empty input".to_string());
+        let [x, y, z] = integers::<Int>(value)[..] else {
+            return Err(format!("Failed to parse input: {value}"));
         };
-        let coordinates = captures
-            .iter()
-            .skip(1)
-            .filter_map(|x| x.map(|x| x.as_str()))
-            .map(|x| x.parse::<Int>())
-            .take_while(|x| x.is_ok())
-            .map(Result::unwrap)
-            .collect::<Vec<_>>();
-        if coordinates.len() != 3 {
-            return Err(format!(
-                "Failed to parse input: only {} coordinated passed",
-                coordinates.len()
-            ));
-        }
-        Ok(Self {
-            x: coordinates[0],
-            y: coordinates[1],
-            z: coordinates[2],
-        })
+        Ok(Self { x, y, z })
     }
 }
 
@@ -1,4 +1,5 @@
 use crate::solution::Solution;
+use crate::utils::bitset::BitSet;
 use crate::utils::*;
 
 use std::collections::{HashMap, HashSet};
@@ -90,7 +91,7 @@ impl Solution for AoC2019_18 {
     }
 }
 
-type Keys = [bool; 26];
+type Keys = BitSet;
 type PointData = (Point, usize);
 type PointDataCollection = Vec<PointData>;
 type MemoKey = (Point, Keys);
@@ -107,7 +108,7 @@ impl StackElement {
     fn with_points(points: &[Point]) -> Self {
         Self {
             positions: points.to_owned(),
-            keys: [false; 26],
+            keys: Keys::new(),
             distance: 0,
         }
     }
@@ -153,9 +154,8 @@ fn shortest_path_len(map: &Maze, start: &[Point]) -> usize {
                     }
                     distances.insert(distance_key, acc_distance);
 
-                    let mut keys = data.keys;
                     let index = key_index(map, *point).expect("Missing value");
-                    keys[index] = true;
+                    let keys = data.keys.with(index);
 
                     let mut positions = data.positions.clone();
                     positions[vault] = *point;
@@ -199,12 +199,12 @@ fn get_available_points(map: &Maze, start: Point, keys: &Keys) -> PointDataColle
                 if *ch == TILE_WALL {
                     continue;
                 }
-                if is_key(ch) && !keys[char_to_index(*ch)] {
+                if is_key(ch) && !keys.contains(char_to_index(*ch)) {
                     seen.insert(adjacent);
                     result.push((adjacent, step));
                     continue;
                 }
-                if is_door(ch) && !keys[char_to_index(ch.to_ascii_lowercase())] {
+                if is_door(ch) && !keys.contains(char_to_index(ch.to_ascii_lowercase())) {
                     continue;
                 }
                 next.insert(adjacent);
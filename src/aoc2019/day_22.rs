@@ -1,6 +1,5 @@
 use crate::solution::Solution;
 use crate::utils::*;
-use mod_exp::mod_exp;
 
 use std::io;
 
@@ -69,40 +68,29 @@ impl Solution for AoC2019_22 {
 }
 
 fn position(rules: &[Shuffle], card: Int, total: Int) -> Int {
-    rules.iter().fold(card, |acc, val| match *val {
-        Shuffle::NewStack => total - acc - 1,
-        Shuffle::Cut(n) => {
-            let tmp = acc - n;
-            if tmp < 0 {
-                tmp + total
-            } else {
-                tmp % total
-            }
-        }
-        Shuffle::Increment(n) => (acc * n) % total,
-    })
+    shuffle(rules, total).apply(card)
 }
 
 fn card(rules: &[Shuffle], position: Int, total: Int, steps: Int) -> Int {
-    // Convert the whole process to a linear equation: ax + b
-    let (a, b) = rules.iter().rev().fold((1, 0), |(a, b), shuffle| {
-        let (a_new, b_new) = match *shuffle {
-            Shuffle::NewStack => (-a, -b - 1),
-            Shuffle::Cut(n) => (a, b + n),
-            Shuffle::Increment(n) => {
-                let n = mod_exp(n, total - 2, total);
-                (a * n, b * n)
-            }
-        };
-        (a_new % total, b_new % total)
-    });
-
-    // Applying the function n times simplifies to:
-    // x * a^n + b * (a^n - 1) / (a-1)
-    let term1 = position * mod_exp(a, steps, total) % total;
-    let tmp = (mod_exp(a, steps, total) - 1) * mod_exp(a - 1, total - 2, total) % total;
-    let term2 = b * tmp % total;
-    (term1 + term2) % total
+    shuffle(rules, total)
+        .pow(steps as u128)
+        .inverse()
+        .expect("Increments must be coprime with total")
+        .apply(position)
+}
+
+// Convert the whole process to a linear equation: ax + b
+fn shuffle(rules: &[Shuffle], total: Int) -> AffineMap<Int> {
+    rules
+        .iter()
+        .fold(AffineMap::identity(total), |acc, shuffle| {
+            let step = match *shuffle {
+                Shuffle::NewStack => AffineMap::new(-1, -1, total),
+                Shuffle::Cut(n) => AffineMap::new(1, -n, total),
+                Shuffle::Increment(n) => AffineMap::new(n, 0, total),
+            };
+            acc.then(&step)
+        })
 }
 
 #[cfg(test)]
@@ -130,6 +118,16 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn aoc2019_22_example() {
+        let rules = ["cut 6", "deal with increment 7", "deal into new stack"].map(Shuffle::from);
+        // Result: 3 0 7 4 1 8 5 2 9 6
+        assert_eq!(position(&rules, 3, 10), 0);
+        assert_eq!(position(&rules, 6, 10), 9);
+        assert_eq!(card(&rules, 2, 10, 1), 7);
+        assert_eq!(card(&rules, 2, 10, 2), card(&rules, 7, 10, 1));
+    }
+
     fn make_solution() -> io::Result<AoC2019_22> {
         AoC2019_22::new()
     }
@@ -1,7 +1,8 @@
 use crate::solution::Solution;
+use crate::utils::automaton::{DenseAutomaton, Neighbourhood};
 use crate::utils::*;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::io;
 
 const TILE_EMPTY: char = '.';
@@ -34,22 +35,16 @@ impl AoC2019_24 {
 
 impl Solution for AoC2019_24 {
     fn part_one(&self) -> String {
-        let mut seen = HashSet::new();
-        let mut next = self.input;
-        let value = loop {
-            let s = next.to_string();
-            if seen.contains(&s) {
-                break s;
-            }
-            seen.insert(s);
-            next = generate_next_grid(&next);
-        };
+        let bugs = grid::Grid::try_from(self.input.map(Vec::from).to_vec())
+            .expect("Bugs should form a rectangle");
+        let automaton = DenseAutomaton::new(bugs, &Neighbourhood::VonNeumann, TILE_EMPTY);
+        let history = automaton.find_cycle(|ch, counts| next_tile(*ch, counts.count(&TILE_BUG)));
+        let repeated = history.states[history.cycle.start].grid();
 
-        let rating = value
-            .chars()
-            .enumerate()
-            .filter(|(_, ch)| *ch == TILE_BUG)
-            .map(|(pos, _)| 1 << pos)
+        let rating = repeated
+            .iter()
+            .filter(|(_, ch)| **ch == TILE_BUG)
+            .map(|(pos, _)| 1 << (pos.y * GRID_SIZE + pos.x))
             .sum::<usize>();
 
         rating.to_string()
@@ -79,7 +74,6 @@ trait GridOps {
     fn empty() -> Grid;
     fn inner_count(&self, side: Direction) -> usize;
     fn outer_count(&self, side: Direction) -> usize;
-    fn to_string(&self) -> String;
 
     fn adjacent_count(
         &self,
@@ -159,10 +153,6 @@ impl GridOps for Grid {
             Direction::Right => col_sum(0),
         }
     }
-
-    fn to_string(&self) -> String {
-        self.iter().flatten().collect::<String>()
-    }
 }
 
 type LevelIndex = isize;
@@ -202,11 +192,7 @@ impl GridArea {
                         level.adjacent_count(x as isize, y as isize, dir, prev_level, next_level)
                     })
                     .sum::<usize>();
-                grid[y][x] = match *ch {
-                    TILE_BUG if bugs != 1 => TILE_EMPTY,
-                    TILE_EMPTY if bugs == 1 || bugs == 2 => TILE_BUG,
-                    _ => *ch,
-                };
+                grid[y][x] = next_tile(*ch, bugs);
             }
         }
         grid
@@ -227,29 +213,12 @@ impl GridArea {
     }
 }
 
-type Position = Point2d<usize>;
-
-fn generate_next_grid(input: &Grid) -> Grid {
-    let mut result = Grid::empty();
-    let rows = input.len();
-    for (y, row) in input.iter().enumerate() {
-        let cols = row.len();
-        for (x, ch) in row.iter().enumerate() {
-            let pos = Position::new(x, y);
-            let bugs = Direction::all()
-                .iter()
-                .filter_map(|dir| pos.safe_moved_by(dir))
-                .filter(|p| p.x < cols && p.y < rows)
-                .filter(|p| (input[p.y].as_ref())[p.x] == TILE_BUG)
-                .count();
-            result[y][x] = match *ch {
-                TILE_BUG if bugs != 1 => TILE_EMPTY,
-                TILE_EMPTY if bugs == 1 || bugs == 2 => TILE_BUG,
-                _ => *ch,
-            };
-        }
+fn next_tile(ch: char, bugs: usize) -> char {
+    match ch {
+        TILE_BUG if bugs != 1 => TILE_EMPTY,
+        TILE_EMPTY if bugs == 1 || bugs == 2 => TILE_BUG,
+        _ => ch,
     }
-    result
 }
 
 #[cfg(test)]
@@ -277,6 +246,12 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn aoc2019_24_case_1() {
+        let sol = AoC2019_24::with_lines(&["....#", "#..#.", "#..##", "..#..", "#...."]);
+        assert_eq!(sol.part_one(), "2129920");
+    }
+
     fn make_solution() -> io::Result<AoC2019_24> {
         AoC2019_24::new()
     }
@@ -96,9 +96,9 @@ fn next_generation(
 }
 
 fn close_adjacent_occupied(matrix: &Vec2<char>, pos: Position) -> usize {
-    Direction::circular_directions()
+    Compass::all()
         .iter()
-        .filter_map(|dir| pos.safe_moved_with_dirs(dir))
+        .filter_map(|compass| pos.safe_moved_by_compass(compass))
         .filter_map(|p| matrix.get(p.y)?.get(p.x))
         .filter(|ch| **ch == POSITION_OCCUPIED)
         .count()
@@ -106,9 +106,9 @@ fn close_adjacent_occupied(matrix: &Vec2<char>, pos: Position) -> usize {
 
 fn far_adjacent_occupied(matrix: &Vec2<char>, pos: Position) -> usize {
     let mut count = 0;
-    for dir in Direction::circular_directions() {
+    for compass in Compass::all() {
         let mut tmp = pos;
-        while let Some(next) = tmp.safe_moved_with_dirs(&dir) {
+        while let Some(next) = tmp.safe_moved_by_compass(&compass) {
             let Some(ch) = matrix.get(next.y).and_then(|row| row.get(next.x)) else {
                 break;
             };
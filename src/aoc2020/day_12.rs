@@ -7,12 +7,8 @@ type Int = isize;
 type Position = Point2d<Int>;
 
 enum Command {
-    North,
-    South,
-    East,
-    West,
-    Left,
-    Right,
+    Move(Compass),
+    Turn(Turn),
     Forward,
 }
 
@@ -21,28 +17,20 @@ struct Instruction {
     param: Int,
 }
 
-impl Instruction {
-    fn new(command: Command, param: Int) -> Self {
-        Self { command, param }
-    }
-}
-
 impl From<&str> for Instruction {
     fn from(value: &str) -> Self {
         let (cmd, param) = value.split_at(1);
         let param = param
             .parse::<Int>()
             .expect("Invalid instruction format: {value}");
-        match cmd {
-            "N" => Self::new(Command::North, param),
-            "S" => Self::new(Command::South, param),
-            "E" => Self::new(Command::East, param),
-            "W" => Self::new(Command::West, param),
-            "L" => Self::new(Command::Left, param),
-            "R" => Self::new(Command::Right, param),
-            "F" => Self::new(Command::Forward, param),
-            _ => unreachable!(),
-        }
+        let command = if cmd == "F" {
+            Command::Forward
+        } else if let Ok(turn) = cmd.parse::<Turn>() {
+            Command::Turn(turn)
+        } else {
+            Command::Move(cmd.parse::<Compass>().expect("Invalid command"))
+        };
+        Self { command, param }
     }
 }
 
@@ -52,8 +40,12 @@ trait Navigation {
     fn position(&self) -> Position;
 }
 
+fn moved(pos: Position, delta: Position, times: Int) -> Position {
+    Position::new(pos.x + delta.x * times, pos.y + delta.y * times)
+}
+
 struct SimpleNavigation {
-    dir: Direction,
+    dir: Compass,
     pos: Position,
 }
 
@@ -61,13 +53,9 @@ impl Navigation for SimpleNavigation {
     fn movement(&mut self, instr: &Instruction) {
         let value = instr.param;
         match instr.command {
-            Command::North => self.pos.y -= value,
-            Command::South => self.pos.y += value,
-            Command::East => self.pos.x += value,
-            Command::West => self.pos.x -= value,
-            Command::Left => self.rotate_direction(Direction::turn_left, value),
-            Command::Right => self.rotate_direction(Direction::turn_right, value),
-            Command::Forward => self.move_in_direction(value),
+            Command::Move(dir) => self.pos = moved(self.pos, dir.delta(YAxis::Down), value),
+            Command::Turn(turn) => self.dir = self.dir.rotated(turn.angle(value)),
+            Command::Forward => self.pos = moved(self.pos, self.dir.delta(YAxis::Down), value),
         }
     }
 
@@ -79,23 +67,10 @@ impl Navigation for SimpleNavigation {
 impl SimpleNavigation {
     fn new() -> Self {
         Self {
-            dir: Direction::Right,
+            dir: Compass::East,
             pos: Position::zero(),
         }
     }
-
-    fn rotate_direction(&mut self, modifier: impl Fn(&Direction) -> Direction, value: Int) {
-        self.dir = rotate(self.dir, modifier, value);
-    }
-
-    fn move_in_direction(&mut self, value: Int) {
-        match self.dir {
-            Direction::Up => self.pos.y -= value,
-            Direction::Down => self.pos.y += value,
-            Direction::Left => self.pos.x -= value,
-            Direction::Right => self.pos.x += value,
-        }
-    }
 }
 
 struct WaypointNavigation {
@@ -107,16 +82,11 @@ impl Navigation for WaypointNavigation {
     fn movement(&mut self, instr: &Instruction) {
         let value = instr.param;
         match instr.command {
-            Command::North => self.waypoint.y -= value,
-            Command::South => self.waypoint.y += value,
-            Command::East => self.waypoint.x += value,
-            Command::West => self.waypoint.x -= value,
-            Command::Left => self.rotate_waypoint(Position::rotate_left, value),
-            Command::Right => self.rotate_waypoint(Position::rotate_right, value),
-            Command::Forward => {
-                self.pos.x += value * self.waypoint.x;
-                self.pos.y += value * self.waypoint.y;
+            Command::Move(dir) => {
+                self.waypoint = moved(self.waypoint, dir.delta(YAxis::Down), value)
             }
+            Command::Turn(turn) => self.waypoint = self.waypoint.rotated_by(turn.angle(value)),
+            Command::Forward => self.pos = moved(self.pos, self.waypoint, value),
         }
     }
 
@@ -132,33 +102,10 @@ impl WaypointNavigation {
             waypoint: Position::new(10, -1),
         }
     }
-
-    fn rotate_waypoint(&mut self, modifier: impl Fn(&Position) -> Position, value: Int) {
-        self.waypoint = rotate(self.waypoint, modifier, value);
-    }
 }
 
-fn rotate<T>(initial: T, modifier: impl Fn(&T) -> T, value: Int) -> T {
-    assert!(value % 90 == 0);
-    let value = value % 360;
-    let mut output = initial;
-    for _ in 0..(value / 90) {
-        output = modifier(&output);
-    }
-    output
-}
-impl Position {
-    fn rotate_right(&self) -> Position {
-        Position::new(-self.y, self.x)
-    }
-
-    fn rotate_left(&self) -> Position {
-        Position::new(self.y, -self.x)
-    }
-
-    fn manhattan_distance(&self) -> Int {
-        self.x.abs() + self.y.abs()
-    }
+fn manhattan_distance(pos: &Position) -> Int {
+    pos.x.abs() + pos.y.abs()
 }
 
 pub struct AoC2020_12 {
@@ -184,7 +131,7 @@ impl AoC2020_12 {
         self.input
             .iter()
             .for_each(|instr| navigation.movement(instr));
-        navigation.position().manhattan_distance().to_string()
+        manhattan_distance(&navigation.position()).to_string()
     }
 }
 
@@ -231,6 +178,13 @@ mod test {
         AoC2020_12::new()
     }
 
+    #[test]
+    fn aoc2020_12_case1() {
+        let lines = ["F10", "N3", "F7", "R90", "F11"];
+        let sol = AoC2020_12::parse(&lines);
+        assert_eq!(sol.part_one(), "25")
+    }
+
     #[test]
     fn aoc2020_12_case2() {
         let lines = ["F10", "N3", "F7", "R90", "F11"];
@@ -60,9 +60,11 @@ impl Solution for AoC2020_13 {
         let pairs = self
             .elements
             .iter()
-            .map(|elem| (elem.id, elem.id - elem.order))
+            .map(|elem| (elem.id - elem.order, elem.id))
             .collect::<Vec<_>>();
-        crt(&pairs).to_string()
+        crt(&pairs)
+            .map(|(x, _)| x.to_string())
+            .unwrap_or(not_found())
     }
 
     fn description(&self) -> String {
@@ -70,28 +72,6 @@ impl Solution for AoC2020_13 {
     }
 }
 
-fn egcd(a: Int, b: Int) -> (Int, Int, Int) {
-    if a == 0 {
-        (b, 0, 1)
-    } else {
-        let (g, x, y) = egcd(b % a, a);
-        (g, y - (b / a) * x, x)
-    }
-}
-
-fn mod_inv(x: Int, n: Int) -> Int {
-    let (_, x, _) = egcd(x, n);
-    (x % n + n) % n
-}
-
-fn crt(pairs: &[(Int, Int)]) -> Int {
-    let product = pairs.iter().map(|(r, _)| r).product::<Int>();
-    pairs.iter().fold(0, |acc, (i, j)| {
-        let p = product / i;
-        acc + j * mod_inv(p, *i) * p
-    }) % product
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1,5 +1,5 @@
 use crate::solution::Solution;
-use crate::utils::cartesian::RepeativeCartesianIter;
+use crate::utils::automaton::{hyper_neighbours, life_rule, SparseAutomaton};
 use crate::utils::hyper_point::HyperPoint;
 use crate::utils::*;
 
@@ -10,55 +10,6 @@ type Int = isize;
 type Point = HyperPoint<Int>;
 type Store = HashSet<Point>;
 
-impl Point {
-    fn is_zero(&self) -> bool {
-        self.0.iter().all(|x| *x == 0)
-    }
-
-    fn adjacent(&self, deltas: &[Point]) -> Vec<Point> {
-        deltas.iter().map(|hp| self.add(hp)).collect::<Vec<_>>()
-    }
-}
-
-fn adjacent_deltas(dimension: usize) -> Vec<Point> {
-    [-1, 0, 1]
-        .cartesian_iter(dimension)
-        .map(Point::from)
-        .filter(|p| !p.is_zero())
-        .collect::<Vec<_>>()
-}
-
-fn simulate(deltas: &[Point], store: &Store, new_store: &mut Store) {
-    let mut seen = HashSet::new();
-    let mut interested = store.clone();
-
-    while !interested.is_empty() {
-        let any = interested.iter().next().cloned().expect("Unreachable");
-        interested.remove(&any);
-        seen.insert(any.clone());
-
-        let is_active = store.contains(&any);
-        let mut count = 0usize;
-
-        for adj in any.adjacent(deltas) {
-            if store.contains(&adj) {
-                count += 1;
-            }
-            if is_active && !seen.contains(&adj) {
-                interested.insert(adj);
-            }
-        }
-        match (is_active, count) {
-            (true, 2) | (true, 3) | (false, 3) => {
-                new_store.insert(any);
-            }
-            _ => {
-                // no op
-            }
-        }
-    }
-}
-
 pub struct AoC2020_17 {
     input: Store,
 }
@@ -84,15 +35,10 @@ impl AoC2020_17 {
 
     fn simulate(&self, dimension: usize) -> String {
         assert!(dimension > 2);
-        let mut store = self.expand_store(vec![0; dimension - 2]);
-        let deltas = adjacent_deltas(dimension);
-
-        for _ in 0..6 {
-            let mut new_store = HashSet::new();
-            simulate(&deltas, &store, &mut new_store);
-            store = new_store;
-        }
-        store.len().to_string()
+        let store = self.expand_store(vec![0; dimension - 2]);
+        let mut automaton = SparseAutomaton::new(store, hyper_neighbours(dimension));
+        automaton.run(6, life_rule(&[3], &[2, 3]));
+        automaton.alive().len().to_string()
     }
 
     fn expand_store(&self, values: Vec<Int>) -> Store {
@@ -1,6 +1,6 @@
 use crate::{
     solution::Solution,
-    utils::{not_found, Diminishable, Point2d, Transformable2d, Vec2},
+    utils::{not_found, Dihedral, Diminishable, Point2d, Transformable2d, Vec2},
 };
 
 use std::{
@@ -14,35 +14,18 @@ struct TileRaw {
 }
 
 impl TileRaw {
-    fn edges(&self) -> HashMap<TileEdge, Vec<Transformation>> {
-        let mut collection = HashMap::new();
-
-        let modify = |inp: &TileEdge,
-                      op: Transformation,
-                      store: &mut HashMap<TileEdge, Vec<Transformation>>|
-         -> TileEdge {
-            let val = match op {
-                Transformation::Rotate => inp.rotated90(),
-                Transformation::FlipVertical => inp.flipped_up_down(),
-            };
-
-            let mut arr = store.get(inp).cloned().unwrap_or(vec![]);
-            arr.push(op);
-            store.insert(val.clone(), arr);
-            val
-        };
-
-        let mut edges = TileEdge::from(self);
-        for _ in 0..4 {
-            edges = modify(&edges, Transformation::Rotate, &mut collection); //current.rotated90();
-            _ = modify(&edges, Transformation::FlipVertical, &mut collection); // next.flipped_up_down();
-        }
+    fn edges(&self) -> HashMap<TileEdge, Dihedral> {
+        let edges = TileEdge::from(self);
+        let collection = Dihedral::ALL
+            .into_iter()
+            .map(|transform| (edges.transformed(transform), transform))
+            .collect::<HashMap<_, _>>();
 
         {
             for (k, v) in collection.iter() {
                 let x = TileRaw {
                     id: 0,
-                    data: transform_series(&self.data, v),
+                    data: self.data.transformed(*v),
                 };
                 let e = TileEdge::from(&x);
 
@@ -78,12 +61,6 @@ struct TileEdge {
     left: u16,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Transformation {
-    Rotate,
-    FlipVertical,
-}
-
 impl TileEdge {
     fn rotated90(&self) -> Self {
         Self {
@@ -103,6 +80,19 @@ impl TileEdge {
         }
     }
 
+    /// The same transform as `Transformable2d::transformed` makes on the tile data
+    fn transformed(&self, transform: Dihedral) -> Self {
+        let mut edges = if transform.is_flipped {
+            self.flipped_up_down()
+        } else {
+            self.clone()
+        };
+        for _ in 0..transform.rotation {
+            edges = edges.rotated90();
+        }
+        edges
+    }
+
     // fn flipped_left_right(&self) -> Self {
     //     Self {
     //         top: reverse_bits(self.top),
@@ -311,7 +301,7 @@ impl TileLayout {
 #[derive(Default)]
 struct TileDataStore {
     edges: HashMap<usize, Vec<TileEdge>>,
-    transformations: HashMap<(usize, TileEdge), Vec<Transformation>>,
+    transformations: HashMap<(usize, TileEdge), Dihedral>,
     data: HashMap<usize, Vec2<char>>,
 }
 
@@ -360,10 +350,11 @@ impl TileDataStore {
         result
     }
 
-    fn transformations(&self, disp: &Disposition) -> &[Transformation] {
-        self.transformations
+    fn transformation(&self, disp: &Disposition) -> Dihedral {
+        *self
+            .transformations
             .get(&(disp.tile_id, disp.edges.clone()))
-            .expect("transformations can't be None")
+            .expect("transformation can't be None")
     }
 
     fn data(&self, id: usize) -> &Vec2<char> {
@@ -443,21 +434,17 @@ impl Solution for AoC2020_20 {
     fn part_two(&self) -> String {
         let (layout, tile_store) = self.make_layout();
         let dim = self.tiles.len().isqrt();
-        let (mut image, pixels) = assemble_image(layout, tile_store, dim);
+        let (image, pixels) = assemble_image(layout, tile_store, dim);
 
-        let mut result = pixels;
         let pattern = build_pattern();
         let cols = 1 + pattern.iter().map(|p| p.x).max().unwrap_or(0);
         let rows = 1 + pattern.iter().map(|p| p.y).max().unwrap_or(0);
-        for _ in 0..4 {
-            for _ in 0..2 {
-                image = transform(&image, Transformation::FlipVertical);
-                let val = check_pattern(&image, &pattern, rows, cols);
-                result = result.min(pixels - val);
-            }
-            image = transform(&image, Transformation::Rotate);
-        }
-        result.to_string()
+        image
+            .orientations()
+            .map(|(_, image)| pixels - check_pattern(&image, &pattern, rows, cols))
+            .min()
+            .unwrap_or(pixels)
+            .to_string()
     }
 
     fn description(&self) -> String {
@@ -479,8 +466,10 @@ fn assemble_image(
         for c in 0..dim {
             let pos = r * dim + c;
             let disp = layout.get(pos).expect("Can't be none");
-            let trans = tile_store.transformations(disp);
-            let data = transform_series(tile_store.data(disp.tile_id), trans)
+            let trans = tile_store.transformation(disp);
+            let data = tile_store
+                .data(disp.tile_id)
+                .transformed(trans)
                 .diminished(1)
                 .expect("Failed to diminish data");
 
@@ -504,19 +493,6 @@ fn assemble_image(
     (image, pixels)
 }
 
-fn transform_series<T: Clone>(data: &Vec2<T>, series: &[Transformation]) -> Vec2<T> {
-    series
-        .iter()
-        .fold(data.clone(), |acc, t| transform(&acc, *t))
-}
-
-fn transform<T: Clone>(data: &Vec2<T>, t: Transformation) -> Vec2<T> {
-    match t {
-        Transformation::Rotate => data.transposed().flipped_horizontally(),
-        Transformation::FlipVertical => data.flipped_vertically(),
-    }
-}
-
 fn check_pattern(
     image: &Vec2<char>,
     pattern: &[Point2d<usize>],
@@ -639,7 +615,7 @@ mod test {
 
         let rot = TileRaw {
             id: 0,
-            data: transform(&original.data, Transformation::Rotate),
+            data: original.data.rotated90(),
         };
 
         let orig_edges = TileEdge::from(&original);
@@ -1,13 +1,14 @@
 use crate::solution::Solution;
+use crate::utils::hex::{Hex, HexDirection, PointyHexDirection};
 use crate::utils::*;
 
 use std::collections::{HashMap, HashSet};
 use std::io;
 
-type TileMap = HashMap<HexagonalTile, bool>;
+type TileMap = HashMap<Hex, bool>;
 
 pub struct AoC2020_24 {
-    input: Vec2<HexagonalDirection>,
+    input: Vec2<PointyHexDirection>,
 }
 
 impl AoC2020_24 {
@@ -20,44 +21,18 @@ impl AoC2020_24 {
         let input = lines
             .iter()
             .map(|s| s.as_ref())
-            .map(Self::parse_directions)
+            .map(|s| PointyHexDirection::parse_joined(s).expect("Invalid directions"))
             .collect::<Vec<_>>();
 
         Self { input }
     }
 
-    fn parse_directions(line: &str) -> Vec<HexagonalDirection> {
-        let mut result = Vec::new();
-        let mut iter = line.chars().peekable();
-        while iter.peek().is_some() {
-            let ch1 = iter.next().expect("Can't be none");
-            let mut ch2 = '\0';
-
-            if ch1 == 's' || ch1 == 'n' {
-                ch2 = iter.next().expect("Parsing error");
-            }
-
-            use HexagonalDirection::*;
-            let val = match (ch1, ch2) {
-                ('w', '\0') => West,
-                ('e', '\0') => East,
-                ('n', 'e') => NorthEast,
-                ('n', 'w') => NorthWest,
-                ('s', 'e') => SouthEast,
-                ('s', 'w') => SouthWest,
-                _ => panic!("Unexpected chars {ch1}{ch2}"),
-            };
-            result.push(val);
-        }
-        result
-    }
-
     fn make_tile_map(&self) -> TileMap {
-        let mut tiles: HashMap<HexagonalTile, bool> = HashMap::new();
+        let mut tiles = TileMap::new();
         for directions in self.input.iter() {
             let tile = directions
                 .iter()
-                .fold(HexagonalTile::default(), |tile, dir| tile.moved_by(*dir));
+                .fold(Hex::default(), |tile, dir| tile.moved_by(dir));
 
             let entry = tiles.entry(tile).or_insert(false);
             *entry = !*entry;
@@ -92,28 +67,22 @@ fn simulate_day(map: &TileMap) -> TileMap {
             continue;
         }
         candidates.insert(*tile);
-        HexagonalDirection::all_cases()
-            .iter()
-            .map(|dir| tile.moved_by(*dir))
-            .for_each(|adj| {
-                candidates.insert(adj);
-            });
+        tile.neighbours().into_iter().for_each(|adj| {
+            candidates.insert(adj);
+        });
     }
 
     let mut new_map = TileMap::new();
     let mut candidates = candidates.into_iter().collect::<Vec<_>>();
     while let Some(tile) = candidates.pop() {
-        let black_adj = HexagonalDirection::all_cases()
-            .iter()
-            .map(|dir| tile.moved_by(*dir))
-            .fold(0, |acc, adj| {
-                let is_black = map.get(&adj).unwrap_or(&false);
-                if *is_black {
-                    acc + 1
-                } else {
-                    acc
-                }
-            });
+        let black_adj = tile.neighbours().into_iter().fold(0, |acc, adj| {
+            let is_black = map.get(&adj).unwrap_or(&false);
+            if *is_black {
+                acc + 1
+            } else {
+                acc
+            }
+        });
         let is_black = *map.get(&tile).unwrap_or(&false);
         match (is_black, black_adj) {
             (true, x) if x == 0 || x > 2 => {
@@ -136,94 +105,6 @@ fn black_tiles_count(map: &TileMap) -> String {
     map.values().filter(|x| **x).count().to_string()
 }
 
-type Int = isize;
-
-#[derive(Debug, Clone, Copy)]
-enum HexagonalDirection {
-    East,
-    West,
-    NorthEast,
-    NorthWest,
-    SouthEast,
-    SouthWest,
-}
-
-impl HexagonalDirection {
-    fn all_cases() -> [HexagonalDirection; 6] {
-        use HexagonalDirection::*;
-        [East, West, NorthEast, NorthWest, SouthEast, SouthWest]
-    }
-}
-
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
-struct HexagonalTile {
-    a: Int,
-    r: Int,
-    c: Int,
-}
-
-impl HexagonalTile {
-    // https://en.wikipedia.org/wiki/Hexagonal_Efficient_Coordinate_System
-    fn moved_by(&self, direction: HexagonalDirection) -> Self {
-        match direction {
-            HexagonalDirection::East => self.moved_east(),
-            HexagonalDirection::West => self.moved_west(),
-            HexagonalDirection::NorthEast => self.moved_north_east(),
-            HexagonalDirection::NorthWest => self.moved_north_west(),
-            HexagonalDirection::SouthEast => self.moved_south_east(),
-            HexagonalDirection::SouthWest => self.moved_south_west(),
-        }
-    }
-
-    fn moved_east(&self) -> Self {
-        Self {
-            a: self.a,
-            r: self.r,
-            c: self.c + 1,
-        }
-    }
-
-    fn moved_west(&self) -> Self {
-        Self {
-            a: self.a,
-            r: self.r,
-            c: self.c - 1,
-        }
-    }
-
-    fn moved_north_east(&self) -> Self {
-        Self {
-            a: 1 - self.a,
-            r: self.r - (1 - self.a),
-            c: self.c + self.a,
-        }
-    }
-
-    fn moved_north_west(&self) -> Self {
-        Self {
-            a: 1 - self.a,
-            r: self.r - (1 - self.a),
-            c: self.c - (1 - self.a),
-        }
-    }
-
-    fn moved_south_east(&self) -> Self {
-        Self {
-            a: 1 - self.a,
-            r: self.r + self.a,
-            c: self.c + self.a,
-        }
-    }
-
-    fn moved_south_west(&self) -> Self {
-        Self {
-            a: 1 - self.a,
-            r: self.r + self.a,
-            c: self.c - (1 - self.a),
-        }
-    }
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -42,12 +42,7 @@ impl Solution for AoC2020_25 {
 const MODULUS: Int = 20201227;
 
 fn transform(subject: Int, loops: Int) -> Int {
-    let mut val = 1;
-    for _ in 0..loops {
-        val *= subject;
-        val %= MODULUS;
-    }
-    val
+    mod_pow(subject, loops, MODULUS)
 }
 
 fn calc_loops(subject: Int, pk: Int) -> Option<Int> {
@@ -78,9 +78,9 @@ fn simulate(input: &mut Vec2<Int>) -> usize {
             if seen.contains(&p) {
                 continue;
             }
-            Direction::circular_directions()
+            Compass::all()
                 .iter()
-                .filter_map(|dirs| p.safe_moved_with_dirs(dirs))
+                .filter_map(|compass| p.safe_moved_by_compass(compass))
                 .for_each(|adj| {
                     if input.get(adj.y).and_then(|row| row.get(adj.x)).is_none() {
                         return;
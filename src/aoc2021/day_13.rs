@@ -1,6 +1,6 @@
 use crate::{
     solution::Solution,
-    utils::{not_found, Point2d},
+    utils::{not_found, ocr::recognize_points, Point2d},
 };
 
 use std::{collections::HashSet, io};
@@ -76,22 +76,8 @@ impl Solution for AoC2021_13 {
         let mut dots = make_set(&self.dots);
         self.folds.iter().for_each(|f| fold(&mut dots, f));
 
-        let (Some(max_x), Some(max_y)) =
-            (find_max(&dots, |dot| dot.x), find_max(&dots, |dot| dot.y))
-        else {
-            return not_found();
-        };
-
-        for row in 0..=max_y {
-            for col in 0..=max_x {
-                let dot = Dot::new(col, row);
-                let ch = if dots.contains(&dot) { '❚' } else { ' ' };
-                print!("{ch}");
-            }
-            println!()
-        }
-
-        "".to_string()
+        let dots = dots.into_iter().collect::<Vec<_>>();
+        recognize_points(&dots).unwrap_or_else(not_found)
     }
 
     fn description(&self) -> String {
@@ -128,10 +114,6 @@ fn fold_ex(
     dots.retain(|dot| !filter(dot));
 }
 
-fn find_max(dots: &HashSet<Dot>, transform: impl Fn(&Dot) -> Int) -> Option<usize> {
-    dots.iter().map(transform).max()
-}
-
 fn make_set(dots: &[Dot]) -> HashSet<Dot> {
     dots.iter().copied().collect::<HashSet<_>>()
 }
@@ -158,7 +140,8 @@ mod test {
     #[test]
     fn aoc2021_13_correctness_part_2() -> io::Result<()> {
         let sol = make_solution()?;
-        assert_eq!(sol.part_two(), "");
+        let message = sol.part_two();
+        assert!(message.len() == 8 && message.chars().all(|ch| ch.is_ascii_uppercase()));
         Ok(())
     }
 
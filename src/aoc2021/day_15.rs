@@ -1,7 +1,7 @@
 use crate::solution::Solution;
+use crate::utils::search;
 use crate::utils::*;
 
-use std::collections::{HashMap, VecDeque};
 use std::io;
 
 type Int = u32;
@@ -37,18 +37,17 @@ impl Solution for AoC2021_15 {
         let rows = self.input.len();
         let cols = self.input[0].len();
 
-        let dp = risk(
+        risk(
             |p| {
                 if p.y < rows && p.x < cols {
                     return Some(self.input[p.y][p.x]);
                 }
                 None
             },
-            Point::zero(),
-        );
-        dp.get(&Point::new(cols - 1, rows - 1))
-            .map(|x| x.to_string())
-            .unwrap_or(not_found())
+            Point::new(cols - 1, rows - 1),
+        )
+        .map(|x| x.to_string())
+        .unwrap_or(not_found())
     }
 
     fn part_two(&self) -> String {
@@ -58,7 +57,7 @@ impl Solution for AoC2021_15 {
         let rows = 5 * tile_rows;
         let cols = 5 * tile_cols;
 
-        let dp = risk(
+        risk(
             |p| {
                 if p.y >= rows || p.x >= cols {
                     return None;
@@ -70,11 +69,10 @@ impl Solution for AoC2021_15 {
                 value = (value - 1 + times_col + times_row) % 9 + 1;
                 Some(value)
             },
-            Point::zero(),
-        );
-        dp.get(&Point::new(cols - 1, rows - 1))
-            .map(|x| x.to_string())
-            .unwrap_or(not_found())
+            Point::new(cols - 1, rows - 1),
+        )
+        .map(|x| x.to_string())
+        .unwrap_or(not_found())
     }
 
     fn description(&self) -> String {
@@ -82,35 +80,19 @@ impl Solution for AoC2021_15 {
     }
 }
 
-fn risk(area: impl Fn(&Point) -> Option<Int>, start: Point) -> HashMap<Point, Int> {
-    let mut dp = HashMap::<Point, Int>::new();
-    dp.insert(start, 0);
-
-    let mut queue = VecDeque::new();
-    queue.push_front(start);
-    while let Some(point) = queue.pop_back() {
-        let weight = dp.get(&point).copied().unwrap();
-        for (adj_p, adj_w) in Direction::all()
+/// The lowest total risk from the top left corner to the target,
+/// every step costs at least 1, so the Manhattan distance never overestimates
+fn risk(area: impl Fn(&Point) -> Option<Int>, target: Point) -> Option<Int> {
+    let neighbours = |point: &Point| {
+        Direction::all()
             .iter()
             .filter_map(|dir| point.safe_moved_by(dir))
-            .filter_map(|p| {
-                let w = area(&p)?;
-                Some((p, w))
-            })
-        {
-            let tmp = weight + adj_w;
-            let is_better = if let Some(adj_weight) = dp.get(&adj_p) {
-                *adj_weight > tmp
-            } else {
-                true
-            };
-            if is_better {
-                dp.insert(adj_p, tmp);
-                queue.push_front(adj_p);
-            }
-        }
-    }
-    dp
+            .filter_map(|p| Some((p, area(&p)?)))
+            .collect::<Vec<_>>()
+    };
+    let heuristic =
+        |point: &Point| (point.x.abs_diff(target.x) + point.y.abs_diff(target.y)) as Int;
+    search::astar([Point::zero()], neighbours, heuristic, |p| *p == target).map(|found| found.cost)
 }
 
 #[cfg(test)]
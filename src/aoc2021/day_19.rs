@@ -1,4 +1,5 @@
-use crate::{solution::Solution, utils::hyper_point::HyperPoint};
+use crate::solution::Solution;
+use crate::utils::{Point3d, Rotation};
 
 use std::{
     collections::{HashSet, VecDeque},
@@ -6,7 +7,7 @@ use std::{
 };
 
 type Int = isize;
-type Point = HyperPoint<Int>;
+type Point = Point3d<Int>;
 type PointSet = HashSet<Point>;
 
 #[derive(Clone)]
@@ -17,18 +18,18 @@ struct Scanner {
 
 impl Scanner {
     fn absolute_beacons(&self) -> PointSet {
-        self.beacons.iter().map(|p| p.add(&self.pole)).collect()
+        self.beacons.iter().map(|p| *p + self.pole).collect()
     }
 
     fn update_pole(&mut self, relative: &Self) -> bool {
         let mut offset: Option<Point> = None;
         'outer: for point in self.beacons.iter() {
             for other in relative.beacons.iter() {
-                let delta = other.sub(point);
+                let delta = *other - *point;
                 let count = self
                     .beacons
                     .iter()
-                    .map(|p| p.add(&delta))
+                    .map(|p| *p + delta)
                     .filter(|p| relative.beacons.contains(p))
                     .count();
                 if count == 12 {
@@ -40,116 +41,20 @@ impl Scanner {
         let Some(offset) = offset else {
             return false;
         };
-        self.pole = relative.pole.add(&offset);
+        self.pole = relative.pole + offset;
         true
     }
 
-    fn remapped(&self, mapping: &HyperPoint<AxeMapping>) -> Self {
+    fn rotated(&self, rotation: &Rotation) -> Self {
         let beacons = self
             .beacons
             .iter()
-            .map(|p| {
-                let arr = mapping
-                    .0
-                    .iter()
-                    .map(|am| match am {
-                        AxeMapping::X => p.x(),
-                        AxeMapping::MinusX => -p.x(),
-                        AxeMapping::Y => p.y(),
-                        AxeMapping::MinusY => -p.y(),
-                        AxeMapping::Z => p.z(),
-                        AxeMapping::MinusZ => -p.z(),
-                    })
-                    .collect::<Vec<_>>();
-                HyperPoint(arr)
-            })
+            .map(|p| rotation.apply(p))
             .collect::<PointSet>();
         Self {
             beacons,
-            pole: self.pole.clone(),
-        }
-    }
-}
-
-impl Point {
-    fn x(&self) -> Int {
-        (self.0)[0]
-    }
-
-    fn y(&self) -> Int {
-        (self.0)[1]
-    }
-
-    fn z(&self) -> Int {
-        (self.0)[2]
-    }
-
-    fn manhattan(&self, other: &Self) -> usize {
-        self.0
-            .iter()
-            .zip(other.0.iter())
-            .map(|(a, b)| a.abs_diff(*b))
-            .sum()
-    }
-
-    fn rotated_x(&self) -> Self {
-        HyperPoint(vec![self.x(), -self.z(), self.y()])
-    }
-
-    fn rotated_y(&self) -> Self {
-        HyperPoint(vec![self.z(), self.y(), -self.x()])
-    }
-
-    fn rotated_z(&self) -> Self {
-        HyperPoint(vec![self.y(), -self.x(), self.z()])
-    }
-}
-enum AxeMapping {
-    X,
-    MinusX,
-    Y,
-    MinusY,
-    Z,
-    MinusZ,
-}
-
-impl AxeMapping {
-    fn all() -> Vec<HyperPoint<Self>> {
-        let mut store = HashSet::new();
-        let mut point = HyperPoint(vec![1, 2, 3]);
-        for _ in 0..4 {
-            for _ in 0..4 {
-                for _ in 0..4 {
-                    store.insert(point.clone());
-                    point = point.rotated_x();
-                }
-                point = point.rotated_y();
-            }
-            point = point.rotated_z();
+            pole: self.pole,
         }
-        assert_eq!(24, store.len());
-
-        store
-            .into_iter()
-            .map(|p| {
-                let arr =
-                    p.0.into_iter()
-                        .map(|val| {
-                            use AxeMapping::*;
-                            match val {
-                                1 => X,
-                                -1 => MinusX,
-                                2 => Y,
-                                -2 => MinusY,
-                                3 => Z,
-                                -3 => MinusZ,
-                                _ => unreachable!(),
-                            }
-                        })
-                        .collect::<Vec<_>>();
-                HyperPoint(arr)
-            })
-            .collect::<Vec<_>>()
     }
 }
 
@@ -159,17 +64,17 @@ fn resolve_scanner_poles(input: &[PointSet]) -> Vec<Scanner> {
         .cloned()
         .map(|beacons| Scanner {
             beacons,
-            pole: Point::from(vec![0; 3]),
+            pole: Point::default(),
         })
         .collect::<VecDeque<_>>();
 
     let mut aligned = vec![scanners.pop_back().unwrap()];
 
-    let all_remap_cases = AxeMapping::all();
+    let rotations = Rotation::all();
     'outer: while let Some(candidate) = scanners.pop_back() {
         for scanner in aligned.iter() {
-            for remap in all_remap_cases.iter() {
-                let mut tmp = candidate.remapped(remap);
+            for rotation in rotations.iter() {
+                let mut tmp = candidate.rotated(rotation);
                 if tmp.update_pole(scanner) {
                     aligned.push(tmp);
                     continue 'outer;
@@ -205,8 +110,7 @@ impl AoC2021_19 {
         data.split('\n')
             .map(|x| x.trim())
             .skip(1)
-            .map(|x| HyperPoint::from_csv(x).expect("Invalid point format"))
-            .inspect(|p| assert_eq!(p.dimension(), 3))
+            .map(|x| Point::parse_csv(x).expect("Invalid point format"))
             .collect()
     }
 }
@@ -1,76 +1,42 @@
 use crate::solution::Solution;
+use crate::utils::box_n::BoxN;
 use crate::utils::*;
 
 use std::io;
 
-#[derive(Debug, Clone)]
-struct Interval3<T> {
-    x: PlainInterval<T>,
-    y: PlainInterval<T>,
-    z: PlainInterval<T>,
-}
-
 type Int = isize;
-type Cube = Interval3<Int>;
-
-impl From<&str> for Cube {
-    fn from(value: &str) -> Self {
-        let arr = value
-            .split(',')
-            .map(|s| s.split_once('=').expect("Invalid interval format").1)
-            .map(|s| PlainInterval::parse(s, "..").expect("Invalid interval"))
-            .inspect(|i| assert!(i.begin <= i.end))
-            .collect::<Vec<_>>();
-        assert_eq!(3, arr.len());
-        Self {
-            x: arr[0],
-            y: arr[1],
-            z: arr[2],
-        }
-    }
+type Cube = BoxN<Int, 3>;
+
+fn parse_cube(value: &str) -> Cube {
+    let arr = value
+        .split(',')
+        .map(|s| s.split_once('=').expect("Invalid interval format").1)
+        .map(|s| PlainInterval::<Int>::parse(s, "..").expect("Invalid interval"))
+        .inspect(|i| assert!(i.begin <= i.end))
+        .collect::<Vec<_>>();
+    assert_eq!(3, arr.len());
+    Cube::new(
+        [arr[0].begin, arr[1].begin, arr[2].begin],
+        [arr[0].end, arr[1].end, arr[2].end],
+    )
 }
 
-impl Cube {
-    fn is_small(&self) -> bool {
-        [&self.x, &self.y, &self.z]
-            .iter()
-            .all(|i| i.begin >= -50 && i.end <= 50)
-    }
-
-    fn intersection(&self, other: &Self) -> Option<Cube> {
-        let x = self.x.intersection(&other.x)?;
-        let y = self.y.intersection(&other.y)?;
-        let z = self.z.intersection(&other.z)?;
-        let cube = Self { x, y, z };
-        Some(cube)
-    }
-
-    fn square(&self) -> Int {
-        [&self.x, &self.y, &self.z]
-            .iter()
-            .map(|int| int.length())
-            .product()
-    }
-}
-
-impl PlainInterval<Int> {
-    fn length(&self) -> isize {
-        self.end - self.begin + 1
-    }
+fn is_small(cube: &Cube) -> bool {
+    Cube::new([-50; 3], [50; 3]).contains_box(cube)
 }
 
 #[derive(Debug, Clone)]
 struct Cuboid {
     cube: Cube,
-    weight: Int,
+    is_on: bool,
 }
 
 impl From<&str> for Cuboid {
     fn from(value: &str) -> Self {
         let (is_on, interval) = value.split_once(' ').expect("Invalid entry format");
-        let cube = Interval3::from(interval);
-        let weight = if is_on == "on" { 1 } else { -1 };
-        Self { weight, cube }
+        let cube = parse_cube(interval);
+        let is_on = is_on == "on";
+        Self { is_on, cube }
     }
 }
 
@@ -99,7 +65,7 @@ impl Solution for AoC2021_22 {
         calculate(
             self.input
                 .iter()
-                .filter(|val| val.cube.is_small())
+                .filter(|val| is_small(&val.cube))
                 .cloned()
                 .collect(),
         )
@@ -116,30 +82,18 @@ impl Solution for AoC2021_22 {
 }
 
 fn calculate(input: Vec<Cuboid>) -> Int {
-    let mut elements = input.into_iter().rev().collect::<Vec<_>>();
-    let mut aligned = Vec::<Cuboid>::new();
-    while let Some(n) = elements.pop() {
-        let mut intersections = aligned
+    // lit cubes are kept disjoint by splitting them on every step
+    let mut lit = Vec::<Cube>::new();
+    for cuboid in input {
+        lit = lit
             .iter()
-            .filter_map(|c| {
-                let inter = c.cube.intersection(&n.cube)?;
-                let next = Cuboid {
-                    cube: inter,
-                    weight: -c.weight,
-                };
-                Some(next)
-            })
-            .collect::<Vec<_>>();
-        if n.weight > 0 {
-            aligned.push(n);
+            .flat_map(|cube| cube.subtract(&cuboid.cube))
+            .collect();
+        if cuboid.is_on {
+            lit.push(cuboid.cube);
         }
-        aligned.append(&mut intersections);
     }
-
-    aligned
-        .into_iter()
-        .map(|val| val.weight * val.cube.square())
-        .sum::<Int>()
+    lit.iter().map(|cube| cube.volume()).sum()
 }
 
 #[cfg(test)]
@@ -167,6 +121,19 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn aoc2021_22_case_1() {
+        let sol = AoC2021_22::parse_lines(&[
+            "on x=10..12,y=10..12,z=10..12",
+            "on x=11..13,y=11..13,z=11..13",
+            "off x=9..11,y=9..11,z=9..11",
+            "on x=10..10,y=10..10,z=10..10",
+            "on x=-54..-50,y=0..0,z=0..0",
+        ]);
+        assert_eq!(sol.part_one(), "39");
+        assert_eq!(sol.part_two(), "44");
+    }
+
     fn make_solution() -> io::Result<AoC2021_22> {
         AoC2021_22::new()
     }
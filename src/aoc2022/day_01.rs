@@ -1,4 +1,5 @@
 use crate::solution::Solution;
+use crate::utils::parse::sections;
 use crate::utils::*;
 
 use std::io;
@@ -16,14 +17,12 @@ impl AoC2022_01 {
     }
 
     fn parse_data(data: &str) -> Self {
-        let input = data
-            .split("\n\n")
+        let input = sections(data)
+            .into_iter()
             .map(|values| {
                 values
-                    .split('\n')
-                    .map(|x| x.trim())
-                    .filter(|x| !x.is_empty())
-                    .map(|x| x.parse::<Int>().expect("Invalid calorie value"))
+                    .into_iter()
+                    .map(|x| x.trim().parse::<Int>().expect("Invalid calorie value"))
                     .collect::<Vec<_>>()
             })
             .collect::<Vec<_>>();
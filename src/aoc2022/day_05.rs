@@ -33,8 +33,7 @@ impl AoC2022_05 {
             .split('\n')
             .map(|row| row.chars().collect::<Vec<_>>())
             .collect::<Vec<_>>()
-            .transposed()
-            .flipped_horizontally()
+            .rotated90()
             .into_iter()
             .filter_map(|chars| {
                 if chars.first().map(|ch| ch.is_ascii_digit()) != Some(true) {
@@ -16,13 +16,7 @@ struct Movement {
 impl From<&str> for Movement {
     fn from(value: &str) -> Self {
         let (dir, steps) = value.split_once(' ').expect("Invalid movement format");
-        let dir = match dir {
-            "D" => Direction::Down,
-            "U" => Direction::Up,
-            "R" => Direction::Right,
-            "L" => Direction::Left,
-            _ => unreachable!(),
-        };
+        let dir = dir.parse::<Direction>().expect("Invalid direction");
 
         let count = steps.parse::<usize>().expect("Steps must be integer value");
 
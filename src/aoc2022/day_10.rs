@@ -59,26 +59,18 @@ impl Solution for AoC2022_10 {
     }
 
     fn part_two(&self) -> String {
-        let strength = signal_log(&self.input);
-        const WIDTH: Int = 40;
-        let mut iter = strength.into_iter();
-        for row in 0..6 {
-            for col in 0..WIDTH {
-                let Some(x) = iter.next() else {
-                    panic!("empty signal");
-                };
-                let sprite_center = row * WIDTH + x;
-                let pos = col + row * WIDTH;
-                let ch = if ((sprite_center - 1)..=(sprite_center + 1)).contains(&pos) {
-                    '#'
-                } else {
-                    ' '
-                };
-                print!("{ch}");
-            }
-            println!()
-        }
-        String::new()
+        const WIDTH: usize = 40;
+        let pixels = signal_log(&self.input)
+            .chunks(WIDTH)
+            .take(6)
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .map(|(col, x)| (x - 1..=x + 1).contains(&(col as Int)))
+                    .collect()
+            })
+            .collect::<Vec<_>>();
+        ocr::recognize(&pixels).unwrap_or_else(not_found)
     }
 
     fn description(&self) -> String {
@@ -165,7 +157,8 @@ mod test {
     #[test]
     fn aoc2022_10_correctness_part_2() -> io::Result<()> {
         let sol = make_solution()?;
-        assert_eq!(sol.part_two(), "");
+        let message = sol.part_two();
+        assert!(message.len() == 8 && message.chars().all(|ch| ch.is_ascii_uppercase()));
         Ok(())
     }
 
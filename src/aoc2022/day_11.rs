@@ -1,4 +1,5 @@
 use crate::solution::Solution;
+use crate::utils::parse::{integers, record, Pattern};
 
 use std::fmt::Debug;
 use std::io;
@@ -87,10 +88,10 @@ impl Logic {
         }
     }
 
-    fn with(lines: &[&str]) -> Self {
-        let divider = Self::parse::<Int>(lines[0], "Test: divisible by ");
-        let true_idx = Self::parse::<usize>(lines[1], "If true: throw to monkey ");
-        let false_idx = Self::parse::<usize>(lines[2], "If false: throw to monkey ");
+    fn with(test: &str, if_true: &str, if_false: &str) -> Self {
+        let divider = Self::parse::<Int>(test, "divisible by {}");
+        let true_idx = Self::parse::<usize>(if_true, "throw to monkey {}");
+        let false_idx = Self::parse::<usize>(if_false, "throw to monkey {}");
         Logic {
             divider,
             true_idx,
@@ -98,39 +99,24 @@ impl Logic {
         }
     }
 
-    fn parse<T>(input: &str, prefix: &str) -> T
-    where
-        T: FromStr,
-        T::Err: Debug,
-    {
-        input
-            .strip_prefix(prefix)
-            .expect("Prefix doesn't match")
-            .parse::<T>()
-            .expect("Failed to parse value")
+    fn parse<T: FromStr>(input: &str, format: &str) -> T {
+        let (value,) = Pattern::new(format)
+            .parse::<(T,)>(input)
+            .unwrap_or_else(|e| panic!("Failed to parse monkey logic: {e}"));
+        value
     }
 }
 
 impl From<&str> for Monkey {
     fn from(value: &str) -> Self {
-        let lines = value
-            .split("\n")
-            .skip(1)
-            .map(|x| x.trim())
-            .collect::<Vec<_>>();
+        let record = record(value).expect("Invalid monkey format");
+        let field = |key: &str| *record.get(key).expect("Monkey field is missing");
 
-        let items = lines[0]
-            .strip_prefix("Starting items: ")
-            .map(|s| {
-                s.split(", ")
-                    .map(|x| x.parse::<Int>().expect("Item must be integer"))
-                    .collect::<Vec<_>>()
-            })
-            .expect("Invalid items format");
+        let items = integers::<Int>(field("Starting items"));
 
-        let transform = Transform::from(lines[1]);
+        let transform = Transform::from(field("Operation"));
 
-        let logic = Logic::with(&lines[2..]);
+        let logic = Logic::with(field("Test"), field("If true"), field("If false"));
 
         Monkey {
             items,
@@ -143,7 +129,7 @@ impl From<&str> for Monkey {
 impl From<&str> for Transform {
     fn from(value: &str) -> Self {
         let tokens = value
-            .strip_prefix("Operation: new = ")
+            .strip_prefix("new = ")
             .map(|s| {
                 s.split_ascii_whitespace()
                     .map(|t| match t {
@@ -1,7 +1,8 @@
 use crate::solution::Solution;
+use crate::utils::search;
 use crate::utils::*;
 
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::HashMap;
 use std::io;
 
 type Int = isize;
@@ -50,16 +51,14 @@ impl AoC2022_12 {
 
 impl Solution for AoC2022_12 {
     fn part_one(&self) -> String {
-        bfs(&self.map, self.start, can_move_forward, |_map, p| {
-            p == self.end
-        })
-        .map(|x| x.to_string())
-        .unwrap_or(not_found())
+        self.shortest_climb(self.start, can_move_forward, |p| *p == self.end)
+            .map(|x| x.to_string())
+            .unwrap_or(not_found())
     }
 
     fn part_two(&self) -> String {
-        bfs(&self.map, self.end, can_move_backward, |map, p| {
-            map.get(&p) == Some(&'a')
+        self.shortest_climb(self.end, can_move_backward, |p| {
+            self.map.get(p) == Some(&'a')
         })
         .map(|x| x.to_string())
         .unwrap_or(not_found())
@@ -70,56 +69,27 @@ impl Solution for AoC2022_12 {
     }
 }
 
-fn bfs(
-    map: &Map,
-    start: Point,
-    can_move: impl Fn(char, char) -> bool,
-    is_target: impl Fn(&Map, Point) -> bool,
-) -> Option<usize> {
-    struct Elem {
-        point: Point,
-        length: usize,
-    }
-
-    let mut dequeue = VecDeque::new();
-    dequeue.push_front(Elem {
-        point: start,
-        length: 0,
-    });
-
-    let mut seen = HashSet::<Point>::new();
-
-    while let Some(elem) = dequeue.pop_back() {
-        if !seen.insert(elem.point) {
-            continue;
-        }
-
-        let Some(value) = map.get(&elem.point) else {
-            unreachable!()
+impl AoC2022_12 {
+    fn shortest_climb(
+        &self,
+        start: Point,
+        can_move: impl Fn(char, char) -> bool,
+        is_target: impl Fn(&Point) -> bool,
+    ) -> Option<usize> {
+        let neighbours = |point: &Point| {
+            let value = self.map[point];
+            Direction::all()
+                .iter()
+                .map(|dir| point.moved_by(dir))
+                .filter(|next| {
+                    self.map
+                        .get(next)
+                        .is_some_and(|next_value| can_move(value, *next_value))
+                })
+                .collect::<Vec<_>>()
         };
-
-        if is_target(map, elem.point) {
-            return Some(elem.length);
-        }
-
-        for dir in Direction::all() {
-            let next_point = elem.point.moved_by(&dir);
-            if seen.contains(&next_point) {
-                continue;
-            }
-            let Some(next_value) = map.get(&next_point) else {
-                continue;
-            };
-
-            if can_move(*value, *next_value) {
-                dequeue.push_front(Elem {
-                    point: next_point,
-                    length: 1 + elem.length,
-                });
-            }
-        }
+        search::bfs_path([start], neighbours, is_target).map(|found| found.cost)
     }
-    None
 }
 
 fn can_move_forward(current: char, next: char) -> bool {
@@ -159,6 +129,7 @@ mod test {
     fn aoc2022_12_case_1() {
         let sol = make_test_solution();
         assert_eq!(sol.part_one(), "31");
+        assert_eq!(sol.part_two(), "29");
     }
 
     fn make_solution() -> io::Result<AoC2022_12> {
@@ -1,6 +1,5 @@
-use regex::Regex;
-
 use crate::solution::Solution;
+use crate::utils::parse::Pattern;
 use crate::utils::*;
 
 use std::io;
@@ -47,27 +46,14 @@ impl AoC2022_15 {
     }
 
     fn parse_lines<T: AsRef<str>>(lines: &[T]) -> Self {
-        let Ok(regex) = Regex::new(r"-?\d+") else {
-            panic!("Invalid regexp")
-        };
-        let input = lines
-            .iter()
-            .map(|x| x.as_ref())
-            .filter(|x| !x.is_empty())
-            .map(|s| {
-                regex
-                    .find_iter(s)
-                    .map(|x| {
-                        x.as_str()
-                            .parse::<Int>()
-                            .expect("Failed to parse coordinate value")
-                    })
-                    .collect::<Vec<_>>()
-            })
-            .inspect(|arr| assert_eq!(4, arr.len()))
-            .map(|arr| Element {
-                sensor: Point::new(arr[0], arr[1]),
-                beacon: Point2d::new(arr[2], arr[3]),
+        let pattern = Pattern::new("Sensor at x={}, y={}: closest beacon is at x={}, y={}");
+        let input = pattern
+            .parse_lines::<(Int, Int, Int, Int), _>(lines)
+            .unwrap_or_else(|e| panic!("Failed to parse sensors: {e}"))
+            .into_iter()
+            .map(|(sx, sy, bx, by)| Element {
+                sensor: Point::new(sx, sy),
+                beacon: Point2d::new(bx, by),
             })
             .collect::<Vec<_>>();
         Self { input }
@@ -1,7 +1,7 @@
 use regex::Regex;
 
 use crate::solution::Solution;
-use crate::utils::not_found;
+use crate::utils::{bitset::BitSet, not_found};
 
 use std::collections::{HashMap, VecDeque};
 use std::io::{self};
@@ -55,7 +55,6 @@ impl AoC2022_16 {
     }
 
     fn solve(&self, time: Int) -> Memo<'_> {
-        assert!(self.input.iter().len() <= 64);
         let distances = calculate_distances(&self.input);
 
         let active_valves = self
@@ -68,7 +67,7 @@ impl AoC2022_16 {
         let state = State {
             valve: "AA",
             time_left: time,
-            opened_bitmap: 0,
+            opened: BitSet::new(),
             current_pressure: 0,
         };
 
@@ -95,7 +94,7 @@ impl Solution for AoC2022_16 {
             for second in arr.iter().skip(i + 1) {
                 let (mask_f, pressure_f) = first;
                 let (mask_s, pressure_s) = second;
-                if (mask_f & mask_s) == 0 {
+                if mask_f.is_disjoint(mask_s) {
                     max_total = max_total.max(pressure_f + pressure_s);
                 }
             }
@@ -144,10 +143,10 @@ struct State<'l> {
     valve: &'l str,
     time_left: Int,
     current_pressure: Int,
-    opened_bitmap: u64,
+    opened: BitSet,
 }
 
-type Memo<'l> = HashMap<u64, Int>;
+type Memo<'l> = HashMap<BitSet, Int>;
 
 fn most_pressure<'l>(
     state: State<'l>,
@@ -156,17 +155,16 @@ fn most_pressure<'l>(
     distances: &'l DistanceMap,
     memo: &mut Memo<'l>,
 ) {
-    let entry = memo.entry(state.opened_bitmap).or_default();
+    let entry = memo.entry(state.opened).or_default();
 
     if state.current_pressure > *entry {
         *entry = state.current_pressure;
     }
 
-    for (bit, target) in valves
+    for (index, target) in valves
         .iter()
         .enumerate()
-        .map(|(i, x)| (1 << i, x))
-        .filter(|(bit, _)| state.opened_bitmap & bit == 0)
+        .filter(|(index, _)| !state.opened.contains(*index))
     {
         let dist = *distances.get(&(state.valve, target)).unwrap();
         let remaining_time = state.time_left - dist - 1;
@@ -178,7 +176,7 @@ fn most_pressure<'l>(
         let next_state = State {
             valve: target,
             time_left: remaining_time,
-            opened_bitmap: state.opened_bitmap | bit,
+            opened: state.opened.with(index),
             current_pressure: state.current_pressure + flow,
         };
 
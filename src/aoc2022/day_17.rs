@@ -1,9 +1,7 @@
 use crate::solution::Solution;
+use crate::utils::cycle;
 
-use std::{
-    collections::HashMap,
-    io::{self},
-};
+use std::io::{self};
 
 pub struct AoC2022_17 {
     input: Vec<char>,
@@ -54,58 +52,49 @@ struct State {
     surface: Vec<u8>,
 }
 
-fn simulate(shapes: &[Shape], jet_pattern: &[char], total_rocks: usize) -> usize {
-    let mut chamber = vec![0u8; 1000]; // Start small, grow as needed
-    let mut cache = HashMap::new();
-
-    let mut shape_idx = 0;
-    let mut jet_idx = 0;
-    let mut top = 0;
-    let mut additional_height_from_cycles = 0;
-    let mut r = 0;
-
-    while r < total_rocks {
-        if top > SURFACE_SIZE {
-            let state = State {
-                shape_idx,
-                jet_idx,
-                // Taking the top rows as a "fingerprint" of the surface
-                surface: chamber[top - SURFACE_SIZE..top].to_vec(),
-            };
-
-            if let Some((prev_r, prev_top)) = cache.get(&state) {
-                let num_rocks_in_cycle = r - prev_r;
-                let height_in_cycle = top - prev_top;
-
-                let remaining_rocks = total_rocks - r;
-                let num_cycles = remaining_rocks / num_rocks_in_cycle;
-
-                additional_height_from_cycles += num_cycles * height_in_cycle;
-                r += num_cycles * num_rocks_in_cycle;
+#[derive(Clone)]
+struct Tower {
+    chamber: Vec<u8>,
+    top: usize,
+    shape_idx: usize,
+    jet_idx: usize,
+}
 
-                // Clear cache so we don't trigger this again
-                cache.clear();
-            } else {
-                cache.insert(state, (r, top));
-            }
-        }
-        if top + 10 > chamber.len() {
-            chamber.resize(chamber.len() * 2, 0);
+impl Tower {
+    fn state(&self) -> State {
+        State {
+            shape_idx: self.shape_idx,
+            jet_idx: self.jet_idx,
+            // Taking the top rows as a "fingerprint" of the surface
+            surface: self.chamber[self.top.saturating_sub(SURFACE_SIZE)..self.top].to_vec(),
         }
+    }
+}
 
+fn simulate(shapes: &[Shape], jet_pattern: &[char], total_rocks: usize) -> usize {
+    let tower = Tower {
+        chamber: vec![0u8; 1000], // Start small, grow as needed
+        top: 0,
+        shape_idx: 0,
+        jet_idx: 0,
+    };
+    let drop_rock = |tower: &Tower| {
+        let mut tower = tower.clone();
+        if tower.top + 10 > tower.chamber.len() {
+            tower.chamber.resize(tower.chamber.len() * 2, 0);
+        }
         simulate_one_rock(
-            shapes[shape_idx],
+            shapes[tower.shape_idx],
             jet_pattern,
-            &mut jet_idx,
-            &mut chamber,
-            &mut top,
+            &mut tower.jet_idx,
+            &mut tower.chamber,
+            &mut tower.top,
         );
-        shape_idx = (shape_idx + 1) % shapes.len();
-
-        r += 1;
-    }
-
-    top + additional_height_from_cycles
+        tower.shape_idx = (tower.shape_idx + 1) % shapes.len();
+        tower
+    };
+    cycle::find_cycle_by_key(tower, drop_rock, Tower::state)
+        .value_at(total_rocks, |tower| tower.top)
 }
 
 fn simulate_one_rock(
@@ -247,7 +236,8 @@ mod test {
     #[test]
     fn aoc2022_17_case_1() {
         let sol = make_test_solution();
-        assert_eq!(sol.part_one(), "3068")
+        assert_eq!(sol.part_one(), "3068");
+        assert_eq!(sol.part_two(), "1514285714288");
     }
 
     fn make_solution() -> io::Result<AoC2022_17> {
@@ -1,11 +1,11 @@
 use crate::solution::Solution;
-use crate::utils::hyper_point::HyperPoint;
+use crate::utils::Point3d;
 
 use std::collections::{HashSet, VecDeque};
 use std::io;
 
 type Int = i32;
-type Point = HyperPoint<Int>;
+type Point = Point3d<Int>;
 
 pub struct AoC2022_18 {
     input: Vec<Point>,
@@ -25,7 +25,7 @@ impl AoC2022_18 {
         let input = lines
             .iter()
             .map(|x| x.as_ref())
-            .map(|x| Point::from_csv(x).expect("Invalid input format"))
+            .map(|x| Point::parse_csv(x).expect("Invalid input format"))
             .collect::<Vec<_>>();
         Self { input }
     }
@@ -33,12 +33,11 @@ impl AoC2022_18 {
 
 impl Solution for AoC2022_18 {
     fn part_one(&self) -> String {
-        let all_points = self.input.iter().cloned().collect::<HashSet<_>>();
+        let all_points = self.input.iter().copied().collect::<HashSet<_>>();
         let mut surface = 0;
         for point in self.input.iter() {
-            surface += 6 - deltas()
-                .iter()
-                .map(|delta| point.add(delta))
+            surface += 6 - point
+                .face_neighbours()
                 .filter(|p| all_points.contains(p))
                 .count();
         }
@@ -47,41 +46,40 @@ impl Solution for AoC2022_18 {
 
     fn part_two(&self) -> String {
         let (min_x, max_x) = (
-            get_min(&self.input, |p| p.0[0]) - 1,
-            get_max(&self.input, |p| p.0[0]) + 1,
+            get_min(&self.input, |p| p.x) - 1,
+            get_max(&self.input, |p| p.x) + 1,
         );
         let (min_y, max_y) = (
-            get_min(&self.input, |p| p.0[1]) - 1,
-            get_max(&self.input, |p| p.0[1]) + 1,
+            get_min(&self.input, |p| p.y) - 1,
+            get_max(&self.input, |p| p.y) + 1,
         );
         let (min_z, max_z) = (
-            get_min(&self.input, |p| p.0[2]) - 1,
-            get_max(&self.input, |p| p.0[2]) + 1,
+            get_min(&self.input, |p| p.z) - 1,
+            get_max(&self.input, |p| p.z) + 1,
         );
 
-        let all_points = self.input.iter().cloned().collect::<HashSet<_>>();
-        let start = Point::from(vec![min_x, min_y, min_z]);
+        let all_points = self.input.iter().copied().collect::<HashSet<_>>();
+        let start = Point::new(min_x, min_y, min_z);
         let mut seen = HashSet::new();
-        seen.insert(start.clone());
+        seen.insert(start);
 
         let mut queue = VecDeque::new();
         queue.push_back(start);
 
         let mut surface = 0usize;
         while let Some(point) = queue.pop_back() {
-            deltas()
-                .iter()
-                .map(|delta| point.add(delta))
+            point
+                .face_neighbours()
                 .filter(|p| {
                     // if point in allowed range
-                    (min_x..=max_x).contains(&p.0[0])
-                        && (min_y..=max_y).contains(&p.0[1])
-                        && (min_z..=max_z).contains(&p.0[2])
+                    (min_x..=max_x).contains(&p.x)
+                        && (min_y..=max_y).contains(&p.y)
+                        && (min_z..=max_z).contains(&p.z)
                 })
                 .for_each(|p| {
                     if all_points.contains(&p) {
                         surface += 1;
-                    } else if seen.insert(p.clone()) {
+                    } else if seen.insert(p) {
                         queue.push_front(p);
                     }
                 });
@@ -103,17 +101,6 @@ fn get_max(input: &[Point], transform: impl Fn(&Point) -> Int) -> Int {
     input.iter().map(transform).max().unwrap()
 }
 
-fn deltas() -> [Point; 6] {
-    [
-        Point::from(vec![1, 0, 0]),
-        Point::from(vec![-1, 0, 0]),
-        Point::from(vec![0, 1, 0]),
-        Point::from(vec![0, -1, 0]),
-        Point::from(vec![0, 0, 1]),
-        Point::from(vec![0, 0, -1]),
-    ]
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1,6 +1,6 @@
 use crate::{
     solution::Solution,
-    utils::{bounds, not_found, Direction, Point2d},
+    utils::{bounds, not_found, Compass, Direction, Point2d},
 };
 
 use std::{
@@ -81,38 +81,22 @@ fn simulate_step(positions: &HashSet<Point>, step: usize) -> Option<HashSet<Poin
     let rules = [
         // If there is no Elf in the N, NE, or NW adjacent positions, the Elf proposes moving north one step.
         (
-            [
-                vec![Direction::Up],
-                vec![Direction::Up, Direction::Right],
-                vec![Direction::Up, Direction::Left],
-            ],
+            [Compass::North, Compass::NorthEast, Compass::NorthWest],
             Direction::Up,
         ),
         // If there is no Elf in the S, SE, or SW adjacent positions, the Elf proposes moving south one step.
         (
-            [
-                vec![Direction::Down],
-                vec![Direction::Down, Direction::Right],
-                vec![Direction::Down, Direction::Left],
-            ],
+            [Compass::South, Compass::SouthEast, Compass::SouthWest],
             Direction::Down,
         ),
         // If there is no Elf in the W, NW, or SW adjacent positions, the Elf proposes moving west one step.
         (
-            [
-                vec![Direction::Left],
-                vec![Direction::Left, Direction::Up],
-                vec![Direction::Left, Direction::Down],
-            ],
+            [Compass::West, Compass::NorthWest, Compass::SouthWest],
             Direction::Left,
         ),
         // If there is no Elf in the E, NE, or SE adjacent positions, the Elf proposes moving east one step.
         (
-            [
-                vec![Direction::Right],
-                vec![Direction::Right, Direction::Up],
-                vec![Direction::Right, Direction::Down],
-            ],
+            [Compass::East, Compass::NorthEast, Compass::SouthEast],
             Direction::Right,
         ),
     ];
@@ -123,15 +107,15 @@ fn simulate_step(positions: &HashSet<Point>, step: usize) -> Option<HashSet<Poin
     let mut result = HashSet::new();
 
     for p in positions.iter() {
-        let all_empty = |directions: &[Vec<Direction>]| -> bool {
+        let all_empty = |directions: &[Compass]| -> bool {
             directions
                 .iter()
-                .map(|directions| p.moved_with_dirs(directions))
+                .map(|compass| p.moved_by_compass(compass))
                 .all(|point| !positions.contains(&point))
         };
         // check if all empty
         let mut destination: Option<Point> = None;
-        if !all_empty(&Direction::circular_directions()) {
+        if !all_empty(&Compass::all()) {
             for rule_id in 0..rules.len() {
                 let index = (rule_id + step) % rules.len();
                 let (adj, dir) = &rules[index];
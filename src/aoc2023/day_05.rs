@@ -1,4 +1,5 @@
 use crate::solution::Solution;
+use crate::utils::interval_set::IntervalSet;
 use crate::utils::*;
 
 use std::io;
@@ -74,6 +75,25 @@ impl AoC2023_05 {
         }
         value
     }
+
+    fn convert_set(&self, map_id: usize, values: &IntervalSet<Int>) -> IntervalSet<Int> {
+        let mut converted = IntervalSet::new();
+        let mut rest = values.clone();
+        for item in &self.mapping[map_id] {
+            let source = IntervalSet::from_iter([PlainInterval::new(
+                item.src_start,
+                item.src_start + item.length - 1,
+            )]);
+            for x in values.intersection(&source).iter() {
+                converted.insert(
+                    x.begin - item.src_start + item.dest_start,
+                    x.end - item.src_start + item.dest_start,
+                );
+            }
+            rest = rest.difference(&source);
+        }
+        converted.union(&rest)
+    }
 }
 
 impl Solution for AoC2023_05 {
@@ -86,17 +106,16 @@ impl Solution for AoC2023_05 {
     }
 
     fn part_two(&self) -> String {
-        let mut result = Int::MAX;
-        for chunk in self.seeds.chunks(2) {
-            for i in 0..chunk[1] {
-                let mut val = chunk[0] + i;
-                for i in 0..self.mapping.len() {
-                    val = self.convert(i, val);
-                }
-                result = result.min(val);
-            }
+        let mut values = self
+            .seeds
+            .chunks(2)
+            .map(|chunk| PlainInterval::new(chunk[0], chunk[0] + chunk[1] - 1))
+            .collect::<IntervalSet<_>>();
+        for i in 0..self.mapping.len() {
+            values = self.convert_set(i, &values);
         }
-        result.to_string()
+        let lowest = values.iter().next().expect("Incorrect state").begin;
+        lowest.to_string()
     }
 
     fn description(&self) -> String {
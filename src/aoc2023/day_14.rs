@@ -1,7 +1,6 @@
 use crate::solution::Solution;
 use crate::utils::*;
 
-use std::collections::HashMap;
 use std::io;
 
 pub struct AoC2023_14 {
@@ -31,25 +30,12 @@ impl Solution for AoC2023_14 {
     }
 
     fn part_two(&self) -> String {
-        let cycles = 1000000000_usize;
-        let mut platform = self.input.clone();
-        let mut load = Vec::new();
-        let mut map = HashMap::new();
-        for i in 0..cycles {
-            // north, then west, then south, then east
-            slide(Direction::Up, &mut platform);
-            slide(Direction::Left, &mut platform);
-            slide(Direction::Down, &mut platform);
-            slide(Direction::Right, &mut platform);
-            if let Some(from) = map.get(&platform) {
-                let in_loop: &[usize] = &load[*from..];
-                return in_loop[(cycles - i - 1) % in_loop.len()].to_string();
-            }
-            load.push(total_load(&platform));
-            map.insert(platform.clone(), i);
-        }
-
-        "Age later...".to_string()
+        let platform = cycle::nth_state(
+            self.input.clone(),
+            |platform| spin_cycle(platform),
+            1000000000,
+        );
+        total_load(&platform).to_string()
     }
 
     fn description(&self) -> String {
@@ -57,6 +43,16 @@ impl Solution for AoC2023_14 {
     }
 }
 
+fn spin_cycle(platform: &[Vec<char>]) -> Vec<Vec<char>> {
+    let mut platform = platform.to_vec();
+    // north, then west, then south, then east
+    slide(Direction::Up, &mut platform);
+    slide(Direction::Left, &mut platform);
+    slide(Direction::Down, &mut platform);
+    slide(Direction::Right, &mut platform);
+    platform
+}
+
 fn slide(direction: Direction, platform: &mut [Vec<char>]) {
     let rows = platform.len();
     for row in 0..rows {
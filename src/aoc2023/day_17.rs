@@ -1,8 +1,8 @@
 use crate::solution::Solution;
+use crate::utils::search;
 use crate::utils::*;
 
-use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap};
+use std::collections::HashMap;
 use std::io;
 
 type Int = u32;
@@ -25,31 +25,6 @@ impl Node {
     }
 }
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-struct QueueItem {
-    weight: Int,
-    node: Node,
-}
-
-impl Ord for QueueItem {
-    fn cmp(&self, other: &Self) -> Ordering {
-        // Notice that the we flip the ordering on costs.
-        // In case of a tie we compare positions - this step is necessary
-        // to make implementations of `PartialEq` and `Ord` consistent.
-        other
-            .weight
-            .cmp(&self.weight)
-            .then(self.node.cmp(&other.node))
-    }
-}
-
-// `PartialOrd` needs to be implemented as well.
-impl PartialOrd for QueueItem {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
 fn valid_points(point: Point, rows: usize, cols: usize) -> HashMap<Direction, Point> {
     Direction::all()
         .iter()
@@ -100,42 +75,17 @@ fn adjacent_pt2(map: &[Vec<Int>], node: Node) -> Vec<Node> {
 type Adjacent = dyn Fn(&[Vec<Int>], Node) -> Vec<Node>;
 
 fn dijkstra(map: &[Vec<Int>], start: Point, target: Point, adjacent: &Adjacent) -> Option<Int> {
-    // init
-    let mut weights: HashMap<Node, Int> = HashMap::new();
-    let mut queue: BinaryHeap<QueueItem> = BinaryHeap::new();
-    [
+    let starts = [
         Node::new(start, Direction::Down, 0),
         Node::new(start, Direction::Right, 0),
-    ]
-    .iter()
-    .for_each(|node| {
-        weights.insert(*node, 0);
-        queue.push(QueueItem {
-            node: *node,
-            weight: 0,
-        });
-    });
-    // main loop
-    while let Some(item) = queue.pop() {
-        let QueueItem { node, weight } = item;
-        if node.point == target {
-            return Some(weight);
-        }
-        for adj in adjacent(map, node) {
-            let new_weight = weight + map[adj.point.y][adj.point.x];
-            if let Some(old_weight) = weights.get(&adj) {
-                if new_weight >= *old_weight {
-                    continue;
-                }
-            }
-            weights.insert(adj, new_weight);
-            queue.push(QueueItem {
-                node: adj,
-                weight: new_weight,
-            });
-        }
-    }
-    None
+    ];
+    let neighbours = |node: &Node| {
+        adjacent(map, *node)
+            .into_iter()
+            .map(|adj| (adj, map[adj.point.y][adj.point.x]))
+            .collect::<Vec<_>>()
+    };
+    search::dijkstra_path(starts, neighbours, |node| node.point == target).map(|found| found.cost)
 }
 
 pub struct AoC2023_17 {
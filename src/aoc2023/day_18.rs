@@ -1,4 +1,5 @@
 use crate::solution::Solution;
+use crate::utils::geometry::covered_points;
 use crate::utils::*;
 
 use std::io;
@@ -85,46 +86,27 @@ impl Solution for AoC2023_18 {
     }
 }
 
-type Vertex = (Int, Int);
-
 fn plan_square(plan: &[PlanItem]) -> Int {
-    let mut vertex = vec![(0, 0)];
-    let mut boundary_points = 0;
+    let mut vertex = vec![Point2d::new(0, 0)];
     let (mut row, mut col) = (0, 0);
     for item in plan {
         let val = item.depth;
-        boundary_points += val;
         match item.direction {
             Direction::Up => row -= val,
             Direction::Down => row += val,
             Direction::Left => col -= val,
             Direction::Right => col += val,
         };
-        vertex.push((row, col));
+        vertex.push(Point2d::new(col, row));
     }
-    // Pike's theorem
-    // https://en.wikipedia.org/wiki/Pick%27s_theorem
-    let a = square(&vertex);
-    let i = a - (boundary_points >> 1) + 1;
-    i + boundary_points
-}
-
-// Shoelace formula
-// TODO: make generics, move to math module
-fn square(vertex: &[Vertex]) -> Int {
-    let n = vertex.len();
-    let mut upper_sum = vertex[n - 1].0 * vertex[0].1;
-    let mut lower_sum = vertex[0].0 * vertex[n - 1].1;
-    for i in 0..n - 1 {
-        upper_sum += vertex[i].0 * vertex[i + 1].1;
-        lower_sum += vertex[i + 1].0 * vertex[i].1;
-    }
-    (upper_sum.abs_diff(lower_sum) >> 1) as Int
+    // Pick's theorem
+    covered_points(&vertex)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::utils::geometry::polygon_area;
 
     #[test]
     fn aoc2023_18_input_load_test() -> io::Result<()> {
@@ -178,6 +160,13 @@ mod test {
 
     #[test]
     fn aoc2023_18_gauss_square() {
+        let square = |vertex: &[(Int, Int)]| {
+            let polygon = vertex
+                .iter()
+                .map(|(x, y)| Point2d::new(*x, *y))
+                .collect::<Vec<_>>();
+            polygon_area(&polygon)
+        };
         let vertex = [(3, 4), (5, 11), (12, 8), (9, 5), (5, 6)];
         assert_eq!(square(&vertex), 30);
 
@@ -1,19 +1,24 @@
 use crate::solution::Solution;
+use crate::utils::matrix::Matrix;
+use crate::utils::rational::Rational;
 use crate::utils::*;
 
 use std::io;
+use std::ops::{Add, Div, Mul, Sub};
 
 type Number = f64;
-const TOLERANCE: Number = 1e-10;
 
 #[derive(Debug, Copy, Clone)]
-struct Vector3d {
-    x: Number,
-    y: Number,
-    z: Number,
+struct Vector3d<T = Number> {
+    x: T,
+    y: T,
+    z: T,
 }
 
-impl Vector3d {
+impl<T> Vector3d<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
     fn sum(&self, other: &Self) -> Self {
         Self {
             x: self.x + other.x,
@@ -30,7 +35,7 @@ impl Vector3d {
         }
     }
 
-    fn scalar_mul(&self, scalar: Number) -> Self {
+    fn scalar_mul(&self, scalar: T) -> Self {
         Self {
             x: self.x * scalar,
             y: self.y * scalar,
@@ -38,7 +43,7 @@ impl Vector3d {
         }
     }
 
-    fn scalar_div(&self, scalar: Number) -> Self {
+    fn scalar_div(&self, scalar: T) -> Self {
         Self {
             x: self.x / scalar,
             y: self.y / scalar,
@@ -46,11 +51,11 @@ impl Vector3d {
         }
     }
 
-    fn dot_product(&self, other: &Self) -> Number {
+    fn dot_product(&self, other: &Self) -> T {
         self.x * other.x + self.y * other.y + self.z * other.z
     }
 
-    fn cross_product(&self, other: &Self) -> Vector3d {
+    fn cross_product(&self, other: &Self) -> Self {
         Self {
             x: self.y * other.z - self.z * other.y,
             y: self.z * other.x - self.x * other.z,
@@ -59,6 +64,18 @@ impl Vector3d {
     }
 }
 
+impl Vector3d {
+    /// Input values are integers that f64 represents exactly
+    fn exact(&self) -> Vector3d<Rational> {
+        let convert = |value: Number| Rational::from(value as i128);
+        Vector3d {
+            x: convert(self.x),
+            y: convert(self.y),
+            z: convert(self.z),
+        }
+    }
+}
+
 impl From<&str> for Vector3d {
     fn from(value: &str) -> Self {
         let values = value
@@ -78,9 +95,9 @@ impl From<&str> for Vector3d {
     }
 }
 
-struct Hailstone {
-    position: Vector3d,
-    velocity: Vector3d,
+struct Hailstone<T = Number> {
+    position: Vector3d<T>,
+    velocity: Vector3d<T>,
 }
 
 impl From<&str> for Hailstone {
@@ -95,12 +112,24 @@ impl From<&str> for Hailstone {
     }
 }
 
-impl Hailstone {
-    fn position(&self, time: Number) -> Vector3d {
+impl<T> Hailstone<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    fn position(&self, time: T) -> Vector3d<T> {
         self.position.sum(&self.velocity.scalar_mul(time))
     }
 }
 
+impl Hailstone {
+    fn exact(&self) -> Hailstone<Rational> {
+        Hailstone {
+            position: self.position.exact(),
+            velocity: self.velocity.exact(),
+        }
+    }
+}
+
 pub struct AoC2023_24 {
     input: Vec<Hailstone>,
 }
@@ -149,9 +178,14 @@ impl Solution for AoC2023_24 {
     fn part_two(&self) -> String {
         // https://www.reddit.com/r/adventofcode/comments/18pnycy/2023_day_24_solutions/
         assert!(self.input.len() > 2);
-        let relative = |a: usize, b: usize| -> (Vector3d, Vector3d) {
-            let p = self.input[a].position.diff(&self.input[b].position);
-            let v = self.input[a].velocity.diff(&self.input[b].velocity);
+        // the intermediate values exceed the f64 precision
+        let input = self.input[..3]
+            .iter()
+            .map(Hailstone::exact)
+            .collect::<Vec<_>>();
+        let relative = |a: usize, b: usize| -> (Vector3d<Rational>, Vector3d<Rational>) {
+            let p = input[a].position.diff(&input[b].position);
+            let v = input[a].velocity.diff(&input[b].velocity);
             (p, v)
         };
         let (p1, v1) = relative(1, 0);
@@ -161,9 +195,9 @@ impl Solution for AoC2023_24 {
         // t2 = -((p1 x p2) * v1) / ((p1 x v2) * v1)
         let t2 = -p1.cross_product(&p2).dot_product(&v1) / (p1.cross_product(&v2).dot_product(&v1));
         // c1 = position_1 + t1 * velocity_1
-        let c1 = self.input[1].position(t1);
+        let c1 = input[1].position(t1);
         // c2 = position_2 + t2 * velocity_2
-        let c2 = self.input[2].position(t2);
+        let c2 = input[2].position(t2);
         // v = (c2 - c1) / (t2 - t1)
         let v = c2.diff(&c1).scalar_div(t2 - t1);
         // p = c1 - t1 * v
@@ -178,39 +212,13 @@ impl Solution for AoC2023_24 {
 }
 
 fn intersection(a: &Hailstone, b: &Hailstone) -> Option<(Number, Number)> {
-    let a_x = a.position.x; // A
-    let a_dx = a.velocity.x; // alpha
-    if a_dx.abs() < TOLERANCE {
-        return None;
-    }
-
-    let a_y = a.position.y; // C
-    let a_dy = a.velocity.y; // gamma
-
-    let b_x = b.position.x; // B
-    let b_dx = b.velocity.x; // beta
-
-    let b_y = b.position.y; // D
-    let b_dy = b.velocity.y; // delta
-
-    // linear equation
-    // a_x + t1 * a_dx = b_x + t2 * b_dx
-    // a_y + t1 * a_dy = b_y + t2 * b_dy
-
-    // solution
-    // t2 = (alpha * D - alpha * C - gamma * B + gamma * A) / (gamma * beta - alpha * delta)
-    // t1 = (B - A + beta * t2) / alpha
-
-    let denom: Number = a_dy * b_dx - a_dx * b_dy;
-    if denom.abs() < TOLERANCE {
-        return None;
-    }
-
-    let t2 = (a_dx * b_y - a_dx * a_y - a_dy * b_x + a_dy * a_x) / denom;
-
-    let t1 = (b_x - a_x + b_dx * t2) / a_dx;
-
-    Some((t1, t2))
+    // a.position + t1 * a.velocity = b.position + t2 * b.velocity
+    let system = Matrix::from_rows(&[
+        vec![a.velocity.x, -b.velocity.x],
+        vec![a.velocity.y, -b.velocity.y],
+    ]);
+    let times = system.solve(&[b.position.x - a.position.x, b.position.y - a.position.y])?;
+    Some((times[0], times[1]))
 }
 
 #[cfg(test)]
@@ -238,6 +246,7 @@ mod test {
         .collect::<Vec<_>>();
         let puzzle = AoC2023_24::with_lines(&input);
         assert_eq!(puzzle.path_cross_count(7.0, 27.0), 2);
+        assert_eq!(puzzle.part_two(), "47");
     }
 
     #[test]
@@ -1,11 +1,9 @@
 use crate::solution::Solution;
+use crate::utils::graph::Graph;
 use crate::utils::*;
 
-use std::collections::{HashMap, HashSet, VecDeque};
 use std::io;
 
-type Graph = HashMap<String, HashSet<String>>;
-
 pub struct AoC2023_25 {
     graph: Graph,
 }
@@ -17,21 +15,15 @@ impl AoC2023_25 {
     }
 
     fn with_lines(lines: &[String]) -> Self {
-        let mut graph = Graph::new();
+        let mut names = String2IdMapper::new();
+        let mut graph = Graph::default();
         lines
             .iter()
             .map(|s| s.split_once(": ").expect("Incorrect format (1)"))
             .for_each(|(v, adj)| {
-                let adj = adj.split(' ').collect::<Vec<_>>();
-                for item in adj {
-                    {
-                        let entry = graph.entry(v.to_string()).or_default();
-                        entry.insert(item.to_string());
-                    }
-                    {
-                        let entry = graph.entry(item.to_string()).or_default();
-                        entry.insert(v.to_string());
-                    }
+                let v = names.get_id(v);
+                for item in adj.split(' ') {
+                    graph.add_edge(v, names.get_id(item), 1);
                 }
             });
         Self { graph }
@@ -40,26 +32,8 @@ impl AoC2023_25 {
 
 impl Solution for AoC2023_25 {
     fn part_one(&self) -> String {
-        let mut frequency = EdgeFrequency::new();
-
-        for vertex in self.graph.keys().take(self.graph.len() / 10) {
-            bfs(&self.graph, vertex, &mut frequency);
-        }
-
-        let mut arr = frequency.into_iter().collect::<Vec<_>>();
-        arr.sort_by_key(|x| x.1);
-        arr = arr.into_iter().rev().take(3).collect::<Vec<_>>();
-
-        let mut graph = self.graph.clone();
-        arr.iter().for_each(|(edge, _)| {
-            graph.get_mut(&edge.0).unwrap().remove(&edge.1);
-            graph.get_mut(&edge.1).unwrap().remove(&edge.0);
-        });
-
-        let any = &arr.first().unwrap().0;
-        let len = bfs(&graph, &any.0, &mut HashMap::new());
-
-        (len * (self.graph.len() - len)).to_string()
+        let (_, side) = self.graph.min_cut().expect("Graph is too small");
+        (side.len() * (self.graph.len() - side.len())).to_string()
     }
 
     fn description(&self) -> String {
@@ -67,35 +41,6 @@ impl Solution for AoC2023_25 {
     }
 }
 
-type EdgeFrequency = HashMap<(String, String), usize>;
-
-fn bfs(graph: &Graph, initial: &str, edge_frequency: &mut EdgeFrequency) -> usize {
-    let mut dequeue = VecDeque::new();
-    dequeue.push_back(initial);
-    let mut seen = HashSet::new();
-    seen.insert(initial);
-    let mut length = 0;
-    while let Some(vertex) = dequeue.pop_front() {
-        length += 1;
-        for other in graph.get(vertex).expect("(1)").iter() {
-            if seen.contains(other.as_str()) {
-                continue;
-            }
-            seen.insert(other);
-            dequeue.push_back(other);
-
-            let edge = if matches!(vertex.cmp(other), std::cmp::Ordering::Less) {
-                (vertex.to_string(), other.to_string())
-            } else {
-                (other.to_string(), vertex.to_string())
-            };
-            let count = edge_frequency.entry(edge).or_default();
-            *count += 1;
-        }
-    }
-    length
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -104,9 +49,9 @@ mod test {
     fn aoc2023_25_input_load_test() -> io::Result<()> {
         let sol = AoC2023_25::new()?;
         let graph = sol.graph;
-        assert!(!graph.is_empty());
-        for (_, adj) in graph {
-            assert!(!adj.is_empty());
+        assert!(graph.len() > 1);
+        for vertex in 0..graph.len() {
+            assert!(graph.neighbours(vertex).next().is_some());
         }
         Ok(())
     }
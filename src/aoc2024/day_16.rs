@@ -1,7 +1,8 @@
 use crate::solution::Solution;
+use crate::utils::search::{self, AllShortestPaths};
 use crate::utils::*;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 use std::fs::read_to_string;
 use std::io;
 
@@ -27,41 +28,44 @@ impl AoC2024_16 {
     }
 }
 
-impl Solution for AoC2024_16 {
-    fn part_one(&self) -> String {
+impl AoC2024_16 {
+    /// All cheapest paths from the start and the end states reached at the lowest cost
+    fn best_paths(&self) -> (AllShortestPaths<Node, usize>, Vec<Node>) {
         let start = get_first_position(&self.map, START).expect("Start position not found");
         let end = get_first_position(&self.map, END).expect("End position not found");
-        calc_lower_cost(&self.map, start, end, &mut HashMap::new())
-            .map(|x| x.0.to_string())
+        let paths = search::dijkstra_all([(start, Direction::Right)], |node| {
+            next_nodes(&self.map, node)
+        });
+        let end_nodes = Direction::all().map(|dir| (end, dir));
+        let lowest = end_nodes
+            .iter()
+            .filter_map(|node| paths.distance(node))
+            .min();
+        let ends = end_nodes
+            .into_iter()
+            .filter(|node| lowest.is_some() && paths.distance(node) == lowest)
+            .collect();
+        (paths, ends)
+    }
+}
+
+impl Solution for AoC2024_16 {
+    fn part_one(&self) -> String {
+        let (paths, ends) = self.best_paths();
+        ends.first()
+            .and_then(|node| paths.distance(node))
+            .map(|x| x.to_string())
             .unwrap_or("Path not found".to_string())
     }
 
     fn part_two(&self) -> String {
-        let start = get_first_position(&self.map, START).expect("Start position not found");
-        let end = get_first_position(&self.map, END).expect("End position not found");
-        let mut path_map = HashMap::new();
-        let Some((_, dir)) = calc_lower_cost(&self.map, start, end, &mut path_map) else {
-            return 0.to_string();
-        };
-
-        let mut nodes = path_map.get(&(end, dir)).expect("????").clone();
-        let mut path = HashSet::new();
-
-        while !nodes.is_empty() {
-            let mut next = HashSet::new();
-            for node in &nodes {
-                path.insert(node.0);
-                let Some(adj) = path_map.get(node) else {
-                    continue;
-                };
-                for elem in adj {
-                    next.insert(*elem);
-                }
-            }
-            nodes = next;
-        }
-
-        (1 + path.len()).to_string()
+        let (paths, ends) = self.best_paths();
+        ends.iter()
+            .flat_map(|node| paths.states_on_paths(node))
+            .map(|(position, _)| position)
+            .collect::<HashSet<_>>()
+            .len()
+            .to_string()
     }
 
     fn description(&self) -> String {
@@ -79,81 +83,21 @@ const TURN_COST: usize = 1000;
 type Position = Point2d<usize>;
 type Node = (Position, Direction);
 
-fn calc_lower_cost(
-    map: &[Vec<char>],
-    start: Position,
-    end: Position,
-    path_map: &mut HashMap<Node, HashSet<Node>>,
-) -> Option<(usize, Direction)> {
-    let mut queue = vec![(start, Direction::Right)];
-    let mut table = HashMap::new();
-    for item in &queue {
-        table.insert(*item, 0usize);
-    }
-    ///////
-    while let Some(elem) = queue.pop() {
-        let (p, dir) = elem;
-        if p == end {
-            continue;
-        }
-
-        let cost = *table.get(&elem).expect("cost not found: bug in code (1)");
-
-        let is_vertical = dir.is_vertical();
-
-        for next in Direction::all().iter().map(|dir| (p.moved_by(dir), *dir)) {
-            let (n_pos, n_dir) = next;
-            if map[n_pos.y][n_pos.x] == WALL {
-                continue;
-            }
-            let next_cost = cost
-                + if is_vertical == n_dir.is_vertical() {
-                    STEP_COST
-                } else {
-                    STEP_COST + TURN_COST
-                };
-
-            let mut is_better = true;
-            let mut is_equal = false;
-            if let Some(old_cost) = table.get(&next) {
-                is_better = *old_cost > next_cost;
-                is_equal = *old_cost == next_cost;
-            }
-
-            let path_entry = path_map.entry(next).or_default();
-            if is_better {
-                table.insert(next, next_cost);
-                queue.push(next);
-                path_entry.clear();
-                path_entry.insert(elem);
-            } else if is_equal {
-                path_entry.insert(elem);
-            }
-        }
-    }
-    ///////
-    let mut result: Option<(usize, Direction)> = None;
-    for dir in Direction::all() {
-        let pos = (end, dir);
-        let Some(val) = table.get(&pos) else {
-            continue;
-        };
-        let mut is_better = true;
-        if let Some(cur) = result {
-            is_better = cur.0 > *val
-        }
-        if is_better {
-            result = Some((*val, dir));
-        }
-    }
-    result
-    // Direction::all()
-    //     .iter()
-    //     .map(|dir| (end, *dir))
-    //     .filter_map(|tuple| table.get(&tuple))
-    //     .copied()
-    //     .inspect(|x| println!("{x}"))
-    //     .min()
+fn next_nodes(map: &[Vec<char>], node: &Node) -> Vec<(Node, usize)> {
+    let (position, dir) = node;
+    Direction::all()
+        .iter()
+        .map(|next_dir| (position.moved_by(next_dir), *next_dir))
+        .filter(|(next, _)| map[next.y][next.x] != WALL)
+        .map(|next| {
+            let cost = if dir.is_vertical() == next.1.is_vertical() {
+                STEP_COST
+            } else {
+                STEP_COST + TURN_COST
+            };
+            (next, cost)
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -223,7 +167,7 @@ mod test {
 #S#.............#
 #################";
         let puzzle = AoC2024_16::with_str(input);
-        // assert_eq!("11048", puzzle.part_one());
+        assert_eq!("11048", puzzle.part_one());
         assert_eq!("64", puzzle.part_two());
     }
 
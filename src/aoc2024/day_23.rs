@@ -1,13 +1,16 @@
 use crate::solution::Solution;
+use crate::utils::graph::Graph;
+use crate::utils::string2id::intern_id;
 use crate::utils::*;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 use std::io;
 
-type StringPair = (String, String);
+intern_id!(Computer);
 
 pub struct AoC2024_23 {
-    input: Vec<StringPair>,
+    input: Vec<(Computer, Computer)>,
+    names: String2IdMapper<Computer>,
 }
 
 impl AoC2024_23 {
@@ -17,36 +20,41 @@ impl AoC2024_23 {
     }
 
     fn with_lines<T: AsRef<str>>(lines: &[T]) -> Self {
+        let mut names = String2IdMapper::default();
         let input = lines
             .iter()
             .map(|s| s.as_ref())
             .map(|s| s.trim())
             .filter(|s| !s.is_empty())
             .map(|s| s.split_once("-").expect("Invalid input format"))
-            .map(|(a, b)| (a.to_string(), b.to_string()))
+            .map(|(a, b)| (names.get_id(a), names.get_id(b)))
             .collect::<Vec<_>>();
-        Self { input }
+        names.freeze();
+        Self { input, names }
+    }
+
+    fn graph(&self) -> Graph<Computer> {
+        let mut graph = Graph::with_vertices(self.names.len());
+        for (first, second) in &self.input {
+            graph.add_edge(*first, *second, 1);
+        }
+        graph
     }
 }
 
 impl Solution for AoC2024_23 {
     fn part_one(&self) -> String {
-        let map = make_connection_map(&self.input);
+        let graph = self.graph();
         let mut output = HashSet::new();
-        for (key, val) in map.iter() {
-            if !key.starts_with('t') {
+        for (key, name) in self.names.iter() {
+            if !name.starts_with('t') {
                 continue;
             }
-            if val.len() < 2 {
-                continue;
-            }
-            for b in val {
-                for c in val {
-                    if b == c {
-                        continue;
-                    }
-                    if check_triple(&map, key, b, c) {
-                        let mut arr = vec![key.to_string(), b.to_string(), c.to_string()];
+            let val = graph.neighbours(key).collect::<Vec<_>>();
+            for b in &val {
+                for c in &val {
+                    if b < c && graph.neighbours(*b).any(|x| x == *c) {
+                        let mut arr = [key, *b, *c];
                         arr.sort();
                         output.insert(arr);
                     }
@@ -57,8 +65,14 @@ impl Solution for AoC2024_23 {
     }
 
     fn part_two(&self) -> String {
-        let map = make_connection_map(&self.input);
-        find_largest_set(&map).to_string()
+        let mut names = self
+            .graph()
+            .maximum_clique()
+            .into_iter()
+            .map(|id| self.names.name(id))
+            .collect::<Vec<_>>();
+        names.sort();
+        names.join(",")
     }
 
     fn description(&self) -> String {
@@ -66,63 +80,6 @@ impl Solution for AoC2024_23 {
     }
 }
 
-type ConnectionMap = HashMap<String, HashSet<String>>;
-
-fn find_largest_set(map: &ConnectionMap) -> String {
-    let keys = map.keys().cloned().collect::<Vec<_>>();
-    let mut candidates = map
-        .keys()
-        .map(|x| vec![x.to_string()])
-        .collect::<HashSet<_>>();
-    let mut best = String::new();
-    while !candidates.is_empty() {
-        let mut next = HashSet::new();
-        for candidate in &candidates {
-            for key in &keys {
-                if candidate.contains(key) {
-                    continue;
-                }
-                let is_valid = candidate
-                    .iter()
-                    .all(|node| map.get(node).expect("Shouldn't be empty").contains(key));
-                if is_valid {
-                    let mut tmp = candidate.clone();
-                    tmp.push(key.clone());
-                    tmp.sort();
-                    next.insert(tmp);
-                }
-            }
-        }
-        if let Some(arr) = next.iter().next() {
-            best = arr.join(",");
-        }
-        candidates = next;
-    }
-    best
-}
-
-fn check_triple(map: &ConnectionMap, a: &str, b: &str, c: &str) -> bool {
-    let check = |a: &str, b: &str, c: &str| -> bool {
-        let Some(a_set) = map.get(a) else {
-            return false;
-        };
-        a_set.contains(b) && a_set.contains(c)
-    };
-
-    check(a, b, c) && check(b, a, c) && check(c, a, b)
-}
-
-fn make_connection_map(input: &[StringPair]) -> ConnectionMap {
-    let mut map = ConnectionMap::new();
-    for (first, second) in input {
-        let entry = map.entry(first.clone()).or_default();
-        entry.insert(second.clone());
-        let entry = map.entry(second.clone()).or_default();
-        entry.insert(first.clone());
-    }
-    map
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -59,16 +59,15 @@ type Position = Point2d<usize>;
 
 fn reachable_positions(diagram: &Vec2<char>) -> Vec<Position> {
     let mut positions = Vec::new();
-    let directions = Direction::circular_directions();
     for (row, arr) in diagram.iter().enumerate() {
         for (col, ch) in arr.iter().enumerate() {
             if *ch != DIAGRAM_ROLL {
                 continue;
             }
             let p = Point2d::new(col, row);
-            let adjacent_count = directions
+            let adjacent_count = Compass::all()
                 .iter()
-                .filter_map(|dir| p.safe_moved_with_dirs(dir))
+                .filter_map(|compass| p.safe_moved_by_compass(compass))
                 .filter(|adj| {
                     let Some(val) = diagram.get(adj.y).and_then(|v| v.get(adj.x)) else {
                         return false;
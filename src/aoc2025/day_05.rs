@@ -1,18 +1,15 @@
-use crate::{solution::Solution, utils::PlainInterval};
+use crate::{
+    solution::Solution,
+    utils::{interval_set::IntervalSet, PlainInterval},
+};
 
 use std::io;
 
 type Int = usize;
 type RangeInt = PlainInterval<Int>;
 
-impl RangeInt {
-    fn contains(&self, value: &Int) -> bool {
-        self.begin <= *value && *value <= self.end
-    }
-}
-
 pub struct AoC2025_05 {
-    ranges: Vec<RangeInt>,
+    ranges: IntervalSet<Int>,
     ingredients: Vec<Int>,
 }
 
@@ -29,7 +26,7 @@ impl AoC2025_05 {
             .map(|s| s.trim())
             .filter(|s| !s.is_empty())
             .map(Self::parse_range)
-            .collect::<Vec<_>>();
+            .collect::<IntervalSet<_>>();
 
         let ingredients = ingredients
             .split('\n')
@@ -56,42 +53,13 @@ impl Solution for AoC2025_05 {
     fn part_one(&self) -> String {
         self.ingredients
             .iter()
-            .filter(|elem| self.ranges.iter().any(|rng| rng.contains(elem)))
+            .filter(|elem| self.ranges.contains(**elem))
             .count()
             .to_string()
     }
 
     fn part_two(&self) -> String {
-        let mut ranges = self.ranges.clone();
-        loop {
-            let mut intersection: Option<Intersection> = None;
-            'search: for (f, first) in ranges.iter().enumerate() {
-                for (s, second) in ranges.iter().enumerate().skip(f + 1) {
-                    let Some(merged) = first.union(second) else {
-                        continue;
-                    };
-                    intersection = Some(Intersection {
-                        first: f,
-                        second: s,
-                        merged,
-                    });
-                    break 'search;
-                }
-            }
-            let Some(intersection) = intersection else {
-                break;
-            };
-
-            swap_with_last_and_remove(&mut ranges, intersection.second);
-            swap_with_last_and_remove(&mut ranges, intersection.first);
-            ranges.push(intersection.merged);
-        }
-
-        ranges
-            .iter()
-            .map(|r| r.end - r.begin + 1)
-            .sum::<Int>()
-            .to_string()
+        self.ranges.len().to_string()
     }
 
     fn description(&self) -> String {
@@ -99,21 +67,6 @@ impl Solution for AoC2025_05 {
     }
 }
 
-struct Intersection {
-    first: usize,
-    second: usize,
-    merged: RangeInt,
-}
-
-fn swap_with_last_and_remove<T>(arr: &mut Vec<T>, index: usize) {
-    if arr.is_empty() {
-        return;
-    }
-    let last_idx = arr.len() - 1;
-    arr.swap(index, last_idx);
-    arr.pop();
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
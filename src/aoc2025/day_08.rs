@@ -1,30 +1,19 @@
 use crate::solution::Solution;
+use crate::utils::dsu::Dsu;
 use crate::utils::hyper_point::HyperPoint;
 use crate::utils::*;
 
-use std::collections::HashMap;
 use std::io;
 
 type Int = isize;
 type Point = HyperPoint<Int>;
 
-impl From<&str> for Point {
-    fn from(value: &str) -> Self {
-        let coordinates = value
-            .split(',')
-            .map(|x| x.parse::<Int>().expect("Invalid coordinate format"))
-            .collect::<Vec<_>>();
-        Self::from(coordinates)
-    }
-}
-
 impl Point {
     fn dist_sqr(&self, other: &Self) -> usize {
-        assert_eq!(self.dimension(), other.dimension());
-        self.0
+        self.sub(other)
+            .0
             .iter()
-            .zip(other.0.iter())
-            .map(|(a, b)| a.abs_diff(*b).pow(2))
+            .map(|x| x.unsigned_abs().pow(2))
             .sum()
     }
 }
@@ -42,8 +31,7 @@ impl AoC2025_08 {
     fn parse_lines<T: AsRef<str>>(lines: &[T]) -> Self {
         let input = lines
             .iter()
-            .map(|x| x.as_ref())
-            .map(Point::from)
+            .map(|x| Point::from_csv(x.as_ref()).expect("Invalid coordinate format"))
             .collect::<Vec<_>>();
         Self { input }
     }
@@ -54,13 +42,12 @@ impl AoC2025_08 {
             _ = engine.make_connection();
         }
 
-        // calc chain's sizes
-        let mut size_map = HashMap::new();
-        engine.chain_mapping.iter().for_each(|x| {
-            let entry = size_map.entry(*x).or_insert(0usize);
-            *entry += 1;
-        });
-        let mut arr = size_map.values().collect::<Vec<_>>();
+        let mut arr = engine
+            .circuits
+            .components()
+            .iter()
+            .map(|x| x.len())
+            .collect::<Vec<_>>();
         arr.sort();
         arr.into_iter().rev().take(3).product::<usize>().to_string()
     }
@@ -77,10 +64,7 @@ impl Solution for AoC2025_08 {
         while let Some(value) = engine.make_connection() {
             first = value.first;
             second = value.second;
-
-            let mut iter = engine.chain_mapping.iter();
-            let first = iter.next().expect("Chain map shouldn't be empty");
-            if iter.all(|val| val == first) {
+            if engine.circuits.component_count() == 1 {
                 break;
             }
         }
@@ -96,16 +80,16 @@ impl Solution for AoC2025_08 {
 }
 
 struct Engine {
-    chain_mapping: Vec<usize>,
+    circuits: Dsu,
     distances: Vec<DistanceData>,
 }
 
 impl Engine {
     fn with(points: &[Point]) -> Self {
-        let chain_mapping = (0..points.len()).collect::<Vec<_>>();
+        let circuits = Dsu::new(points.len());
         let distances = Self::precalculate_distances(points);
         Self {
-            chain_mapping,
+            circuits,
             distances,
         }
     }
@@ -129,13 +113,7 @@ impl Engine {
 
     fn make_connection(&mut self) -> Option<DistanceData> {
         let data = self.distances.pop()?;
-        // merge chains
-        let chain_1 = self.chain_mapping[data.first];
-        let chain_2 = self.chain_mapping[data.second];
-        self.chain_mapping
-            .iter_mut()
-            .filter(|x| **x == chain_2)
-            .for_each(|x| *x = chain_1);
+        self.circuits.union(data.first, data.second);
         Some(data)
     }
 }
@@ -1,4 +1,5 @@
 use crate::solution::Solution;
+use crate::utils::geometry::rectangle_inside;
 use crate::utils::*;
 
 use std::io;
@@ -39,50 +40,11 @@ impl Solution for AoC2025_09 {
     }
 
     fn part_two(&self) -> String {
-        let len = self.input.len();
-        let intervals = self
-            .input
-            .iter()
-            .enumerate()
-            .map(|(i, cur)| {
-                let next = self.input[(i + 1) % len];
-                Interval2d::with(cur, &next)
-            })
-            .collect::<Vec<_>>();
-
         let mut result = 0;
         for (i, a) in self.input.iter().enumerate() {
             for b in self.input.iter().skip(i + 1) {
-                let diagonal = Interval2d::with(a, b);
-                let square = diagonal.square();
-                if square <= result {
-                    continue;
-                }
-                let mut is_valid = true;
-                for segment in intervals.iter() {
-                    let Some(intersection) = diagonal.intersection(segment) else {
-                        continue;
-                    };
-
-                    // edge check
-                    if intersection.x_interval.len() == 1 {
-                        // if vertical
-                        let x = intersection.x_interval.begin;
-                        if diagonal.x_interval.is_edge(x) {
-                            continue;
-                        }
-                    }
-                    if intersection.y_interval.len() == 1 {
-                        // if horizontal
-                        let y = intersection.y_interval.begin;
-                        if diagonal.y_interval.is_edge(y) {
-                            continue;
-                        }
-                    }
-                    is_valid = false;
-                    break;
-                }
-                if is_valid {
+                let square = Interval2d::with(a, b).square();
+                if square > result && rectangle_inside(&self.input, *a, *b) {
                     result = square;
                 }
             }
@@ -111,22 +73,9 @@ impl Interval2d {
     fn square(&self) -> usize {
         self.x_interval.len() * self.y_interval.len()
     }
-
-    fn intersection(&self, other: &Self) -> Option<Self> {
-        let x_interval = self.x_interval.intersection(&other.x_interval)?;
-        let y_interval = self.y_interval.intersection(&other.y_interval)?;
-        Some(Self {
-            x_interval,
-            y_interval,
-        })
-    }
 }
 
 impl PlainInterval<Int> {
-    fn is_edge(&self, value: Int) -> bool {
-        value == self.begin || value == self.end
-    }
-
     fn len(&self) -> usize {
         self.begin.abs_diff(self.end) + 1
     }
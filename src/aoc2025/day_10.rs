@@ -1,5 +1,5 @@
 use crate::solution::Solution;
-use crate::utils::simplex::solve_ilp_bnb;
+use crate::utils::lp::{Goal, LinearProgram, Relation};
 use crate::utils::*;
 
 use std::collections::{HashMap, VecDeque};
@@ -43,9 +43,8 @@ impl Solution for AoC2025_10 {
     fn part_two(&self) -> String {
         self.input
             .iter()
-            .map(solve_lp)
-            .map(|x| x as usize)
-            .sum::<usize>()
+            .map(joltage_setup_presses)
+            .sum::<i128>()
             .to_string()
     }
 
@@ -114,35 +113,28 @@ fn parse_indicators(s: &str) -> usize {
         .fold(0usize, |acc, ch| acc << 1 | if ch == '#' { 1 } else { 0 })
 }
 
-fn solve_lp(config: &MachineConfiguration) -> f64 {
-    let num_goals = config.joltage.len();
-    let num_buttons = config.buttons.len();
-
-    let rows = 2 * num_goals + num_buttons;
-    let cols = num_buttons + 1;
-
-    let mut matrix = vec![vec![0.0; cols]; rows];
-
-    for (j, row) in matrix.iter_mut().rev().take(num_buttons).enumerate() {
-        row[j] = -1.0;
-    }
-
-    for (j, button) in config.buttons.iter().enumerate() {
-        for &i in button {
-            matrix[i][j] = 1.0;
-            matrix[i + num_goals][j] = -1.0;
-        }
-    }
-
-    for i in 0..num_goals {
-        let val = config.joltage[i] as f64;
-        matrix[i][cols - 1] = val;
-        matrix[i + num_goals][cols - 1] = -val;
-    }
-
-    let obj_coeffs = vec![1.0; num_buttons];
-    let val = solve_ilp_bnb(matrix, &obj_coeffs);
-    val.round()
+fn joltage_setup_presses(config: &MachineConfiguration) -> i128 {
+    let mut lp = LinearProgram::new(Goal::Minimize);
+    let presses = config
+        .buttons
+        .iter()
+        .map(|_| lp.add_integer_variable())
+        .collect::<Vec<_>>();
+    lp.set_objective(&presses.iter().map(|x| (*x, 1)).collect::<Vec<_>>());
+    for (i, joltage) in config.joltage.iter().enumerate() {
+        let terms = config
+            .buttons
+            .iter()
+            .zip(&presses)
+            .filter(|(button, _)| button.contains(&i))
+            .map(|(_, x)| (*x, 1))
+            .collect::<Vec<_>>();
+        lp.add_constraint(&terms, Relation::Equal, *joltage as i64);
+    }
+    lp.solve()
+        .optimal()
+        .and_then(|x| x.objective().to_integer())
+        .expect("Machine must be configurable")
 }
 
 #[cfg(test)]
@@ -192,6 +184,18 @@ mod test {
         assert_eq!(config.buttons[5], [0, 1]);
     }
 
+    #[test]
+    fn aoc2025_10_case_1() {
+        let input = [
+            "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}",
+            "[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}",
+            "[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}",
+        ];
+        let puzzle = AoC2025_10::parse_lines(&input);
+        assert_eq!(puzzle.part_one(), "7");
+        assert_eq!(puzzle.part_two(), "33");
+    }
+
     fn make_solution() -> io::Result<AoC2025_10> {
         AoC2025_10::new()
     }
@@ -1,52 +1,182 @@
 use std::path::{Path, PathBuf};
 
+use advent_of_code::load_aoc_config;
+
 use crate::generror::GenError;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    /// Generate year module and (optionally) day module
+    New,
+    /// Append example test to the existing day module
+    Example,
+    /// Check day modules for the project conventions
+    Lint,
+    /// Save puzzle description as markdown
+    Puzzle,
+    /// Update statistics section in the README
+    Readme,
+}
+
+pub enum PuzzleSource {
+    /// Saved html page
+    File(PathBuf),
+    /// Page to be fetched from the site
+    Remote {
+        base_url: String,
+        session_file: PathBuf,
+    },
+}
+
+pub struct ExampleData {
+    pub input_path: PathBuf,
+    pub expect_part_one: Option<String>,
+    pub expect_part_two: Option<String>,
+}
+
 pub struct Context {
     current_dir: PathBuf,
-    year: usize,
+    command: Command,
+    year: Option<usize>,
     day: Option<usize>,
+    example: Option<ExampleData>,
+    input_dir: String,
+    puzzle_source: PuzzleSource,
+    report: Option<PathBuf>,
 }
 
 impl Context {
     pub fn create() -> Result<Context, GenError> {
         let current_dir = std::env::current_dir()?;
+        let mut command: Option<Command> = None;
         let mut year: Option<usize> = None;
         let mut day: Option<usize> = None;
-        for arg in std::env::args() {
-            let is_year = arg.starts_with("-y");
-            let is_day = arg.starts_with("-d");
-            if !(is_year || is_day) {
-                continue;
+        let mut example: Option<PathBuf> = None;
+        let mut expect_part_one: Option<String> = None;
+        let mut expect_part_two: Option<String> = None;
+        let mut html: Option<PathBuf> = None;
+        let mut base_url: Option<String> = None;
+        let mut report: Option<PathBuf> = None;
+
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            let mut value_for = |name: &str| -> Result<String, GenError> {
+                args.next()
+                    .ok_or_else(|| GenError::new(format!("Value for '{name}' is missing")))
+            };
+            match arg.as_str() {
+                "new" if command.is_none() => command = Some(Command::New),
+                "example" if command.is_none() => command = Some(Command::Example),
+                "lint" if command.is_none() => command = Some(Command::Lint),
+                "puzzle" if command.is_none() => command = Some(Command::Puzzle),
+                "readme" if command.is_none() => command = Some(Command::Readme),
+                "-y" => year = Some(value_for("-y")?.parse::<usize>()?),
+                "-d" => day = Some(value_for("-d")?.parse::<usize>()?),
+                "--example" => example = Some(PathBuf::from(value_for("--example")?)),
+                "--expect1" => expect_part_one = Some(value_for("--expect1")?),
+                "--expect2" => expect_part_two = Some(value_for("--expect2")?),
+                "--html" => html = Some(PathBuf::from(value_for("--html")?)),
+                "--base-url" => base_url = Some(value_for("--base-url")?),
+                "--report" => report = Some(PathBuf::from(value_for("--report")?)),
+                _ if arg.starts_with("-y") => year = Some(arg[2..].parse::<usize>()?),
+                _ if arg.starts_with("-d") => day = Some(arg[2..].parse::<usize>()?),
+                _ => return Err(GenError::new(format!("Unexpected argument '{arg}'"))),
             }
-            let val = arg[2..].parse::<usize>()?;
-            if is_year {
-                year = Some(val);
-                continue;
+        }
+
+        let command = command.unwrap_or(Command::New);
+
+        if year.is_none() && !matches!(command, Command::Lint | Command::Readme) {
+            return Err(GenError::new("Year '-y' parameter is missing"));
+        }
+
+        let has_expectations = expect_part_one.is_some() || expect_part_two.is_some();
+        let example = match example {
+            Some(input_path) => {
+                if day.is_none() {
+                    return Err(GenError::new("Day '-d' parameter is required for example"));
+                }
+                if !has_expectations {
+                    return Err(GenError::new(
+                        "Example requires at least one of '--expect1' or '--expect2'",
+                    ));
+                }
+                Some(ExampleData {
+                    input_path,
+                    expect_part_one,
+                    expect_part_two,
+                })
             }
-            if is_day {
-                day = Some(val);
-                continue;
+            None if has_expectations => {
+                return Err(GenError::new("Expected answers given without '--example'"));
             }
+            None => None,
+        };
+
+        if command == Command::Example && example.is_none() {
+            return Err(GenError::new(
+                "Example file '--example' parameter is missing",
+            ));
         }
 
-        let Some(year) = year else {
-            return Err(GenError::new("Year '-y' parameter is missing"));
+        if command == Command::Puzzle && day.is_none() {
+            return Err(GenError::new("Day '-d' parameter is missing"));
+        }
+
+        let config = load_aoc_config().unwrap_or_default();
+        let input_dir = config.paths.input_dir;
+        let puzzle_source = match html {
+            Some(path) => PuzzleSource::File(path),
+            None => PuzzleSource::Remote {
+                base_url: base_url.unwrap_or(config.remote.base_url),
+                session_file: PathBuf::from(config.remote.session_file),
+            },
         };
 
         let ctxt = Context {
             current_dir,
+            command,
             year,
             day,
+            example,
+            input_dir,
+            puzzle_source,
+            report,
         };
 
         Ok(ctxt)
     }
 
+    pub fn command(&self) -> Command {
+        self.command
+    }
+
     pub fn year(&self) -> usize {
+        self.year.expect("Year is checked on context creation")
+    }
+
+    /// Optional year restriction for the commands that process all years
+    pub fn year_filter(&self) -> Option<usize> {
         self.year
     }
 
+    pub fn example(&self) -> Option<&ExampleData> {
+        self.example.as_ref()
+    }
+
+    pub fn input_dir(&self) -> &str {
+        &self.input_dir
+    }
+
+    pub fn puzzle_source(&self) -> &PuzzleSource {
+        &self.puzzle_source
+    }
+
+    /// Saved JSON lines report of the puzzles execution
+    pub fn report_path(&self) -> Option<&Path> {
+        self.report.as_deref()
+    }
+
     pub fn is_marker_file_exists(&self) -> bool {
         self.marker_file_path().exists()
     }
@@ -55,12 +185,16 @@ impl Context {
         extend_path(&self.current_dir, "aoc.toml")
     }
 
-    fn source_folder(&self) -> PathBuf {
+    pub fn readme_file_path(&self) -> PathBuf {
+        extend_path(&self.current_dir, "README.md")
+    }
+
+    pub fn source_folder(&self) -> PathBuf {
         extend_path(&self.current_dir, "src")
     }
 
     pub fn year_folder(&self) -> PathBuf {
-        extend_path(&self.source_folder(), format!("aoc{}", self.year))
+        extend_path(&self.source_folder(), format!("aoc{}", self.year()))
     }
 
     pub fn year_mod_file_path(&self) -> PathBuf {
@@ -71,6 +205,11 @@ impl Context {
         extend_path(&self.source_folder(), "main.rs")
     }
 
+    pub fn puzzles_year_folder(&self) -> PathBuf {
+        let puzzles_folder = extend_path(&self.current_dir, "puzzles");
+        extend_path(&puzzles_folder, self.year().to_string())
+    }
+
     pub fn day_module_data(&self) -> Option<DayGenData> {
         let day = self.day?;
         let module_name = format!("day_{:02}", day);
@@ -80,18 +219,21 @@ impl Context {
             extend_path(&self.year_folder(), file)
         };
 
-        let struct_name = format!("AoC{}_{day:02}", self.year);
+        let struct_name = format!("AoC{}_{day:02}", self.year());
 
-        let day_input_file_name = format!("input/aoc{}_{day:02}", self.year());
+        let day_input_file_name = format!("{}/aoc{}_{day:02}", self.input_dir, self.year());
 
         let day_test_func_name_prefix = struct_name.to_lowercase();
 
+        let puzzle_file_path = extend_path(&self.puzzles_year_folder(), format!("{day:02}.md"));
+
         Some(DayGenData {
             module_name,
             module_file_path,
             struct_name,
             day_input_file_name,
             day_test_func_name_prefix,
+            puzzle_file_path,
             day,
         })
     }
@@ -109,5 +251,6 @@ pub struct DayGenData {
     pub struct_name: String,
     pub day_input_file_name: String,
     pub day_test_func_name_prefix: String,
+    pub puzzle_file_path: PathBuf,
     pub day: usize,
 }
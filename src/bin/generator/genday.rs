@@ -1,10 +1,12 @@
 use std::path::Path;
 
-use advent_of_code::{file_to_string_array, write_aoc_config, AocConfig, PuzzleConfig};
+use advent_of_code::{file_to_string_array, update_aoc_puzzle_config, PuzzleConfig};
 
 use crate::{
-    context::{Context, DayGenData},
+    context::{Context, DayGenData, ExampleData},
     generror::{GenError, GenResult},
+    genexample::make_example_test,
+    genpuzzle::read_puzzle_title,
     str_to_file, string_array_to_file,
 };
 
@@ -19,23 +21,32 @@ pub fn generate_day(context: &Context) -> GenResult<()> {
         ));
     }
 
-    create_file(&day_data)?;
+    create_file(&day_data, context.example())?;
     patch_year_module(&context.year_mod_file_path(), &day_data)?;
     update_marker_file(context.year(), day_data.day)?;
     Ok(())
 }
 
-fn create_file(data: &DayGenData) -> std::io::Result<()> {
+fn create_file(data: &DayGenData, example: Option<&ExampleData>) -> GenResult<()> {
     let mut output = TEMPLATE_DAY_MODULE.to_string();
 
     output = output.replace(PLACEHOLDER_STRUCT_NAME, &data.struct_name);
     output = output.replace(PLACEHOLDER_INPUT_FILENAME, &data.day_input_file_name);
+    let description = read_puzzle_title(&data.puzzle_file_path).unwrap_or_default();
+    output = output.replace(PLACEHOLDER_DESCRIPTION, &description.replace('"', "\\\""));
     output = output.replace(
         PLACEHOLDER_TEST_FUNC_PREFIX,
         &data.day_test_func_name_prefix,
     );
 
-    str_to_file(&data.module_file_path, &output)
+    let example_tests = match example {
+        Some(example) => make_example_test(data, example, 1)? + "\n\n",
+        None => String::new(),
+    };
+    output = output.replace(PLACEHOLDER_EXAMPLE_TESTS, &example_tests);
+
+    str_to_file(&data.module_file_path, &output)?;
+    Ok(())
 }
 
 fn patch_year_module(year_mod_file_path: &Path, data: &DayGenData) -> GenResult<()> {
@@ -63,8 +74,7 @@ fn patch_year_module(year_mod_file_path: &Path, data: &DayGenData) -> GenResult<
 
 fn update_marker_file(year: usize, day: usize) -> GenResult<()> {
     let puzzle = PuzzleConfig::with(year, day);
-    let config = AocConfig { puzzle };
-    write_aoc_config(config)?;
+    update_aoc_puzzle_config(&puzzle)?;
     Ok(())
 }
 
@@ -75,6 +85,8 @@ const PLACEHOLDER_STRUCT_NAME: &str = "${STRUCT_NAME}";
 const PLACEHOLDER_INPUT_FILENAME: &str = "${INPUT_FILENAME}";
 const PLACEHOLDER_TEST_FUNC_PREFIX: &str = "${TEST_FUNC_PREFIX}";
 const PLACEHOLDER_MODULE_NAME: &str = "${MODULE_NAME}";
+const PLACEHOLDER_EXAMPLE_TESTS: &str = "${EXAMPLE_TESTS}";
+const PLACEHOLDER_DESCRIPTION: &str = "${DESCRIPTION}";
 
 const TEMPLATE_INCLUDE_DAY: &str = r#"mod ${MODULE_NAME};
 use ${MODULE_NAME}::*;
@@ -114,7 +126,7 @@ impl Solution for ${STRUCT_NAME} {
     // }
 
     fn description(&self) -> String {
-        "".to_string()
+        "${DESCRIPTION}".to_string()
     }
 }
 
@@ -144,7 +156,7 @@ mod test {
         Ok(())
     }
 
-    fn make_solution() -> io::Result<${STRUCT_NAME}> {
+${EXAMPLE_TESTS}    fn make_solution() -> io::Result<${STRUCT_NAME}> {
         ${STRUCT_NAME}::new()
     }
 }
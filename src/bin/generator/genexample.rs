@@ -0,0 +1,266 @@
+use crate::{
+    context::{Context, DayGenData, ExampleData},
+    generror::{GenError, GenResult},
+    str_to_file,
+};
+
+pub fn add_example(context: &Context) -> GenResult<()> {
+    let Some(day_data) = context.day_module_data() else {
+        return Err(GenError::new("Day '-d' parameter is missing"));
+    };
+    let Some(example) = context.example() else {
+        return Err(GenError::new(
+            "Example file '--example' parameter is missing",
+        ));
+    };
+
+    if !day_data.module_file_path.exists() {
+        return Err(GenError::new(
+            "Cancelled adding example because day file doesn't exist",
+        ));
+    }
+
+    let source = std::fs::read_to_string(&day_data.module_file_path)?;
+    if !source.contains("fn parse_data(") {
+        return Err(GenError::new(
+            "Cancelled adding example: day module has no 'parse_data' function",
+        ));
+    }
+
+    let index = next_example_index(&source, &day_data.day_test_func_name_prefix);
+    let test_code = make_example_test(&day_data, example, index)?;
+    let output = insert_into_test_module(&source, &test_code);
+    str_to_file(&day_data.module_file_path, &output)?;
+    Ok(())
+}
+
+/// Produces test function with the example input constant
+pub fn make_example_test(
+    data: &DayGenData,
+    example: &ExampleData,
+    index: usize,
+) -> GenResult<String> {
+    let input = std::fs::read_to_string(&example.input_path)?;
+
+    let mut assertions = Vec::new();
+    if let Some(value) = &example.expect_part_one {
+        assertions.push(
+            TEMPLATE_ASSERT
+                .replace(PLACEHOLDER_PART, "one")
+                .replace(PLACEHOLDER_VALUE, value),
+        );
+    }
+    if let Some(value) = &example.expect_part_two {
+        assertions.push(
+            TEMPLATE_ASSERT
+                .replace(PLACEHOLDER_PART, "two")
+                .replace(PLACEHOLDER_VALUE, value),
+        );
+    }
+    if assertions.is_empty() {
+        return Err(GenError::new("Example has no expected answers"));
+    }
+
+    let hashes = "#".repeat(raw_string_hashes(&input));
+    let output = TEMPLATE_EXAMPLE_TEST
+        .replace(
+            PLACEHOLDER_TEST_FUNC_PREFIX,
+            &data.day_test_func_name_prefix,
+        )
+        .replace(PLACEHOLDER_STRUCT_NAME, &data.struct_name)
+        .replace(PLACEHOLDER_ASSERTIONS, &assertions.join("\n"))
+        .replace(PLACEHOLDER_INDEX, &index.to_string())
+        .replace(PLACEHOLDER_HASHES, &hashes)
+        .replace(PLACEHOLDER_INPUT, &input);
+    Ok(output)
+}
+
+/// Finds the index that doesn't collide with existing example constants and case tests
+fn next_example_index(source: &str, test_func_prefix: &str) -> usize {
+    let case_prefix = format!("fn {test_func_prefix}_case_");
+    source
+        .lines()
+        .map(|line| line.trim())
+        .filter_map(|line| {
+            line.strip_prefix("const EXAMPLE_")
+                .or_else(|| line.strip_prefix(&case_prefix))
+        })
+        .filter_map(|rest| {
+            let digits = rest
+                .chars()
+                .take_while(|ch| ch.is_ascii_digit())
+                .collect::<String>();
+            digits.parse::<usize>().ok()
+        })
+        .max()
+        .unwrap_or(0)
+        + 1
+}
+
+/// Number of '#' that makes the raw string literal unambiguous for the given content
+fn raw_string_hashes(content: &str) -> usize {
+    let mut count = 1;
+    while content.contains(&format!("\"{}", "#".repeat(count))) {
+        count += 1;
+    }
+    count
+}
+
+/// Inserts the code before closing brace of the `mod test`
+/// or creates the test module if it's missing
+fn insert_into_test_module(source: &str, code: &str) -> String {
+    let mut lines = source.lines().collect::<Vec<_>>();
+    let test_mod_index = lines.iter().position(|line| line.trim() == "mod test {");
+    let closing_index = test_mod_index.and_then(|begin| closing_brace_line(&lines, begin));
+
+    if let Some(index) = closing_index {
+        let insertion = format!("\n{code}");
+        lines.insert(index, &insertion);
+        return lines.join("\n") + "\n";
+    }
+
+    let module = TEMPLATE_TEST_MODULE.replace(PLACEHOLDER_EXAMPLE_TESTS, code);
+    lines.push(&module);
+    lines.join("\n") + "\n"
+}
+
+/// Line of the brace that closes the block opened on the `start` line,
+/// the braces inside the comments and the string or char literals don't count
+fn closing_brace_line(lines: &[&str], start: usize) -> Option<usize> {
+    let chars = lines[start..].join("\n").chars().collect::<Vec<_>>();
+    let mut depth = 0;
+    let mut line = start;
+    let mut i = 0;
+    // moves `i` to the last char of the terminator,
+    // the backslash escapes work in the plain literals only
+    let skip_to = |i: &mut usize, line: &mut usize, end: &[char], has_escapes: bool| {
+        while *i < chars.len() && !chars[*i..].starts_with(end) {
+            if has_escapes && chars[*i] == '\\' {
+                *i += 1;
+            }
+            if chars.get(*i) == Some(&'\n') {
+                *line += 1;
+            }
+            *i += 1;
+        }
+        *i += end.len().saturating_sub(1);
+    };
+    while i < chars.len() {
+        match chars[i] {
+            '\n' => line += 1,
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(line);
+                }
+            }
+            '/' if chars.get(i + 1) == Some(&'/') => {
+                i += 1;
+                skip_to(&mut i, &mut line, &['\n'], false);
+                continue;
+            }
+            '"' => {
+                i += 1;
+                skip_to(&mut i, &mut line, &['"'], true);
+            }
+            'r' if i == 0 || !chars[i - 1].is_alphanumeric() && chars[i - 1] != '_' => {
+                let hashes = chars[i + 1..].iter().take_while(|ch| **ch == '#').count();
+                if chars.get(i + 1 + hashes) == Some(&'"') {
+                    let end = std::iter::once('"')
+                        .chain(std::iter::repeat_n('#', hashes))
+                        .collect::<Vec<_>>();
+                    i += 2 + hashes;
+                    skip_to(&mut i, &mut line, &end, false);
+                }
+            }
+            '\'' if chars.get(i + 1) == Some(&'\\') => {
+                i += 3;
+                skip_to(&mut i, &mut line, &['\''], false);
+            }
+            '\'' if chars.get(i + 2) == Some(&'\'') => i += 2,
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+const PLACEHOLDER_STRUCT_NAME: &str = "${STRUCT_NAME}";
+const PLACEHOLDER_TEST_FUNC_PREFIX: &str = "${TEST_FUNC_PREFIX}";
+const PLACEHOLDER_INDEX: &str = "${INDEX}";
+const PLACEHOLDER_ASSERTIONS: &str = "${ASSERTIONS}";
+const PLACEHOLDER_HASHES: &str = "${HASHES}";
+const PLACEHOLDER_INPUT: &str = "${INPUT}";
+const PLACEHOLDER_PART: &str = "${PART}";
+const PLACEHOLDER_VALUE: &str = "${VALUE}";
+const PLACEHOLDER_EXAMPLE_TESTS: &str = "${EXAMPLE_TESTS}";
+
+const TEMPLATE_ASSERT: &str = r#"        assert_eq!(sol.part_${PART}(), "${VALUE}");"#;
+
+const TEMPLATE_EXAMPLE_TEST: &str = r#"    #[test]
+    fn ${TEST_FUNC_PREFIX}_case_${INDEX}() {
+        let sol = ${STRUCT_NAME}::parse_data(EXAMPLE_${INDEX});
+${ASSERTIONS}
+    }
+
+    const EXAMPLE_${INDEX}: &str = r${HASHES}"${INPUT}"${HASHES};"#;
+
+const TEMPLATE_TEST_MODULE: &str = r#"
+#[cfg(test)]
+mod test {
+    use super::*;
+
+${EXAMPLE_TESTS}
+}"#;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn genexample_next_index() {
+        let source = "    fn aoc2025_05_case_1() {\n    const EXAMPLE_3: &str = \"\";";
+        assert_eq!(next_example_index(source, "aoc2025_05"), 4);
+        assert_eq!(next_example_index("", "aoc2025_05"), 1);
+    }
+
+    #[test]
+    fn genexample_raw_string_hashes() {
+        assert_eq!(raw_string_hashes("plain"), 1);
+        assert_eq!(raw_string_hashes("a\"#b"), 2);
+    }
+
+    #[test]
+    fn genexample_insert_into_existing_module() {
+        let source = "fn a() {}\n\n#[cfg(test)]\nmod test {\n    use super::*;\n}\n";
+        let output = insert_into_test_module(source, "    // code");
+        assert_eq!(
+            output,
+            "fn a() {}\n\n#[cfg(test)]\nmod test {\n    use super::*;\n\n    // code\n}\n"
+        );
+    }
+
+    #[test]
+    fn genexample_insert_before_module_end() {
+        let source = concat!(
+            "#[cfg(test)]\nmod test {\n",
+            "    const EXAMPLE_1: &str = r#\"}\n{\"#;\n",
+            "    const BRACE: char = '}';\n",
+            "    const QUOTE: char = '\\'';\n",
+            "    // }\n",
+            "}\n\nfn after() {\n}\n"
+        );
+        let output = insert_into_test_module(source, "    // code");
+        assert!(output.contains("    // }\n\n    // code\n}\n\nfn after() {\n}\n"));
+    }
+
+    #[test]
+    fn genexample_insert_creates_module() {
+        let output = insert_into_test_module("fn a() {}\n", "    // code");
+        assert_eq!(
+            output,
+            "fn a() {}\n\n#[cfg(test)]\nmod test {\n    use super::*;\n\n    // code\n}\n"
+        );
+    }
+}
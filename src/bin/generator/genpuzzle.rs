@@ -0,0 +1,342 @@
+use std::{path::Path, process::Command};
+
+use regex::Regex;
+
+use crate::{
+    context::{Context, PuzzleSource},
+    generror::{GenError, GenResult},
+    str_to_file,
+};
+
+/// How many `<pre><code>` blocks are saved as example candidates
+const MAX_EXAMPLES: usize = 3;
+
+pub struct PuzzleDescription {
+    pub title: Option<String>,
+    pub markdown: String,
+    pub examples: Vec<String>,
+}
+
+pub fn archive_puzzle(context: &Context) -> GenResult<()> {
+    let Some(day_data) = context.day_module_data() else {
+        return Err(GenError::new("Day '-d' parameter is missing"));
+    };
+
+    let html = match context.puzzle_source() {
+        PuzzleSource::File(path) => std::fs::read_to_string(path)?,
+        PuzzleSource::Remote {
+            base_url,
+            session_file,
+        } => fetch_page(base_url, context.year(), day_data.day, session_file)?,
+    };
+
+    let description = html_to_markdown(&html);
+    if description.title.is_none() {
+        return Err(GenError::new("Puzzle description not found in the page"));
+    }
+
+    std::fs::create_dir_all(context.puzzles_year_folder())?;
+    str_to_file(&day_data.puzzle_file_path, &description.markdown)?;
+    println!("Saved {}", day_data.puzzle_file_path.display());
+
+    for (index, example) in description.examples.iter().enumerate() {
+        let path = day_data.puzzle_file_path.with_file_name(format!(
+            "{:02}_example_{}.txt",
+            day_data.day,
+            index + 1
+        ));
+        str_to_file(&path, example)?;
+        println!("Saved {}", path.display());
+    }
+    Ok(())
+}
+
+/// Returns description string stored in the archived puzzle file
+pub fn read_puzzle_title(puzzle_file_path: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(puzzle_file_path).ok()?;
+    let title = content.lines().next()?.strip_prefix("# ")?;
+    Some(title.trim().to_string())
+}
+
+fn fetch_page(base_url: &str, year: usize, day: usize, session_file: &Path) -> GenResult<String> {
+    let url = format!("{}/{year}/day/{day}", base_url.trim_end_matches('/'));
+    let mut command = Command::new("curl");
+    command.arg("--silent").arg("--fail");
+    if let Ok(session) = std::fs::read_to_string(session_file) {
+        command
+            .arg("--cookie")
+            .arg(format!("session={}", session.trim()));
+    }
+    let output = command.arg(&url).output()?;
+    if !output.status.success() {
+        return Err(GenError::new(format!("Failed to fetch {url}")));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+enum HtmlEvent {
+    Start { name: String, attributes: String },
+    End(String),
+    Text(String),
+}
+
+fn html_events(html: &str) -> Vec<HtmlEvent> {
+    let mut events = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(begin) = rest.find('<') else {
+            events.push(HtmlEvent::Text(rest.to_string()));
+            break;
+        };
+        if begin > 0 {
+            events.push(HtmlEvent::Text(rest[..begin].to_string()));
+        }
+        rest = &rest[begin..];
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment
+                .split_once("-->")
+                .map(|(_, tail)| tail)
+                .unwrap_or("");
+            continue;
+        }
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+        if let Some(name) = tag.strip_prefix('/') {
+            events.push(HtmlEvent::End(name.trim().to_lowercase()));
+            continue;
+        }
+        if tag.starts_with('!') {
+            continue;
+        }
+        let (name, attributes) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+        events.push(HtmlEvent::Start {
+            name: name.trim_end_matches('/').to_lowercase(),
+            attributes: attributes.to_string(),
+        });
+    }
+    events
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn heading_text(s: &str) -> String {
+    collapse_whitespace(s)
+        .trim_matches(|ch: char| ch == '-' || ch.is_whitespace())
+        .to_string()
+}
+
+/// Converts `<article>` elements of the puzzle page to markdown
+pub fn html_to_markdown(html: &str) -> PuzzleDescription {
+    let href_regex = Regex::new(r#"href="([^"]*)""#).expect("Href regex must be valid");
+    let mut title: Option<String> = None;
+    let mut markdown = String::new();
+    let mut examples = Vec::new();
+
+    let mut article_depth = 0;
+    let mut block = String::new();
+    let mut pre_text: Option<String> = None;
+    let mut pre_has_code = false;
+    let mut is_code = false;
+    let mut links = Vec::new();
+
+    for event in html_events(html) {
+        if let HtmlEvent::Start { name, .. } = &event {
+            if name == "article" {
+                article_depth += 1;
+                continue;
+            }
+        }
+        if article_depth == 0 {
+            continue;
+        }
+        match event {
+            HtmlEvent::Start { name, attributes } => match name.as_str() {
+                "pre" => pre_text = Some(String::new()),
+                "code" if pre_text.is_some() => pre_has_code = true,
+                "code" => {
+                    is_code = true;
+                    block.push('`');
+                }
+                "em" if pre_text.is_none() && !is_code => block.push_str("**"),
+                "a" if pre_text.is_none() => {
+                    let href = href_regex
+                        .captures(&attributes)
+                        .map(|captures| captures[1].to_string())
+                        .unwrap_or_default();
+                    links.push(href);
+                    block.push('[');
+                }
+                "p" | "h2" | "li" => block.clear(),
+                _ => {}
+            },
+            HtmlEvent::End(name) => match name.as_str() {
+                "article" => {
+                    article_depth -= 1;
+                }
+                "pre" => {
+                    let Some(text) = pre_text.take() else {
+                        continue;
+                    };
+                    let mut text = decode_entities(&text);
+                    if !text.ends_with('\n') {
+                        text.push('\n');
+                    }
+                    markdown.push_str(&format!("```\n{text}```\n\n"));
+                    if pre_has_code && examples.len() < MAX_EXAMPLES {
+                        examples.push(text);
+                    }
+                    pre_has_code = false;
+                }
+                "code" if pre_text.is_none() => {
+                    is_code = false;
+                    block.push('`');
+                }
+                "em" if pre_text.is_none() && !is_code => block.push_str("**"),
+                "a" if pre_text.is_none() => {
+                    let href = links.pop().unwrap_or_default();
+                    block.push_str(&format!("]({href})"));
+                }
+                "h2" => {
+                    let text = heading_text(&block);
+                    if title.is_none() {
+                        markdown.push_str(&format!("# {text}\n\n"));
+                        title = Some(text);
+                    } else {
+                        markdown.push_str(&format!("## {text}\n\n"));
+                    }
+                    block.clear();
+                }
+                "p" => {
+                    markdown.push_str(&collapse_whitespace(&block));
+                    markdown.push_str("\n\n");
+                    block.clear();
+                }
+                "li" => {
+                    markdown.push_str(&format!("- {}\n", collapse_whitespace(&block)));
+                    block.clear();
+                }
+                "ul" => markdown.push('\n'),
+                _ => {}
+            },
+            HtmlEvent::Text(text) => match pre_text.as_mut() {
+                Some(pre) => pre.push_str(&text),
+                None => block.push_str(&decode_entities(&text)),
+            },
+        }
+    }
+
+    PuzzleDescription {
+        title,
+        markdown: markdown.trim_end().to_string() + "\n",
+        examples,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html><head><title>Day 1 - Advent of Code 2077</title></head>
+<body>
+<header><h1>Advent of Code</h1></header>
+<main>
+<article class="day-desc"><h2>--- Day 1: Sample Puzzle ---</h2>
+<p>The <em>first</em> paragraph with <code>code</code>
+and a <a href="/2077/about" target="_blank">link</a> &amp; more.</p>
+<pre><code>1 &lt; 2
+<em>3</em>
+</code></pre>
+<ul>
+<li>item <code><em>one</em></code></li>
+<li>item two</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>42</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Second part.</p>
+<pre><code>x
+</code></pre>
+</article>
+</main>
+</body></html>"#;
+
+    const MARKDOWN: &str = r#"# Day 1: Sample Puzzle
+
+The **first** paragraph with `code` and a [link](/2077/about) & more.
+
+```
+1 < 2
+3
+```
+
+- item `one`
+- item two
+
+## Part Two
+
+Second part.
+
+```
+x
+```
+"#;
+
+    #[test]
+    fn genpuzzle_html_to_markdown() {
+        let description = html_to_markdown(PAGE);
+        assert_eq!(description.title.as_deref(), Some("Day 1: Sample Puzzle"));
+        assert_eq!(description.markdown, MARKDOWN);
+        assert_eq!(description.examples, ["1 < 2\n3\n", "x\n"]);
+    }
+
+    #[test]
+    fn genpuzzle_missing_article() {
+        let description = html_to_markdown("<html><body><p>Text</p></body></html>");
+        assert!(description.title.is_none());
+        assert!(description.examples.is_empty());
+    }
+
+    #[test]
+    fn genpuzzle_fetch_from_local_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buffer = [0u8; 1024];
+            let size = stream.read(&mut buffer).unwrap();
+            let request = String::from_utf8_lossy(&buffer[..size]).to_string();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{PAGE}",
+                PAGE.len()
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+            request
+        });
+        let base_url = format!("http://{address}/");
+        let page = fetch_page(&base_url, 2077, 1, Path::new("missing/session")).unwrap();
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2077/day/1 "));
+        assert_eq!(page, PAGE);
+    }
+}
@@ -0,0 +1,219 @@
+use std::{collections::BTreeMap, process::Command};
+
+use advent_of_code::PuzzleReport;
+
+use crate::{
+    context::Context,
+    generror::{GenError, GenResult},
+    str_to_file,
+};
+
+pub fn update_readme(context: &Context) -> GenResult<()> {
+    let reports = match context.report_path() {
+        Some(path) => parse_reports(&std::fs::read_to_string(path)?)?,
+        None => run_all_puzzles()?,
+    };
+    if reports.is_empty() {
+        return Err(GenError::new("No puzzle reports to process"));
+    }
+    let section = render_statistics(&reports);
+    let readme_path = context.readme_file_path();
+    let content = std::fs::read_to_string(&readme_path)?;
+    let output = replace_section(&content, &section);
+    str_to_file(&readme_path, &output)?;
+    println!("Statistics for {} puzzles saved", reports.len());
+    Ok(())
+}
+
+fn run_all_puzzles() -> GenResult<Vec<PuzzleReport>> {
+    println!("Running all puzzles, it may take a while...");
+    let output = Command::new("cargo")
+        .args([
+            "run",
+            "--release",
+            "--bin",
+            "advent_of_code",
+            "--",
+            "all",
+            "--json",
+        ])
+        .output()?;
+    if !output.status.success() {
+        return Err(GenError::new("Failed to run puzzles"));
+    }
+    parse_reports(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parses JSON lines report, the lines that aren't JSON objects are skipped
+fn parse_reports(content: &str) -> GenResult<Vec<PuzzleReport>> {
+    content
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| line.starts_with('{'))
+        .map(|line| {
+            serde_json::from_str::<PuzzleReport>(line)
+                .map_err(|err| GenError::new(format!("Invalid report line: {err}")))
+        })
+        .collect()
+}
+
+fn day_file_link(year: usize, day: usize) -> String {
+    format!("src/aoc{year}/day_{day:02}.rs")
+}
+
+fn puzzle_duration(report: &PuzzleReport) -> u128 {
+    report.parts.iter().map(|part| part.duration_ms).sum()
+}
+
+fn render_statistics(reports: &[PuzzleReport]) -> String {
+    let mut years = BTreeMap::<usize, Vec<&PuzzleReport>>::new();
+    for report in reports {
+        years.entry(report.year).or_default().push(report);
+    }
+    years
+        .values_mut()
+        .for_each(|arr| arr.sort_by_key(|report| report.day));
+
+    let mut lines = vec![
+        "## Statistics".to_string(),
+        String::new(),
+        "| Year | Days | Completed parts | Total time | Slowest puzzle |".to_string(),
+        "|------|------|-----------------|------------|----------------|".to_string(),
+    ];
+    for (year, reports) in &years {
+        let completed = reports
+            .iter()
+            .flat_map(|report| report.parts.iter())
+            .filter(|part| part.is_completed())
+            .count();
+        let total = reports.iter().map(|r| puzzle_duration(r)).sum::<u128>();
+        let slowest = reports
+            .iter()
+            .max_by_key(|report| puzzle_duration(report))
+            .map(|report| {
+                format!(
+                    "[Day {}]({}) ({} ms)",
+                    report.day,
+                    day_file_link(report.year, report.day),
+                    puzzle_duration(report)
+                )
+            })
+            .unwrap_or_default();
+        lines.push(format!(
+            "| {year} | {} | {completed} | {total} ms | {slowest} |",
+            reports.len()
+        ));
+    }
+
+    for (year, reports) in &years {
+        lines.push(String::new());
+        lines.push("<details>".to_string());
+        lines.push(format!("<summary>{year}</summary>"));
+        lines.push(String::new());
+        lines.push("| Day | Puzzle | Part 1 | Part 2 | Time |".to_string());
+        lines.push("|-----|--------|--------|--------|------|".to_string());
+        for report in reports {
+            let mark = |part: u8| {
+                let is_completed = report
+                    .parts
+                    .iter()
+                    .any(|x| x.part == part && x.is_completed());
+                if is_completed {
+                    "★"
+                } else {
+                    " "
+                }
+            };
+            lines.push(format!(
+                "| [{}]({}) | {} | {} | {} | {} ms |",
+                report.day,
+                day_file_link(report.year, report.day),
+                report.description.replace('|', "\\|"),
+                mark(1),
+                mark(2),
+                puzzle_duration(report)
+            ));
+        }
+        lines.push(String::new());
+        lines.push("</details>".to_string());
+    }
+    lines.join("\n")
+}
+
+/// Replaces content between markers, the markers are added if they are missing
+fn replace_section(content: &str, section: &str) -> String {
+    let block = format!("{MARKER_STATS_BEGIN}\n{section}\n{MARKER_STATS_END}");
+    let begin = content.find(MARKER_STATS_BEGIN);
+    let end = content
+        .find(MARKER_STATS_END)
+        .map(|index| index + MARKER_STATS_END.len());
+    match (begin, end) {
+        (Some(begin), Some(end)) if begin < end => {
+            format!("{}{block}{}", &content[..begin], &content[end..])
+        }
+        _ => match content.find(ANCHOR_CONTRIBUTING) {
+            Some(index) => format!("{}{block}\n\n{}", &content[..index], &content[index..]),
+            None => format!("{}\n\n{block}\n", content.trim_end()),
+        },
+    }
+}
+
+const MARKER_STATS_BEGIN: &str = "<!-- GENERATOR_MARKER: STATS_BEGIN -->";
+const MARKER_STATS_END: &str = "<!-- GENERATOR_MARKER: STATS_END -->";
+const ANCHOR_CONTRIBUTING: &str = "## Contributing";
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const REPORT: &str = r#"Advent of Code
+{"year":2016,"day":2,"description":"Day 2: B","parts":[{"part":1,"result":"1","duration_ms":5},{"part":2,"result":"Part #2 isn't implemented yet","duration_ms":0}]}
+{"year":2016,"day":1,"description":"Day 1: A|B","parts":[{"part":1,"result":"1","duration_ms":1},{"part":2,"result":"2","duration_ms":2}]}
+{"year":2015,"day":1,"description":"Day 1: C","parts":[{"part":1,"result":"1","duration_ms":7},{"part":2,"result":"2","duration_ms":0}]}
+"#;
+
+    #[test]
+    fn genreadme_parse_reports() {
+        let reports = parse_reports(REPORT).unwrap();
+        assert_eq!(reports.len(), 3);
+        assert_eq!(reports[0].year, 2016);
+        assert_eq!(reports[0].parts.len(), 2);
+        assert!(parse_reports("{").is_err());
+    }
+
+    #[test]
+    fn genreadme_render_statistics() {
+        let reports = parse_reports(REPORT).unwrap();
+        let output = render_statistics(&reports);
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines[4],
+            "| 2015 | 1 | 2 | 7 ms | [Day 1](src/aoc2015/day_01.rs) (7 ms) |"
+        );
+        assert_eq!(
+            lines[5],
+            "| 2016 | 2 | 3 | 8 ms | [Day 2](src/aoc2016/day_02.rs) (5 ms) |"
+        );
+        assert!(output.contains("| [1](src/aoc2016/day_01.rs) | Day 1: A\\|B | ★ | ★ | 3 ms |"));
+        assert!(output.contains("| [2](src/aoc2016/day_02.rs) | Day 2: B | ★ |   | 5 ms |"));
+    }
+
+    #[test]
+    fn genreadme_replace_section() {
+        let content = format!(
+            "# Title\n\n{MARKER_STATS_BEGIN}\nold\n{MARKER_STATS_END}\n\n## Contributing\n"
+        );
+        let expected = format!(
+            "# Title\n\n{MARKER_STATS_BEGIN}\nnew\n{MARKER_STATS_END}\n\n## Contributing\n"
+        );
+        assert_eq!(replace_section(&content, "new"), expected);
+        assert_eq!(replace_section(&expected, "new"), expected);
+
+        let content = "# Title\n\n## Contributing\n";
+        assert_eq!(replace_section(content, "new"), expected);
+
+        let content = "# Title\n";
+        let expected = format!("# Title\n\n{MARKER_STATS_BEGIN}\nnew\n{MARKER_STATS_END}\n");
+        assert_eq!(replace_section(content, "new"), expected);
+    }
+}
@@ -0,0 +1,293 @@
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+use crate::{context::Context, generror::GenResult};
+
+struct LintIssue {
+    line: Option<usize>,
+    message: String,
+}
+
+impl LintIssue {
+    fn new<T: AsRef<str>>(message: T) -> Self {
+        Self {
+            line: None,
+            message: message.as_ref().to_string(),
+        }
+    }
+
+    fn at_line<T: AsRef<str>>(line: usize, message: T) -> Self {
+        Self {
+            line: Some(line),
+            message: message.as_ref().to_string(),
+        }
+    }
+}
+
+struct DayFile {
+    path: PathBuf,
+    year: usize,
+    day: usize,
+}
+
+struct Rules {
+    input_dir: String,
+    description: Regex,
+    struct_name: Regex,
+    input_path: Regex,
+    test_func: Regex,
+}
+
+impl Rules {
+    fn new(input_dir: &str) -> Self {
+        let make = |s: &str| Regex::new(s).expect("Lint regex must be valid");
+        Self {
+            input_dir: input_dir.to_string(),
+            description: make(r"^Day (\d+): \S.*$"),
+            struct_name: make(r"pub struct (AoC\w+)"),
+            input_path: make(&format!(r#""({}/[^"]*)""#, regex::escape(input_dir))),
+            test_func: make(r"#\[test\]\s*fn (\w+)"),
+        }
+    }
+}
+
+pub fn lint_project(context: &Context) -> GenResult<()> {
+    let source_folder = context.source_folder();
+    let root = source_folder.parent().unwrap_or(&source_folder);
+    let files = collect_day_files(&source_folder, context.year_filter())?;
+    let rules = Rules::new(context.input_dir());
+    let mut issues_total = 0;
+    let mut files_with_issues = 0;
+    for file in &files {
+        let source = std::fs::read_to_string(&file.path)?;
+        let issues = lint_day(file, &source, &rules);
+        if issues.is_empty() {
+            continue;
+        }
+        files_with_issues += 1;
+        issues_total += issues.len();
+        let path = file.path.strip_prefix(root).unwrap_or(&file.path);
+        println!("{}", path.display());
+        for issue in issues {
+            match issue.line {
+                Some(line) => println!("\tline {line}: {}", issue.message),
+                None => println!("\t{}", issue.message),
+            }
+        }
+    }
+    println!(
+        "\nChecked {} files, found {issues_total} issue(s) in {files_with_issues} file(s)",
+        files.len()
+    );
+    Ok(())
+}
+
+fn collect_day_files(source_folder: &Path, year_filter: Option<usize>) -> GenResult<Vec<DayFile>> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(source_folder)? {
+        let year_path = entry?.path();
+        let Some(year) = file_name_number(&year_path, "aoc", "") else {
+            continue;
+        };
+        if year_filter.is_some_and(|filter| filter != year) || !year_path.is_dir() {
+            continue;
+        }
+        for entry in std::fs::read_dir(&year_path)? {
+            let path = entry?.path();
+            let Some(day) = file_name_number(&path, "day_", ".rs") else {
+                continue;
+            };
+            files.push(DayFile { path, year, day });
+        }
+    }
+    files.sort_by_key(|file| (file.year, file.day));
+    Ok(files)
+}
+
+fn file_name_number(path: &Path, prefix: &str, suffix: &str) -> Option<usize> {
+    path.file_name()?
+        .to_str()?
+        .strip_prefix(prefix)?
+        .strip_suffix(suffix)?
+        .parse::<usize>()
+        .ok()
+}
+
+fn lint_day(file: &DayFile, source: &str, rules: &Rules) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    check_description(file, source, rules, &mut issues);
+    check_struct_name(file, source, rules, &mut issues);
+    check_input_path(file, source, rules, &mut issues);
+    check_tests(file, source, rules, &mut issues);
+    check_todo_markers(source, &mut issues);
+    issues
+}
+
+fn check_description(file: &DayFile, source: &str, rules: &Rules, issues: &mut Vec<LintIssue>) {
+    let Some(description) = find_description(source) else {
+        issues.push(LintIssue::new("description not found"));
+        return;
+    };
+    let Some(captures) = rules.description.captures(&description) else {
+        let message = format!("description '{description}' doesn't match 'Day N: Title' format");
+        issues.push(LintIssue::new(message));
+        return;
+    };
+    if captures[1].parse::<usize>().ok() != Some(file.day) {
+        let message = format!("description '{description}' refers to the wrong day");
+        issues.push(LintIssue::new(message));
+    }
+}
+
+/// Returns the first string literal in the `description` function body
+fn find_description(source: &str) -> Option<String> {
+    let (_, body) = source.split_once("fn description(")?;
+    let (_, rest) = body.split_once('"')?;
+    let (value, _) = rest.split_once('"')?;
+    Some(value.to_string())
+}
+
+fn check_struct_name(file: &DayFile, source: &str, rules: &Rules, issues: &mut Vec<LintIssue>) {
+    let expected = format!("AoC{}_{:02}", file.year, file.day);
+    let found = rules
+        .struct_name
+        .captures_iter(source)
+        .map(|captures| captures[1].to_string())
+        .collect::<Vec<_>>();
+    if found.is_empty() {
+        issues.push(LintIssue::new(format!("struct '{expected}' not found")));
+        return;
+    }
+    for name in found.iter().filter(|name| **name != expected) {
+        issues.push(LintIssue::new(format!(
+            "struct name '{name}' doesn't match file name, expected '{expected}'"
+        )));
+    }
+}
+
+fn check_input_path(file: &DayFile, source: &str, rules: &Rules, issues: &mut Vec<LintIssue>) {
+    let expected = format!("{}/aoc{}_{:02}", rules.input_dir, file.year, file.day);
+    let mut is_found = false;
+    for (index, line) in source.lines().enumerate() {
+        for captures in rules.input_path.captures_iter(line) {
+            is_found = true;
+            let path = &captures[1];
+            if path != expected {
+                let message = format!("input path '{path}' doesn't match '{expected}'");
+                issues.push(LintIssue::at_line(index + 1, message));
+            }
+        }
+    }
+    if !is_found {
+        issues.push(LintIssue::new("input path not found"));
+    }
+}
+
+fn check_tests(file: &DayFile, source: &str, rules: &Rules, issues: &mut Vec<LintIssue>) {
+    let names = rules
+        .test_func
+        .captures_iter(source)
+        .map(|captures| captures[1].to_string())
+        .collect::<Vec<_>>();
+    if names.is_empty() {
+        issues.push(LintIssue::new("no tests found"));
+        return;
+    }
+
+    let prefix = format!("aoc{}_{:02}_", file.year, file.day);
+    for name in names.iter().filter(|name| !name.starts_with(&prefix)) {
+        issues.push(LintIssue::new(format!(
+            "test name '{name}' doesn't start with '{prefix}'"
+        )));
+    }
+
+    let is_correctness = |name: &String| name.contains("correctness");
+    let is_input_load = |name: &String| name.contains("input_load");
+    if !names.iter().any(is_correctness) {
+        issues.push(LintIssue::new("correctness test is missing"));
+    }
+    if !names
+        .iter()
+        .any(|name| !is_correctness(name) && !is_input_load(name))
+    {
+        issues.push(LintIssue::new("example test is missing"));
+    }
+}
+
+fn check_todo_markers(source: &str, issues: &mut Vec<LintIssue>) {
+    for (index, line) in source.lines().enumerate() {
+        if line.contains("todo!(") {
+            issues.push(LintIssue::at_line(index + 1, "leftover 'todo!()'"));
+        } else if line.contains("TODO") {
+            issues.push(LintIssue::at_line(index + 1, "leftover TODO marker"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn make_day_file() -> DayFile {
+        DayFile {
+            path: PathBuf::from("src/aoc2024/day_10.rs"),
+            year: 2024,
+            day: 10,
+        }
+    }
+
+    fn lint_messages(source: &str) -> Vec<String> {
+        lint_day(&make_day_file(), source, &Rules::new("input"))
+            .into_iter()
+            .map(|issue| issue.message)
+            .collect()
+    }
+
+    #[test]
+    fn lint_consistent_day() {
+        let source = r#"
+pub struct AoC2024_10 {}
+    let data = read_to_string("input/aoc2024_10")?;
+    fn description(&self) -> String {
+        "Day 10: Hoof It".to_string()
+    }
+    #[test]
+    fn aoc2024_10_correctness_part_1() {}
+    #[test]
+    fn aoc2024_10_case_1() {}
+"#;
+        assert!(lint_messages(source).is_empty());
+    }
+
+    #[test]
+    fn lint_inconsistent_day() {
+        let source = r#"
+pub struct AoC2024_01 {}
+    let data = read_to_string("input/aoc2024_01")?;
+    fn description(&self) -> String {
+        "2024/Day 10: Hoof It".to_string()
+    }
+    // TODO: refactor
+    #[test]
+    fn hoof_it_correctness() {}
+"#;
+        let messages = lint_messages(source);
+        assert_eq!(messages.len(), 6);
+        assert!(messages[0].starts_with("description"));
+        assert!(messages[1].starts_with("struct name"));
+        assert!(messages[2].starts_with("input path"));
+        assert!(messages[3].starts_with("test name"));
+        assert_eq!(messages[4], "example test is missing");
+        assert_eq!(messages[5], "leftover TODO marker");
+    }
+
+    #[test]
+    fn lint_wrong_description_day() {
+        let mut issues = Vec::new();
+        let source = "fn description(&self) -> String { \"Day 1: Title\" }";
+        check_description(&make_day_file(), source, &Rules::new("input"), &mut issues);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.ends_with("refers to the wrong day"));
+    }
+}
@@ -6,11 +6,23 @@ use genmod::generate_module;
 mod genday;
 use genday::generate_day;
 
+mod genexample;
+use genexample::add_example;
+
+mod genpuzzle;
+use genpuzzle::archive_puzzle;
+
+mod genreadme;
+use genreadme::update_readme;
+
+mod lint;
+use lint::lint_project;
+
 mod generror;
 use generror::{GenError, GenResult};
 
 mod context;
-use context::Context;
+use context::{Command, Context};
 
 fn main() -> GenResult<()> {
     let result = Context::create();
@@ -24,9 +36,16 @@ fn main() -> GenResult<()> {
         return Ok(());
     }
 
-    generate_module(&context)?;
-
-    generate_day(&context)
+    match context.command() {
+        Command::New => {
+            generate_module(&context)?;
+            generate_day(&context)
+        }
+        Command::Example => add_example(&context),
+        Command::Lint => lint_project(&context),
+        Command::Puzzle => archive_puzzle(&context),
+        Command::Readme => update_readme(&context),
+    }
 }
 
 fn show_usage(error: GenError) {
@@ -37,6 +56,17 @@ fn show_usage(error: GenError) {
     println!("\tcargo r --bin generator -- -y2077");
     println!("Generate new year module (if not exists) and day module");
     println!("\tcargo r --bin generator -- -y2077 -d1");
+    println!("Generate day module with the example test");
+    println!("\tcargo r --bin generator -- new -y 2077 -d 1 --example ex.txt --expect1 42");
+    println!("Append example test to the existing day module");
+    println!("\tcargo r --bin generator -- example -y 2077 -d 1 --example ex2.txt --expect2 7");
+    println!("Check day modules for the project conventions (all years or the one)");
+    println!("\tcargo r --bin generator -- lint [-y 2077]");
+    println!("Save puzzle description to puzzles/YYYY/DD.md from the saved page or the site");
+    println!("\tcargo r --bin generator -- puzzle -y 2077 -d 1 [--html page.html]");
+    println!("\tcargo r --bin generator -- puzzle -y 2077 -d 1 [--base-url http://localhost:8080]");
+    println!("Update README statistics by running all puzzles or from the saved report");
+    println!("\tcargo r --bin generator -- readme [--report report.jsonl]");
     println!("\nNote: generator will not overwrite existing files");
 }
 
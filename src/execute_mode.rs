@@ -6,11 +6,15 @@ use advent_of_code::load_aoc_config;
 pub enum ExecuteMode {
     Single { year: usize, day: usize },
     Year { year: usize },
+    All,
     Undefined,
 }
 
 impl ExecuteMode {
     fn by_parsing(year: Option<&String>, day: Option<&String>) -> Result<Self, ParseIntError> {
+        if year.is_some_and(|x| x == "all") {
+            return Ok(Self::All);
+        }
         let mut y: Option<usize> = None;
         if let Some(year) = year {
             y = Some(year.parse::<usize>()?);
@@ -40,8 +44,8 @@ pub fn get_execute_mode() -> std::io::Result<ExecuteMode> {
 }
 
 fn get_execute_mode_from_arguments() -> std::io::Result<ExecuteMode> {
-    let args: Vec<String> = std::env::args().collect();
-    let mode = ExecuteMode::by_parsing(args.get(1), args.get(2))
+    let args: Vec<String> = std::env::args().skip(1).filter(|x| !is_flag(x)).collect();
+    let mode = ExecuteMode::by_parsing(args.first(), args.get(1))
         .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidInput, err))?;
     Ok(mode)
 }
@@ -51,3 +55,12 @@ fn get_execute_mode_from_config() -> std::io::Result<ExecuteMode> {
     let mode = ExecuteMode::with(puzzle_config.year, puzzle_config.day);
     Ok(mode)
 }
+
+/// Checks if the command line argument is an option (e.g. `--json`) but not a puzzle parameter
+pub fn is_flag(arg: &str) -> bool {
+    arg.starts_with("--")
+}
+
+pub fn has_flag(flag: &str) -> bool {
+    std::env::args().skip(1).any(|x| x == flag)
+}
@@ -1,13 +1,37 @@
+use std::collections::BTreeMap;
+
 use serde_derive::{Deserialize, Serialize};
 
 pub const AOC_CONFIG_FILE: &str = "aoc.toml";
 
-#[derive(Serialize, Deserialize)]
+const DEFAULT_DAYS_IN_YEAR: usize = 25;
+
+#[derive(Serialize, Deserialize, Default)]
 pub struct AocConfig {
     pub puzzle: PuzzleConfig,
+    #[serde(default)]
+    pub paths: PathsConfig,
+    #[serde(default)]
+    pub output: OutputConfig,
+    #[serde(default)]
+    pub bench: BenchConfig,
+    #[serde(default)]
+    pub remote: RemoteConfig,
+    /// Amount of puzzles per year if it differs from 25, keys are years
+    #[serde(default)]
+    pub days: BTreeMap<String, usize>,
 }
 
-#[derive(Serialize, Deserialize)]
+impl AocConfig {
+    pub fn days_in_year(&self, year: usize) -> usize {
+        self.days
+            .get(&year.to_string())
+            .copied()
+            .unwrap_or(DEFAULT_DAYS_IN_YEAR)
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
 pub struct PuzzleConfig {
     pub year: Option<usize>,
     pub day: Option<usize>,
@@ -22,9 +46,78 @@ impl PuzzleConfig {
     }
 }
 
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct PathsConfig {
+    /// Folder with puzzle input files
+    pub input_dir: String,
+    /// File with the verified puzzle answers
+    pub answers_file: String,
+}
+
+impl Default for PathsConfig {
+    fn default() -> Self {
+        Self {
+            input_dir: "input".to_string(),
+            answers_file: "answers.toml".to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct OutputConfig {
+    pub format: OutputFormat,
+    /// Allows tools to ask user for the confirmation
+    pub interactive: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct BenchConfig {
+    /// How many times each puzzle part is executed for time measurement
+    pub iterations: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self { iterations: 1 }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct RemoteConfig {
+    /// Advent of Code site address, may point to the local server for testing
+    pub base_url: String,
+    /// File with the session cookie value
+    pub session_file: String,
+}
+
+impl Default for RemoteConfig {
+    fn default() -> Self {
+        Self {
+            base_url: "https://adventofcode.com".to_string(),
+            session_file: "etc/session".to_string(),
+        }
+    }
+}
+
 pub fn load_aoc_config() -> std::io::Result<AocConfig> {
     let content = std::fs::read_to_string(AOC_CONFIG_FILE)?;
-    let config = toml::from_str(&content)
+    parse_aoc_config(&content)
+}
+
+pub fn parse_aoc_config(content: &str) -> std::io::Result<AocConfig> {
+    let config = toml::from_str(content)
         .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidInput, err))?;
     Ok(config)
 }
@@ -35,6 +128,79 @@ pub fn write_aoc_config(config: AocConfig) -> std::io::Result<()> {
     str_to_file(AOC_CONFIG_FILE, &content)
 }
 
+/// Updates `[puzzle]` section only, the rest of the file (including comments) stays untouched
+pub fn update_aoc_puzzle_config(puzzle: &PuzzleConfig) -> std::io::Result<()> {
+    let content = match std::fs::read_to_string(AOC_CONFIG_FILE) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err),
+    };
+    let output = patch_puzzle_config(&content, puzzle)?;
+    str_to_file(AOC_CONFIG_FILE, &output)
+}
+
+pub fn patch_puzzle_config(content: &str, puzzle: &PuzzleConfig) -> std::io::Result<String> {
+    let mut document = content
+        .parse::<toml_edit::DocumentMut>()
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidInput, err))?;
+    let section = document
+        .entry("puzzle")
+        .or_insert(toml_edit::table())
+        .as_table_like_mut()
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "'puzzle' entry isn't a table",
+            )
+        })?;
+    for (key, value) in [("year", puzzle.year), ("day", puzzle.day)] {
+        match value {
+            Some(value) => {
+                let value = i64::try_from(value)
+                    .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidInput, err))?;
+                match section.get_mut(key).and_then(|item| item.as_value_mut()) {
+                    // keeps decoration of the existing value, e.g. trailing comment
+                    Some(item) => {
+                        let decor = item.decor().clone();
+                        *item = value.into();
+                        *item.decor_mut() = decor;
+                    }
+                    None => {
+                        section.insert(key, toml_edit::value(value));
+                    }
+                }
+            }
+            None => {
+                section.remove(key);
+            }
+        }
+    }
+    Ok(document.to_string())
+}
+
+/// Execution results of the single puzzle
+#[derive(Serialize, Deserialize)]
+pub struct PuzzleReport {
+    pub year: usize,
+    pub day: usize,
+    pub description: String,
+    pub parts: Vec<PartReport>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PartReport {
+    pub part: u8,
+    pub result: String,
+    pub duration_ms: u128,
+}
+
+impl PartReport {
+    /// Part is completed if it returns something other than the default `Solution` message
+    pub fn is_completed(&self) -> bool {
+        !self.result.is_empty() && !self.result.ends_with("isn't implemented yet")
+    }
+}
+
 // shared
 pub fn file_to_string_array<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Vec<String>> {
     let arr = std::fs::read_to_string(path)?
@@ -49,3 +215,90 @@ pub fn str_to_file<P: AsRef<std::path::Path>>(path: P, output: &str) -> std::io:
     std::io::Write::write_all(&mut file, output.as_bytes())?;
     std::io::Write::flush(&mut file)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn config_minimal_defaults() {
+        let config = parse_aoc_config("[puzzle]\nyear = 2022\nday = 25\n").unwrap();
+        assert_eq!(config.puzzle.year, Some(2022));
+        assert_eq!(config.puzzle.day, Some(25));
+        assert_eq!(config.paths.input_dir, "input");
+        assert_eq!(config.output.format, OutputFormat::Text);
+        assert!(!config.output.interactive);
+        assert_eq!(config.bench.iterations, 1);
+        assert_eq!(config.days_in_year(2022), 25);
+        assert_eq!(config.remote.base_url, "https://adventofcode.com");
+    }
+
+    #[test]
+    fn config_extended_sections() {
+        let content = r#"
+[puzzle]
+year = 2025
+
+[paths]
+input_dir = "data"
+
+[output]
+format = "json"
+interactive = true
+
+[bench]
+iterations = 10
+
+[remote]
+base_url = "http://127.0.0.1:8080"
+
+[days]
+2025 = 12
+"#;
+        let config = parse_aoc_config(content).unwrap();
+        assert_eq!(config.puzzle.day, None);
+        assert_eq!(config.paths.input_dir, "data");
+        assert_eq!(config.paths.answers_file, "answers.toml");
+        assert_eq!(config.output.format, OutputFormat::Json);
+        assert!(config.output.interactive);
+        assert_eq!(config.bench.iterations, 10);
+        assert_eq!(config.days_in_year(2025), 12);
+        assert_eq!(config.days_in_year(2024), 25);
+        assert_eq!(config.remote.base_url, "http://127.0.0.1:8080");
+        assert_eq!(config.remote.session_file, "etc/session");
+    }
+
+    #[test]
+    fn config_patch_preserves_content() {
+        let content = r#"# current puzzle
+[puzzle]
+year = 2022 # the year
+day = 25
+
+# bench settings
+[bench]
+iterations = 5
+custom = "value"
+"#;
+        let output = patch_puzzle_config(content, &PuzzleConfig::with(2025, 3)).unwrap();
+        let expected = r#"# current puzzle
+[puzzle]
+year = 2025 # the year
+day = 3
+
+# bench settings
+[bench]
+iterations = 5
+custom = "value"
+"#;
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn config_patch_creates_section() {
+        let output = patch_puzzle_config("", &PuzzleConfig::with(2025, 3)).unwrap();
+        let config = parse_aoc_config(&output).unwrap();
+        assert_eq!(config.puzzle.year, Some(2025));
+        assert_eq!(config.puzzle.day, Some(3));
+    }
+}
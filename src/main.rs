@@ -42,11 +42,18 @@ use crate::aoc2022::puzzle_factory_2022;
 // Do not remove the line above
 
 use crate::solution::AggregatedFactory;
-use execute_mode::{get_execute_mode, ExecuteMode};
+use advent_of_code::{load_aoc_config, AocConfig, OutputFormat, PartReport, PuzzleReport};
+use execute_mode::{get_execute_mode, has_flag, ExecuteMode};
 use solution::Solution;
 
 fn main() -> io::Result<()> {
-    println!("Advent of Code");
+    let mut config = load_aoc_config().unwrap_or_default();
+    if has_flag("--json") {
+        config.output.format = OutputFormat::Json;
+    }
+    if config.output.format == OutputFormat::Text {
+        println!("Advent of Code");
+    }
     let Ok(mode) = get_execute_mode() else {
         println!("Failed to process parameters");
         return Ok(());
@@ -54,10 +61,15 @@ fn main() -> io::Result<()> {
     let factory = create_factory();
     match mode {
         ExecuteMode::Single { year, day } => {
-            execute_puzzle(&factory, year, day);
+            execute_puzzle(&factory, &config, year, day);
         }
         ExecuteMode::Year { year } => {
-            execute_year_puzzles(&factory, year);
+            execute_year_puzzles(&factory, &config, year);
+        }
+        ExecuteMode::All => {
+            for year in factory.years() {
+                execute_year_puzzles(&factory, &config, year);
+            }
         }
         ExecuteMode::Undefined => {
             println!("Input is missing in command line parameters or toml-file");
@@ -66,16 +78,16 @@ fn main() -> io::Result<()> {
     Ok(())
 }
 
-fn execute_year_puzzles(factory: &AggregatedFactory, year: usize) {
-    for day in 1..=25 {
+fn execute_year_puzzles(factory: &AggregatedFactory, config: &AocConfig, year: usize) {
+    for day in 1..=config.days_in_year(year) {
         let Some(puzzle) = factory.puzzle(year, day).and_then(|x| x.ok()) else {
             continue;
         };
-        execute(puzzle.deref());
+        execute(puzzle.deref(), config, year, day);
     }
 }
 
-fn execute_puzzle(factory: &AggregatedFactory, year: usize, day: usize) {
+fn execute_puzzle(factory: &AggregatedFactory, config: &AocConfig, year: usize, day: usize) {
     let puzzle = factory.puzzle(year, day);
     let Some(puzzle) = puzzle else {
         println!("Puzzle {year}\\{day} not found");
@@ -85,7 +97,7 @@ fn execute_puzzle(factory: &AggregatedFactory, year: usize, day: usize) {
         println!("Failed to create solution for {year}\\{day} puzzle");
         return;
     };
-    execute(puzzle.deref());
+    execute(puzzle.deref(), config, year, day);
 }
 
 fn create_factory() -> AggregatedFactory {
@@ -107,20 +119,47 @@ fn create_factory() -> AggregatedFactory {
     factory
 }
 
-fn execute(solution: &dyn Solution) {
-    println!();
+fn execute(solution: &dyn Solution, config: &AocConfig, year: usize, day: usize) {
+    let is_text_output = config.output.format == OutputFormat::Text;
     let mut description = solution.description();
     if description.is_empty() {
         description = "## UNTITLED PUZZLE ##".to_string();
     }
-    println!("{}", description);
+    if is_text_output {
+        println!();
+        println!("{}", description);
+    }
+    let iterations = config.bench.iterations.max(1);
     let measure = |part: u8, proc: &dyn Fn() -> String| {
         let now = Instant::now();
-        let result = proc();
-        let duration = now.elapsed().as_millis();
-        let title = format!("{} ms for part {}", duration, part);
-        println!("{:>30}: {}", title, result);
+        let mut result = proc();
+        for _ in 1..iterations {
+            result = proc();
+        }
+        let duration = now.elapsed().as_millis() / iterations as u128;
+        if is_text_output {
+            let title = format!("{} ms for part {}", duration, part);
+            println!("{:>30}: {}", title, result);
+        }
+        PartReport {
+            part,
+            result,
+            duration_ms: duration,
+        }
+    };
+    let report = PuzzleReport {
+        year,
+        day,
+        description,
+        parts: vec![
+            measure(1, &|| solution.part_one()),
+            measure(2, &|| solution.part_two()),
+        ],
     };
-    measure(1, &|| solution.part_one());
-    measure(2, &|| solution.part_two());
-}
\ No newline at end of file
+    if config.output.format == OutputFormat::Json {
+        match serde_json::to_string(&report) {
+            Ok(json) => println!("{json}"),
+            Err(err) => eprintln!("Failed to serialize report: {err}"),
+        }
+    }
+}
@@ -31,6 +31,10 @@ impl PuzzleFactory {
         Self { year, producers }
     }
 
+    pub fn year(&self) -> usize {
+        self.year
+    }
+
     pub fn puzzle(&self, year: usize, day: usize) -> PuzzleFactoryResult<Box<dyn Solution>> {
         if year != self.year || day == 0 || day > 25 {
             return Err(PuzzleFactoryError::NotFound);
@@ -57,6 +61,13 @@ impl AggregatedFactory {
         self.factories.push(factory);
     }
 
+    /// Registered years in ascending order
+    pub fn years(&self) -> Vec<usize> {
+        let mut years = self.factories.iter().map(|x| x.year()).collect::<Vec<_>>();
+        years.sort();
+        years
+    }
+
     pub fn puzzle(
         &self,
         year: usize,
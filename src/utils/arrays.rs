@@ -4,10 +4,84 @@ use super::Point2d;
 
 pub type Vec2<T> = Vec<Vec<T>>;
 
-pub trait Transformable2d {
+/// Symmetry of the square: the optional upside-down flip
+/// followed by `rotation` clockwise quarter turns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dihedral {
+    pub is_flipped: bool,
+    pub rotation: u8,
+}
+
+impl Dihedral {
+    pub const ALL: [Self; 8] = [
+        Self::new(false, 0),
+        Self::new(false, 1),
+        Self::new(false, 2),
+        Self::new(false, 3),
+        Self::new(true, 0),
+        Self::new(true, 1),
+        Self::new(true, 2),
+        Self::new(true, 3),
+    ];
+
+    pub const fn new(is_flipped: bool, rotation: u8) -> Self {
+        Self {
+            is_flipped,
+            rotation: rotation % 4,
+        }
+    }
+}
+
+pub trait Transformable2d: Clone {
     fn flipped_horizontally(&self) -> Self;
     fn flipped_vertically(&self) -> Self;
     fn transposed(&self) -> Self;
+
+    /// Clockwise quarter turn
+    fn rotated90(&self) -> Self {
+        self.transposed().flipped_horizontally()
+    }
+
+    fn rotated180(&self) -> Self {
+        self.flipped_horizontally().flipped_vertically()
+    }
+
+    fn rotated270(&self) -> Self {
+        self.transposed().flipped_vertically()
+    }
+
+    fn transformed(&self, transform: Dihedral) -> Self {
+        let data = if transform.is_flipped {
+            self.flipped_vertically()
+        } else {
+            self.clone()
+        };
+        match transform.rotation {
+            1 => data.rotated90(),
+            2 => data.rotated180(),
+            3 => data.rotated270(),
+            _ => data,
+        }
+    }
+
+    /// All 8 orientations with the transforms producing them
+    fn orientations(&self) -> impl Iterator<Item = (Dihedral, Self)> + '_ {
+        Dihedral::ALL
+            .into_iter()
+            .map(|transform| (transform, self.transformed(transform)))
+    }
+
+    /// The smallest orientation, it's the same for all the orientations
+    /// of the data, so it serves as the lookup key for the patterns
+    fn canonical(&self) -> Self
+    where
+        Self: Ord,
+    {
+        self.orientations()
+            .map(|(_, data)| data)
+            .min()
+            .expect("There are 8 orientations")
+    }
 }
 
 impl<T: Clone> Transformable2d for Vec2<T> {
@@ -34,6 +108,47 @@ impl<T: Clone> Transformable2d for Vec2<T> {
     }
 }
 
+/// Cuts the grid into the square blocks of the given size,
+/// `None` if the grid can't be tiled by them
+pub fn split_blocks<T: Clone>(data: &[Vec<T>], size: usize) -> Option<Vec2<Vec2<T>>> {
+    if size == 0
+        || !data.len().is_multiple_of(size)
+        || data.iter().any(|row| !row.len().is_multiple_of(size))
+    {
+        return None;
+    }
+    let blocks = data
+        .chunks(size)
+        .map(|rows| {
+            let cols = rows[0].len();
+            (0..cols)
+                .step_by(size)
+                .map(|col| {
+                    rows.iter()
+                        .map(|row| row.get(col..col + size).map(|x| x.to_vec()))
+                        .collect::<Option<Vec2<T>>>()
+                })
+                .collect::<Option<Vec<_>>>()
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(blocks)
+}
+
+/// Glues the blocks back, the reverse of `split_blocks`
+pub fn join_blocks<T: Clone>(blocks: &[Vec<Vec2<T>>]) -> Vec2<T> {
+    blocks
+        .iter()
+        .flat_map(|row| {
+            let height = row.first().map(|block| block.len()).unwrap_or_default();
+            (0..height).map(move |i| {
+                row.iter()
+                    .flat_map(|block| block[i].iter().cloned())
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect()
+}
+
 pub trait Diminishable: Sized {
     fn diminished(&self, size: usize) -> Option<Self>;
 }
@@ -130,6 +245,53 @@ mod test {
         assert_eq!(expected.flipped_vertically(), src);
     }
 
+    #[test]
+    fn transformable_rotate() {
+        let src = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        let expected = vec![vec![4, 1], vec![5, 2], vec![6, 3]];
+        assert_eq!(src.rotated90(), expected);
+        assert_eq!(src.rotated180(), vec![vec![6, 5, 4], vec![3, 2, 1]]);
+        assert_eq!(src.rotated270(), expected.rotated180());
+        assert_eq!(src.rotated90().rotated270(), src);
+    }
+
+    #[test]
+    fn transformable_dihedral() {
+        let src = vec![vec![1, 2], vec![3, 4]];
+        let orientations = src.orientations().collect::<Vec<_>>();
+        assert_eq!(orientations.len(), 8);
+        assert_eq!(orientations[0], (Dihedral::new(false, 0), src.clone()));
+        assert_eq!(orientations[4].1, src.flipped_vertically());
+        let distinct = orientations
+            .iter()
+            .map(|(_, x)| x.clone())
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(distinct.len(), 8);
+        for (transform, data) in &orientations {
+            assert_eq!(data.orientations().filter(|(_, x)| *x == src).count(), 1);
+            // the flipped transforms undo themselves
+            if transform.is_flipped {
+                assert_eq!(data.transformed(*transform), src);
+            }
+        }
+        assert_eq!(src.canonical(), vec![vec![1, 2], vec![3, 4]]);
+        assert_eq!(src.rotated90().flipped_horizontally().canonical(), src);
+    }
+
+    #[test]
+    fn blocks_split_join() {
+        let src = (0..4)
+            .map(|row| (0..6).map(|col| row * 6 + col).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let blocks = split_blocks(&src, 2).unwrap();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].len(), 3);
+        assert_eq!(blocks[1][2], vec![vec![16, 17], vec![22, 23]]);
+        assert_eq!(join_blocks(&blocks), src);
+        assert_eq!(split_blocks(&src, 3), None);
+        assert_eq!(split_blocks(&src, 0), None);
+    }
+
     #[test]
     fn diminishable_0() {
         let inp = vec![
@@ -0,0 +1,295 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use super::{
+    cartesian::RepeativeCartesianIter,
+    cycle::{find_cycle_by_key, CycleHistory},
+    grid::Grid,
+    hyper_point::HyperPoint,
+    Point2d,
+};
+
+/// Number of neighbours in each state
+#[derive(Debug, Clone)]
+pub struct NeighbourCounts<S> {
+    counts: Vec<(S, usize)>,
+}
+
+impl<S: PartialEq> NeighbourCounts<S> {
+    fn new() -> Self {
+        Self { counts: Vec::new() }
+    }
+
+    fn add(&mut self, state: S, count: usize) {
+        match self.counts.iter_mut().find(|(s, _)| *s == state) {
+            Some((_, value)) => *value += count,
+            None => self.counts.push((state, count)),
+        }
+    }
+
+    pub fn count(&self, state: &S) -> usize {
+        self.counts
+            .iter()
+            .find(|(s, _)| s == state)
+            .map(|(_, count)| *count)
+            .unwrap_or_default()
+    }
+}
+
+/// Cells that are considered as neighbours on the plane
+#[derive(Debug, Clone)]
+pub enum Neighbourhood {
+    /// 8 surrounding cells
+    Moore,
+    /// 4 orthogonally adjacent cells
+    VonNeumann,
+}
+
+impl Neighbourhood {
+    pub fn offsets(&self) -> Vec<Point2d<isize>> {
+        match self {
+            Self::Moore => [-1, 0, 1]
+                .cartesian_iter(2)
+                .filter(|v| v.iter().any(|x| *x != 0))
+                .map(|v| Point2d::new(v[0], v[1]))
+                .collect(),
+            Self::VonNeumann => vec![
+                Point2d::new(0, -1),
+                Point2d::new(1, 0),
+                Point2d::new(0, 1),
+                Point2d::new(-1, 0),
+            ],
+        }
+    }
+}
+
+/// Automaton on the fixed-size grid, the cells outside the grid are in the background state.
+/// The background evolves too as if all its neighbours were background cells
+#[derive(Debug, Clone)]
+pub struct DenseAutomaton<S> {
+    grid: Grid<S>,
+    background: S,
+    offsets: Vec<Point2d<isize>>,
+}
+
+impl<S: Clone + PartialEq> DenseAutomaton<S> {
+    pub fn new(grid: Grid<S>, neighbourhood: &Neighbourhood, background: S) -> Self {
+        Self {
+            grid,
+            background,
+            offsets: neighbourhood.offsets(),
+        }
+    }
+
+    pub fn grid(&self) -> &Grid<S> {
+        &self.grid
+    }
+
+    pub fn grid_mut(&mut self) -> &mut Grid<S> {
+        &mut self.grid
+    }
+
+    /// Number of the grid cells in the given state
+    pub fn count(&self, state: &S) -> usize {
+        self.grid.iter().filter(|(_, s)| *s == state).count()
+    }
+
+    fn cell(&self, p: &Point2d<isize>) -> &S {
+        self.grid.get_signed(p).unwrap_or(&self.background)
+    }
+
+    pub fn next(&self, rule: impl Fn(&S, &NeighbourCounts<S>) -> S) -> Self {
+        let mut grid = Grid::new(self.grid.rows(), self.grid.cols(), self.background.clone());
+        for y in 0..grid.rows() {
+            for x in 0..grid.cols() {
+                let p = Point2d::new(x as isize, y as isize);
+                let mut counts = NeighbourCounts::new();
+                for offset in &self.offsets {
+                    counts.add(self.cell(&p.add(offset)).clone(), 1);
+                }
+                grid[Point2d::new(x, y)] = rule(self.cell(&p), &counts);
+            }
+        }
+        let mut counts = NeighbourCounts::new();
+        counts.add(self.background.clone(), self.offsets.len());
+        Self {
+            grid,
+            background: rule(&self.background, &counts),
+            offsets: self.offsets.clone(),
+        }
+    }
+
+    pub fn step(&mut self, rule: impl Fn(&S, &NeighbourCounts<S>) -> S) {
+        *self = self.next(rule);
+    }
+
+    pub fn run(&mut self, generations: usize, rule: impl Fn(&S, &NeighbourCounts<S>) -> S) {
+        for _ in 0..generations {
+            self.step(&rule);
+        }
+    }
+}
+
+impl<S: Clone + Eq + Hash> DenseAutomaton<S> {
+    /// Runs the automaton till the grid and background repeat,
+    /// it always happens as the number of states of the fixed-size grid is finite
+    pub fn find_cycle(&self, rule: impl Fn(&S, &NeighbourCounts<S>) -> S) -> CycleHistory<Self> {
+        find_cycle_by_key(
+            self.clone(),
+            |automaton| automaton.next(&rule),
+            |automaton| (automaton.grid.clone(), automaton.background.clone()),
+        )
+    }
+}
+
+/// Automaton with two states where only the alive cells are stored,
+/// suits unbounded and N-dimensional worlds
+#[derive(Debug, Clone)]
+pub struct SparseAutomaton<P, F> {
+    alive: HashSet<P>,
+    neighbours: F,
+}
+
+impl<P, F> SparseAutomaton<P, F>
+where
+    P: Clone + Eq + Hash,
+    F: Fn(&P) -> Vec<P>,
+{
+    pub fn new(alive: impl IntoIterator<Item = P>, neighbours: F) -> Self {
+        Self {
+            alive: alive.into_iter().collect(),
+            neighbours,
+        }
+    }
+
+    pub fn alive(&self) -> &HashSet<P> {
+        &self.alive
+    }
+
+    /// `rule` receives the cell state and the number of alive neighbours
+    pub fn step(&mut self, rule: impl Fn(bool, usize) -> bool) {
+        let mut counts = HashMap::<P, usize>::new();
+        for p in &self.alive {
+            for adj in (self.neighbours)(p) {
+                *counts.entry(adj).or_default() += 1;
+            }
+        }
+        let mut alive = counts
+            .iter()
+            .filter(|(p, count)| rule(self.alive.contains(*p), **count))
+            .map(|(p, _)| p.clone())
+            .collect::<HashSet<_>>();
+        self.alive
+            .iter()
+            .filter(|p| !counts.contains_key(*p) && rule(true, 0))
+            .for_each(|p| {
+                alive.insert(p.clone());
+            });
+        self.alive = alive;
+    }
+
+    pub fn run(&mut self, generations: usize, rule: impl Fn(bool, usize) -> bool) {
+        for _ in 0..generations {
+            self.step(&rule);
+        }
+    }
+}
+
+/// Rule in the `B/S` notation, e.g. Conway's life is `life_rule(&[3], &[2, 3])`
+pub fn life_rule<'a>(born: &'a [usize], survive: &'a [usize]) -> impl Fn(bool, usize) -> bool + 'a {
+    move |is_alive, count| {
+        if is_alive {
+            survive.contains(&count)
+        } else {
+            born.contains(&count)
+        }
+    }
+}
+
+/// Neighbours of the N-dimensional point including the diagonal ones
+pub fn hyper_neighbours(dimension: usize) -> impl Fn(&HyperPoint<isize>) -> Vec<HyperPoint<isize>> {
+    let offsets = [-1, 0, 1]
+        .cartesian_iter(dimension)
+        .filter(|v| v.iter().any(|x| *x != 0))
+        .map(HyperPoint::from)
+        .collect::<Vec<_>>();
+    move |p| offsets.iter().map(|offset| p.add(offset)).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse_grid(lines: &[&str]) -> Grid<bool> {
+        Grid::parse(lines, |ch| Some(ch == '#')).unwrap()
+    }
+
+    fn life(cell: &bool, counts: &NeighbourCounts<bool>) -> bool {
+        life_rule(&[3], &[2, 3])(*cell, counts.count(&true))
+    }
+
+    #[test]
+    fn automaton_neighbourhood() {
+        assert_eq!(Neighbourhood::Moore.offsets().len(), 8);
+        assert_eq!(Neighbourhood::VonNeumann.offsets().len(), 4);
+    }
+
+    #[test]
+    fn automaton_dense_life() {
+        // 2015 day 18 example
+        let grid = parse_grid(&[".#.#.#", "...##.", "#....#", "..#...", "#.#..#", "####.."]);
+        let mut automaton = DenseAutomaton::new(grid, &Neighbourhood::Moore, false);
+        automaton.run(4, life);
+        assert_eq!(automaton.count(&true), 4);
+    }
+
+    #[test]
+    fn automaton_dense_background_flip() {
+        // the cell without alive neighbours becomes alive and vice versa
+        let rule = |_: &bool, counts: &NeighbourCounts<bool>| counts.count(&true) == 0;
+        let mut automaton = DenseAutomaton::new(parse_grid(&["."]), &Neighbourhood::Moore, false);
+        automaton.step(rule);
+        assert_eq!(automaton.count(&true), 1);
+        // the background became alive as well
+        automaton.step(rule);
+        assert_eq!(automaton.count(&true), 0);
+        assert_eq!(automaton.find_cycle(rule).cycle.length, 2);
+    }
+
+    #[test]
+    fn automaton_dense_cycle() {
+        let blinker = parse_grid(&[".....", "..#..", "..#..", "..#..", "....."]);
+        let automaton = DenseAutomaton::new(blinker, &Neighbourhood::Moore, false);
+        let history = automaton.find_cycle(life);
+        assert_eq!(history.cycle.start, 0);
+        assert_eq!(history.cycle.length, 2);
+        assert_eq!(history.state_at(1_000_000_001).count(&true), 3);
+        assert_eq!(
+            history.state_at(1_000_000_001).grid(),
+            history.states[1].grid()
+        );
+    }
+
+    #[test]
+    fn automaton_sparse_hyper() {
+        // 2020 day 17 example
+        let alive = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]
+            .into_iter()
+            .map(|(x, y)| HyperPoint::from(vec![x, y, 0]));
+        let mut automaton = SparseAutomaton::new(alive, hyper_neighbours(3));
+        automaton.run(6, life_rule(&[3], &[2, 3]));
+        assert_eq!(automaton.alive().len(), 112);
+    }
+
+    #[test]
+    fn automaton_sparse_isolated_cells() {
+        let mut automaton = SparseAutomaton::new([0i32, 10], |x: &i32| vec![x - 1, x + 1]);
+        automaton.step(|is_alive, count| is_alive || count > 0);
+        assert_eq!(automaton.alive().len(), 6);
+        automaton.step(|is_alive, _| is_alive);
+        assert_eq!(automaton.alive().len(), 6);
+        assert!(automaton.alive().contains(&11));
+    }
+}
@@ -0,0 +1,379 @@
+use std::ops::{BitAnd, BitOr, Sub};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Iterator over the set bits of the words in ascending order
+pub struct Bits<'a> {
+    words: &'a [u64],
+    index: usize,
+    current: u64,
+}
+
+impl<'a> Bits<'a> {
+    fn new(words: &'a [u64]) -> Self {
+        Self {
+            words,
+            index: 0,
+            current: words.first().copied().unwrap_or_default(),
+        }
+    }
+}
+
+impl Iterator for Bits<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current == 0 {
+            self.index += 1;
+            self.current = *self.words.get(self.index)?;
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        // clears the lowest set bit
+        self.current &= self.current - 1;
+        Some(self.index * WORD_BITS + bit)
+    }
+}
+
+/// Subsets of the given elements, from the empty one to the full one
+fn subsets<S: Default + Extend<usize>>(elements: Vec<usize>) -> impl Iterator<Item = S> {
+    assert!(elements.len() < WORD_BITS, "Too many elements to enumerate");
+    (0u64..1 << elements.len()).map(move |mask| {
+        let mut subset = S::default();
+        subset.extend(Bits::new(&[mask]).map(|i| elements[i]));
+        subset
+    })
+}
+
+/// Set of integers below `N * 64` packed into the words,
+/// it's `Copy` and hashable, so it fits the memoization keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BitSet<const N: usize = 1> {
+    words: [u64; N],
+}
+
+impl<const N: usize> Default for BitSet<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> BitSet<N> {
+    pub const CAPACITY: usize = N * WORD_BITS;
+
+    pub const fn new() -> Self {
+        Self { words: [0; N] }
+    }
+
+    /// Returns `true` if the value wasn't present
+    pub fn insert(&mut self, value: usize) -> bool {
+        assert!(value < Self::CAPACITY, "Value {value} is out of capacity");
+        let (word, mask) = (value / WORD_BITS, 1 << (value % WORD_BITS));
+        let is_new = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        is_new
+    }
+
+    /// Returns `true` if the value was present
+    pub fn remove(&mut self, value: usize) -> bool {
+        let was_present = self.contains(value);
+        if was_present {
+            self.words[value / WORD_BITS] &= !(1 << (value % WORD_BITS));
+        }
+        was_present
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        value < Self::CAPACITY && self.words[value / WORD_BITS] & (1 << (value % WORD_BITS)) != 0
+    }
+
+    /// Copy of the set with the value added
+    pub fn with(mut self, value: usize) -> Self {
+        self.insert(value);
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|x| x.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|x| *x == 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.words = [0; N];
+    }
+
+    pub fn iter(&self) -> Bits<'_> {
+        Bits::new(&self.words)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a | b)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a & !b)
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    /// All subsets of this set, the set must have less than 64 elements
+    pub fn subsets(&self) -> impl Iterator<Item = Self> {
+        subsets(self.iter().collect())
+    }
+
+    fn zip_with(&self, other: &Self, f: impl Fn(u64, u64) -> u64) -> Self {
+        let mut words = self.words;
+        words
+            .iter_mut()
+            .zip(other.words)
+            .for_each(|(a, b)| *a = f(*a, b));
+        Self { words }
+    }
+}
+
+impl<const N: usize> Extend<usize> for BitSet<N> {
+    fn extend<T: IntoIterator<Item = usize>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|x| {
+            self.insert(x);
+        });
+    }
+}
+
+impl<const N: usize> FromIterator<usize> for BitSet<N> {
+    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+/// Set of integers that grows on demand. Trailing zero words are never kept,
+/// so the equal sets have the same representation
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct DynBitSet {
+    words: Vec<u64>,
+}
+
+impl DynBitSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the value wasn't present
+    pub fn insert(&mut self, value: usize) -> bool {
+        let (word, mask) = (value / WORD_BITS, 1 << (value % WORD_BITS));
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        let is_new = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        is_new
+    }
+
+    /// Returns `true` if the value was present
+    pub fn remove(&mut self, value: usize) -> bool {
+        let was_present = self.contains(value);
+        if was_present {
+            self.words[value / WORD_BITS] &= !(1 << (value % WORD_BITS));
+            self.trim();
+        }
+        was_present
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        self.words
+            .get(value / WORD_BITS)
+            .is_some_and(|word| word & (1 << (value % WORD_BITS)) != 0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|x| x.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.words.clear();
+    }
+
+    pub fn iter(&self) -> Bits<'_> {
+        Bits::new(&self.words)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let (long, short) = if self.words.len() >= other.words.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut result = long.clone();
+        result
+            .words
+            .iter_mut()
+            .zip(&short.words)
+            .for_each(|(a, b)| *a |= b);
+        result
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut result = Self {
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(a, b)| a & b)
+                .collect(),
+        };
+        result.trim();
+        result
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        result
+            .words
+            .iter_mut()
+            .zip(&other.words)
+            .for_each(|(a, b)| *a &= !b);
+        result.trim();
+        result
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    /// All subsets of this set, the set must have less than 64 elements
+    pub fn subsets(&self) -> impl Iterator<Item = Self> {
+        subsets(self.iter().collect())
+    }
+
+    fn trim(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+}
+
+impl Extend<usize> for DynBitSet {
+    fn extend<T: IntoIterator<Item = usize>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|x| {
+            self.insert(x);
+        });
+    }
+}
+
+impl FromIterator<usize> for DynBitSet {
+    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+macro_rules! impl_set_ops {
+    ($t:ty $(, $n:ident)?) => {
+        impl$(<const $n: usize>)? BitOr for &$t {
+            type Output = $t;
+
+            fn bitor(self, rhs: Self) -> Self::Output {
+                self.union(rhs)
+            }
+        }
+
+        impl$(<const $n: usize>)? BitAnd for &$t {
+            type Output = $t;
+
+            fn bitand(self, rhs: Self) -> Self::Output {
+                self.intersection(rhs)
+            }
+        }
+
+        impl$(<const $n: usize>)? Sub for &$t {
+            type Output = $t;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                self.difference(rhs)
+            }
+        }
+    };
+}
+
+impl_set_ops!(BitSet<N>, N);
+impl_set_ops!(DynBitSet);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bitset_fixed() {
+        let mut set = BitSet::<2>::new();
+        assert!(set.insert(3));
+        assert!(!set.insert(3));
+        assert!(set.insert(100));
+        assert!(set.contains(100) && !set.contains(99) && !set.contains(1000));
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![3, 100]);
+        assert!(set.remove(3));
+        assert!(!set.remove(3));
+        assert_eq!(set.with(7).iter().collect::<Vec<_>>(), vec![7, 100]);
+
+        let a = [1, 2, 3].into_iter().collect::<BitSet>();
+        let b = [3, 4].into_iter().collect::<BitSet>();
+        assert_eq!((&a | &b).iter().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!((&a & &b).iter().collect::<Vec<_>>(), vec![3]);
+        assert_eq!((&a - &b).iter().collect::<Vec<_>>(), vec![1, 2]);
+        assert!(!a.is_disjoint(&b));
+        assert!((&a & &b).is_subset(&b));
+        assert_eq!(BitSet::<1>::CAPACITY, 64);
+    }
+
+    #[test]
+    fn bitset_dynamic() {
+        let mut set = DynBitSet::new();
+        set.insert(1000);
+        set.insert(5);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![5, 1000]);
+        set.remove(1000);
+        let small = [5].into_iter().collect::<DynBitSet>();
+        // trailing zero words don't affect the equality
+        assert_eq!(set, small);
+        let other = [5, 200].into_iter().collect::<DynBitSet>();
+        assert_eq!(&other - &small, [200].into_iter().collect());
+        assert_eq!(&small & &other, small);
+        assert_eq!((&small | &other).len(), 2);
+        assert!(small.is_subset(&other));
+        assert!(DynBitSet::new().is_empty());
+    }
+
+    #[test]
+    fn bitset_subsets() {
+        let set = [1, 4, 70].into_iter().collect::<BitSet<2>>();
+        let subsets = set.subsets().collect::<Vec<_>>();
+        assert_eq!(subsets.len(), 8);
+        assert!(subsets[0].is_empty());
+        assert_eq!(subsets[7], set);
+        assert!(subsets.iter().all(|x| x.is_subset(&set)));
+        let set = [2, 300].into_iter().collect::<DynBitSet>();
+        assert_eq!(set.subsets().map(|x| x.len()).sum::<usize>(), 4);
+    }
+}
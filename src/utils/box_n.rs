@@ -0,0 +1,113 @@
+use super::Integer;
+
+/// Axis-aligned box in `D` dimensions with the inclusive bounds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoxN<T, const D: usize> {
+    pub min: [T; D],
+    pub max: [T; D],
+}
+
+impl<T: Integer, const D: usize> BoxN<T, D> {
+    /// Creates box with the corners in arbitrary order
+    pub fn new(a: [T; D], b: [T; D]) -> Self {
+        let mut min = a;
+        let mut max = b;
+        for i in 0..D {
+            min[i] = a[i].min(b[i]);
+            max[i] = a[i].max(b[i]);
+        }
+        Self { min, max }
+    }
+
+    /// Number of integer points inside the box
+    pub fn volume(&self) -> T {
+        (0..D).fold(T::ONE, |acc, i| acc * (self.max[i] - self.min[i] + T::ONE))
+    }
+
+    pub fn contains(&self, point: &[T; D]) -> bool {
+        (0..D).all(|i| self.min[i] <= point[i] && point[i] <= self.max[i])
+    }
+
+    pub fn contains_box(&self, other: &Self) -> bool {
+        self.contains(&other.min) && self.contains(&other.max)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut result = *self;
+        for i in 0..D {
+            result.min[i] = self.min[i].max(other.min[i]);
+            result.max[i] = self.max[i].min(other.max[i]);
+            if result.min[i] > result.max[i] {
+                return None;
+            }
+        }
+        Some(result)
+    }
+
+    /// Splits the part of the box that isn't covered by other box
+    /// into at most `2 * D` disjoint boxes
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let Some(cut) = self.intersection(other) else {
+            return vec![*self];
+        };
+        let mut result = Vec::new();
+        let mut rest = *self;
+        for i in 0..D {
+            if rest.min[i] < cut.min[i] {
+                let mut slab = rest;
+                slab.max[i] = cut.min[i] - T::ONE;
+                result.push(slab);
+                rest.min[i] = cut.min[i];
+            }
+            if rest.max[i] > cut.max[i] {
+                let mut slab = rest;
+                slab.min[i] = cut.max[i] + T::ONE;
+                result.push(slab);
+                rest.max[i] = cut.max[i];
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn box_n_volume_and_contains() {
+        let cube = BoxN::new([10, 10, 10], [12, 12, 12]);
+        assert_eq!(cube.volume(), 27);
+        assert!(cube.contains(&[11, 10, 12]));
+        assert!(!cube.contains(&[11, 9, 12]));
+        assert_eq!(BoxN::new([3, 1], [1, 2]), BoxN::new([1, 1], [3, 2]));
+        assert!(cube.contains_box(&BoxN::new([11, 11, 11], [12, 12, 12])));
+    }
+
+    #[test]
+    fn box_n_intersection() {
+        let a = BoxN::new([0, 0], [5, 5]);
+        let b = BoxN::new([3, 4], [8, 9]);
+        assert_eq!(a.intersection(&b), Some(BoxN::new([3, 4], [5, 5])));
+        assert_eq!(a.intersection(&BoxN::new([6, 0], [7, 5])), None);
+    }
+
+    #[test]
+    fn box_n_subtract() {
+        // the first steps of the 2021 day 22 example
+        let a = BoxN::new([10i64, 10, 10], [12, 12, 12]);
+        let b = BoxN::new([11, 11, 11], [13, 13, 13]);
+        let parts = a.subtract(&b);
+        assert_eq!(parts.iter().map(|x| x.volume()).sum::<i64>(), 27 - 8);
+        for (i, x) in parts.iter().enumerate() {
+            assert!(a.contains_box(x));
+            assert!(x.intersection(&b).is_none());
+            assert!(parts
+                .iter()
+                .skip(i + 1)
+                .all(|y| x.intersection(y).is_none()));
+        }
+        assert_eq!(a.subtract(&BoxN::new([0, 0, 0], [1, 1, 1])), vec![a]);
+        assert!(a.subtract(&BoxN::new([0, 0, 0], [20, 20, 20])).is_empty());
+    }
+}
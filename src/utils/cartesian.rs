@@ -10,6 +10,7 @@ pub struct Cartesian<T, S: CartesianStore<T>> {
     store: S,
     indices: Vec<usize>,
     is_first: bool,
+    is_done: bool,
     phantom: PhantomData<T>,
 }
 
@@ -20,10 +21,12 @@ where
     fn with(store: S) -> Self {
         let count = store.len();
         let indices = vec![0; count];
+        let is_done = (0..count).any(|i| store.len_at(i) == 0);
         Self {
             store,
             indices,
             is_first: true,
+            is_done,
             phantom: PhantomData,
         }
     }
@@ -37,6 +40,9 @@ where
     type Item = Vec<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
         if self.is_first {
             self.is_first = false;
             return Some(self.yield_state());
@@ -49,6 +55,7 @@ where
                 return Some(self.yield_state());
             }
         }
+        self.is_done = true;
         None
     }
 }
@@ -107,6 +114,38 @@ where
     }
 }
 
+pub struct SliceStore<'a, T> {
+    options: Vec<&'a [T]>,
+}
+
+impl<T> CartesianStore<T> for SliceStore<'_, T>
+where
+    T: Clone,
+{
+    fn len(&self) -> usize {
+        self.options.len()
+    }
+
+    fn len_at(&self, index: usize) -> usize {
+        self.options[index].len()
+    }
+
+    fn output(&self, indices: &[usize]) -> Vec<T> {
+        indices
+            .iter()
+            .zip(self.options.iter())
+            .map(|(idx, arr)| arr[*idx].clone())
+            .collect::<Vec<_>>()
+    }
+}
+
+/// Product of the slices with different lengths, the last one changes first
+pub fn cartesian_product<'a, T: Clone>(options: &[&'a [T]]) -> Cartesian<T, SliceStore<'a, T>> {
+    Cartesian::with(SliceStore {
+        options: options.to_vec(),
+    })
+}
+
 pub trait RepeativeCartesianIter<T>
 where
     T: Clone,
@@ -148,5 +187,19 @@ mod tests {
         let cartesian = range.cartesian_iter(3).collect::<Vec<_>>();
 
         assert_eq!(cartesian, collected);
+        assert_eq!([0; 0].cartesian_iter(2).count(), 0);
+    }
+
+    #[test]
+    fn cartesian_slices() {
+        let a = ["x".to_string(), "y".to_string()];
+        let b = ["1".to_string()];
+        let c = ["p".to_string(), "q".to_string(), "r".to_string()];
+        let product = cartesian_product(&[&a, &b, &c]).collect::<Vec<_>>();
+        assert_eq!(product.len(), 6);
+        assert_eq!(product[0], vec!["x", "1", "p"]);
+        assert_eq!(product[5], vec!["y", "1", "r"]);
+        assert_eq!(cartesian_product(&[&a[..], &[]]).count(), 0);
+        assert_eq!(cartesian_product::<i32>(&[]).count(), 1);
     }
 }
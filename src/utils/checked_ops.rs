@@ -5,6 +5,12 @@ pub trait CheckedOps {
     fn checked_sub(self, rhs: Self) -> Option<Self>
     where
         Self: Sized;
+    fn checked_mul(self, rhs: Self) -> Option<Self>
+    where
+        Self: Sized;
+    fn checked_div(self, rhs: Self) -> Option<Self>
+    where
+        Self: Sized;
 }
 
 macro_rules! impl_checked_ops {
@@ -17,6 +23,12 @@ macro_rules! impl_checked_ops {
                 fn checked_sub(self, rhs: Self) -> Option<Self> {
                     Self::checked_sub(self, rhs)
                 }
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    Self::checked_mul(self, rhs)
+                }
+                fn checked_div(self, rhs: Self) -> Option<Self> {
+                    Self::checked_div(self, rhs)
+                }
             }
         )*
     };
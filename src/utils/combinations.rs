@@ -2,15 +2,16 @@
 // Combinations iterator
 //
 pub struct CombinationIterator<'a, T> {
-    array: &'a Vec<T>,
+    array: &'a [T],
     c: Vec<usize>,
     k: usize,
     is_first: bool,
+    is_done: bool,
 }
 
-impl<'a, T: Copy> CombinationIterator<'a, T> {
+impl<'a, T: Clone> CombinationIterator<'a, T> {
     #[allow(clippy::needless_range_loop)]
-    pub fn from_vector(array: &'a Vec<T>, k: usize) -> Self {
+    pub fn from_vector(array: &'a [T], k: usize) -> Self {
         let n = array.len();
         let mut c = vec![0; k + 3];
         for i in 1..=k {
@@ -23,21 +24,25 @@ impl<'a, T: Copy> CombinationIterator<'a, T> {
             c,
             k,
             is_first: true,
+            is_done: k > n,
         }
     }
 
     fn get_combination(&self) -> Vec<T> {
         self.c[1..=self.k]
             .iter()
-            .map(|i| self.array[*i])
+            .map(|i| self.array[*i].clone())
             .collect::<Vec<T>>()
     }
 }
 
-impl<T: Copy> Iterator for CombinationIterator<'_, T> {
+impl<T: Clone> Iterator for CombinationIterator<'_, T> {
     type Item = Vec<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
         if self.is_first {
             self.is_first = false;
         } else {
@@ -47,6 +52,7 @@ impl<T: Copy> Iterator for CombinationIterator<'_, T> {
                 j += 1;
             }
             if j > self.k {
+                self.is_done = true;
                 return None;
             }
             self.c[j] += 1;
@@ -55,14 +61,133 @@ impl<T: Copy> Iterator for CombinationIterator<'_, T> {
     }
 }
 
+//
+// Combinations with replacement in lexicographic order of indices
+//
+pub struct CombinationsWithReplacement<'a, T> {
+    array: &'a [T],
+    indices: Vec<usize>,
+    is_first: bool,
+    is_done: bool,
+}
+
+impl<'a, T: Clone> CombinationsWithReplacement<'a, T> {
+    pub fn from_array(array: &'a [T], k: usize) -> Self {
+        Self {
+            array,
+            indices: vec![0; k],
+            is_first: true,
+            is_done: array.is_empty() && k > 0,
+        }
+    }
+
+    fn get_combination(&self) -> Vec<T> {
+        self.indices
+            .iter()
+            .map(|i| self.array[*i].clone())
+            .collect()
+    }
+}
+
+impl<T: Clone> Iterator for CombinationsWithReplacement<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+        if self.is_first {
+            self.is_first = false;
+            return Some(self.get_combination());
+        }
+        let len = self.array.len();
+        let Some(i) = self.indices.iter().rposition(|x| *x + 1 != len) else {
+            self.is_done = true;
+            return None;
+        };
+        let value = self.indices[i] + 1;
+        self.indices[i..].iter_mut().for_each(|x| *x = value);
+        Some(self.get_combination())
+    }
+}
+
+//
+// All subsets, the bits of the counter select the items
+//
+pub struct PowerSet<'a, T> {
+    array: &'a [T],
+    mask: u64,
+    is_done: bool,
+}
+
+impl<'a, T: Clone> PowerSet<'a, T> {
+    pub fn from_array(array: &'a [T]) -> Self {
+        assert!(array.len() < 64, "Too many items for the power set");
+        Self {
+            array,
+            mask: 0,
+            is_done: false,
+        }
+    }
+}
+
+impl<T: Clone> Iterator for PowerSet<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+        let subset = self
+            .array
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.mask & (1 << i) != 0)
+            .map(|(_, x)| x.clone())
+            .collect();
+        self.mask += 1;
+        self.is_done = self.mask >> self.array.len() != 0;
+        Some(subset)
+    }
+}
+
+/// Binomial coefficient, `None` on overflow
+pub fn n_choose_k(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    let mut result = 1u128;
+    for i in 0..k as u128 {
+        // the product of i + 1 consecutive numbers is divisible by (i + 1)!
+        result = result * (n as u128 - i) / (i + 1);
+        if result > u64::MAX as u128 {
+            return None;
+        }
+    }
+    Some(result as u64)
+}
+
 pub trait Combinable<T> {
     fn combination_iter(&self, k: usize) -> CombinationIterator<'_, T>;
+
+    fn combination_with_replacement_iter(&self, k: usize) -> CombinationsWithReplacement<'_, T>;
+
+    fn power_set_iter(&self) -> PowerSet<'_, T>;
 }
 
-impl<T: Copy> Combinable<T> for Vec<T> {
+impl<T: Clone> Combinable<T> for [T] {
     fn combination_iter(&self, k: usize) -> CombinationIterator<'_, T> {
         CombinationIterator::from_vector(self, k)
     }
+
+    fn combination_with_replacement_iter(&self, k: usize) -> CombinationsWithReplacement<'_, T> {
+        CombinationsWithReplacement::from_array(self, k)
+    }
+
+    fn power_set_iter(&self) -> PowerSet<'_, T> {
+        PowerSet::from_array(self)
+    }
 }
 
 #[cfg(test)]
@@ -84,7 +209,7 @@ mod tests {
         .into_iter()
         .collect();
 
-        let combs = vec![0, 1, 2, 3]
+        let combs = [0, 1, 2, 3]
             .combination_iter(2)
             .collect::<Vec<Vec<usize>>>();
         assert_eq!(combs.len(), cases.len());
@@ -118,11 +243,61 @@ mod tests {
         ]
         .into_iter()
         .collect();
-        let combs = vec![0, 1, 2, 3, 4, 5]
+        let combs = [0, 1, 2, 3, 4, 5]
             .combination_iter(3)
             .collect::<Vec<Vec<usize>>>();
         assert_eq!(combs.len(), cases.len());
 
         combs.iter().for_each(|arr| assert!(cases.contains(arr)));
     }
+
+    #[test]
+    fn utils_combination_edge_cases() {
+        let names = vec!["a".to_string(), "b".to_string()];
+        assert_eq!(names.combination_iter(3).count(), 0);
+        assert_eq!(
+            names.combination_iter(0).collect::<Vec<_>>(),
+            vec![Vec::<String>::new()]
+        );
+        assert_eq!(
+            names.combination_iter(2).collect::<Vec<_>>(),
+            vec![names.clone()]
+        );
+        assert_eq!(n_choose_k(6, 3), Some(20));
+        assert_eq!(n_choose_k(3, 5), Some(0));
+        assert_eq!(n_choose_k(66, 33), Some(7219428434016265740));
+        assert_eq!(n_choose_k(70, 35), None);
+    }
+
+    #[test]
+    fn utils_combination_with_replacement() {
+        let combs = [1, 2, 3]
+            .combination_with_replacement_iter(2)
+            .collect::<Vec<_>>();
+        let expected = [[1, 1], [1, 2], [1, 3], [2, 2], [2, 3], [3, 3]];
+        assert_eq!(combs, expected.map(|x| x.to_vec()).to_vec());
+        assert_eq!([0; 5].combination_with_replacement_iter(3).count(), 35);
+        assert_eq!(
+            Vec::<i32>::new()
+                .combination_with_replacement_iter(2)
+                .count(),
+            0
+        );
+        assert_eq!(
+            Vec::<i32>::new()
+                .combination_with_replacement_iter(0)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn utils_power_set() {
+        let subsets = ["x", "y", "z"].power_set_iter().collect::<Vec<_>>();
+        assert_eq!(subsets.len(), 8);
+        assert_eq!(subsets[0], Vec::<&str>::new());
+        assert_eq!(subsets[5], vec!["x", "z"]);
+        assert_eq!(subsets[7], vec!["x", "y", "z"]);
+        assert_eq!(Vec::<i32>::new().power_set_iter().count(), 1);
+    }
 }
@@ -1,9 +1,9 @@
 use std::{
-    ops::{AddAssign, SubAssign},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
     str::FromStr,
 };
 
-use super::{checked_ops::CheckedOps, Direction};
+use super::{checked_ops::CheckedOps, Compass, Direction, Integer};
 
 #[derive(Debug)]
 pub enum PointParseError {
@@ -85,12 +85,18 @@ where
         }
     }
 
-    pub fn moved_with_dirs(&self, directions: &[Direction]) -> Self {
-        let mut cur = *self;
-        for d in directions {
-            cur = cur.moved_by(d);
+    /// Moves in the screen orientation, i.e. north is `y - 1`
+    pub fn moved_by_compass(&self, compass: &Compass) -> Self {
+        match *compass {
+            Compass::North => self.up(),
+            Compass::NorthEast => self.up().right(),
+            Compass::East => self.right(),
+            Compass::SouthEast => self.down().right(),
+            Compass::South => self.down(),
+            Compass::SouthWest => self.down().left(),
+            Compass::West => self.left(),
+            Compass::NorthWest => self.up().left(),
         }
-        cur
     }
 
     pub fn up(&self) -> Self {
@@ -123,12 +129,17 @@ where
         }
     }
 
-    pub fn safe_moved_with_dirs(&self, directions: &[Direction]) -> Option<Self> {
-        let mut cur = *self;
-        for d in directions {
-            cur = cur.safe_moved_by(d)?;
+    pub fn safe_moved_by_compass(&self, compass: &Compass) -> Option<Self> {
+        match *compass {
+            Compass::North => self.safe_up(),
+            Compass::NorthEast => self.safe_up()?.safe_right(),
+            Compass::East => self.safe_right(),
+            Compass::SouthEast => self.safe_down()?.safe_right(),
+            Compass::South => self.safe_down(),
+            Compass::SouthWest => self.safe_down()?.safe_left(),
+            Compass::West => self.safe_left(),
+            Compass::NorthWest => self.safe_up()?.safe_left(),
         }
-        Some(cur)
     }
 
     pub fn safe_up(&self) -> Option<Self> {
@@ -160,8 +171,237 @@ where
     }
 }
 
+impl<T: Copy + Neg<Output = T>> Point2d<T> {
+    /// Rotates the vector around the origin by the multiple of 90 degrees,
+    /// the positive angle is clockwise on the screen where `y` grows downwards
+    pub fn rotated_by(&self, degrees: isize) -> Self {
+        assert!(degrees % 90 == 0, "Angle must be multiple of 90 degrees");
+        match degrees.rem_euclid(360) {
+            0 => *self,
+            90 => Self::new(-self.y, self.x),
+            180 => Self::new(-self.x, -self.y),
+            _ => Self::new(self.y, -self.x),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Point3d<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3d<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Copy> Point3d<T> {
+    pub fn to_array(self) -> [T; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+impl<T> From<[T; 3]> for Point3d<T> {
+    fn from([x, y, z]: [T; 3]) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: FromStr> Point3d<T> {
+    pub fn parse_csv(s: &str) -> Result<Self, PointParseError> {
+        let mut tokens = s.split(',').map(|token| {
+            token
+                .trim()
+                .parse::<T>()
+                .map_err(|_| PointParseError::TokenError)
+        });
+        let (Some(x), Some(y), Some(z), None) =
+            (tokens.next(), tokens.next(), tokens.next(), tokens.next())
+        else {
+            return Err(PointParseError::InvalidFormat);
+        };
+        Ok(Self::new(x?, y?, z?))
+    }
+}
+
+impl<T: Integer> Point3d<T> {
+    pub fn manhattan(&self, other: &Self) -> T {
+        let diff = |a: T, b: T| if a > b { a - b } else { b - a };
+        diff(self.x, other.x) + diff(self.y, other.y) + diff(self.z, other.z)
+    }
+
+    pub fn euclidean_squared(&self, other: &Self) -> T {
+        let d = *self - *other;
+        d.dot(&d)
+    }
+
+    pub fn dot(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(&self, other: &Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+}
+
+impl<T: Integer + Neg<Output = T>> Point3d<T> {
+    /// Adjacent points which differ in the given number of coordinates
+    fn neighbours_by(&self, changed: usize) -> impl Iterator<Item = Self> + '_ {
+        let steps = [-T::ONE, T::ZERO, T::ONE];
+        steps
+            .into_iter()
+            .flat_map(move |x| steps.into_iter().map(move |y| (x, y)))
+            .flat_map(move |(x, y)| steps.into_iter().map(move |z| Self::new(x, y, z)))
+            .filter(move |d| d.to_array().iter().filter(|v| **v != T::ZERO).count() == changed)
+            .map(|d| *self + d)
+    }
+
+    /// 6 points sharing a face with the unit cube
+    pub fn face_neighbours(&self) -> impl Iterator<Item = Self> + '_ {
+        self.neighbours_by(1)
+    }
+
+    /// 12 points sharing an edge only
+    pub fn edge_neighbours(&self) -> impl Iterator<Item = Self> + '_ {
+        self.neighbours_by(2)
+    }
+
+    /// 8 points sharing a corner only
+    pub fn corner_neighbours(&self) -> impl Iterator<Item = Self> + '_ {
+        self.neighbours_by(3)
+    }
+
+    /// All 26 adjacent points
+    pub fn neighbours(&self) -> impl Iterator<Item = Self> + '_ {
+        (1..=3).flat_map(|changed| self.neighbours_by(changed))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point3d<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3d<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point3d<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point3d<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+/// One of the 24 orientations of the cube aligned with the axes,
+/// the matrix rows consist of a single non-zero element that is 1 or -1
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Rotation {
+    matrix: [[i8; 3]; 3],
+}
+
+impl Rotation {
+    pub const IDENTITY: Self = Self {
+        matrix: [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+    };
+
+    pub fn matrix(&self) -> &[[i8; 3]; 3] {
+        &self.matrix
+    }
+
+    /// All proper rotations starting with the identity
+    pub fn all() -> Vec<Self> {
+        const PERMUTATIONS: [([usize; 3], i8); 6] = [
+            ([0, 1, 2], 1),
+            ([0, 2, 1], -1),
+            ([1, 0, 2], -1),
+            ([1, 2, 0], 1),
+            ([2, 0, 1], 1),
+            ([2, 1, 0], -1),
+        ];
+        let mut result = Vec::with_capacity(24);
+        for (permutation, parity) in PERMUTATIONS {
+            for signs in 0..8 {
+                let sign = |i: usize| if signs & (1 << i) == 0 { 1 } else { -1 };
+                // the determinant must be 1 to exclude reflections
+                if parity * sign(0) * sign(1) * sign(2) != 1 {
+                    continue;
+                }
+                let mut matrix = [[0; 3]; 3];
+                for (row, col) in permutation.iter().enumerate() {
+                    matrix[row][*col] = sign(row);
+                }
+                result.push(Self { matrix });
+            }
+        }
+        result
+    }
+
+    /// Composition that applies `self` first and `other` next
+    pub fn then(&self, other: &Self) -> Self {
+        let mut matrix = [[0; 3]; 3];
+        for (row, values) in matrix.iter_mut().enumerate() {
+            for (col, value) in values.iter_mut().enumerate() {
+                *value = (0..3)
+                    .map(|k| other.matrix[row][k] * self.matrix[k][col])
+                    .sum();
+            }
+        }
+        Self { matrix }
+    }
+
+    pub fn inverse(&self) -> Self {
+        let mut matrix = [[0; 3]; 3];
+        for (row, values) in self.matrix.iter().enumerate() {
+            for (col, value) in values.iter().enumerate() {
+                matrix[col][row] = *value;
+            }
+        }
+        Self { matrix }
+    }
+
+    pub fn apply<T: Integer + Neg<Output = T>>(&self, point: &Point3d<T>) -> Point3d<T> {
+        let coordinates = point.to_array();
+        let rotate = |row: &[i8; 3]| {
+            row.iter()
+                .zip(coordinates)
+                .fold(T::ZERO, |acc, (k, value)| match k {
+                    1 => acc + value,
+                    -1 => acc - value,
+                    _ => acc,
+                })
+        };
+        Point3d::from(self.matrix.each_ref().map(rotate))
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use std::collections::HashSet;
+
     use super::*;
 
     #[test]
@@ -195,4 +435,81 @@ mod test {
         p.x = 0;
         assert_eq!(p.safe_right(), Some(Point2d::new(1, 0)));
     }
+
+    #[test]
+    fn coordinate_compass_moves() {
+        let p = Point2d::<u8>::zero();
+        assert_eq!(
+            p.safe_moved_by_compass(&Compass::SouthEast),
+            Some(Point2d::new(1, 1))
+        );
+        assert_eq!(p.safe_moved_by_compass(&Compass::NorthEast), None);
+        let p = Point2d::new(3, 3);
+        assert_eq!(p.moved_by_compass(&Compass::NorthWest), Point2d::new(2, 2));
+        let v = Point2d::new(10, -4);
+        assert_eq!(v.rotated_by(90), Point2d::new(4, 10));
+        assert_eq!(v.rotated_by(-90), Point2d::new(-4, -10));
+        assert_eq!(v.rotated_by(540), Point2d::new(-10, 4));
+    }
+
+    #[test]
+    fn coordinate_point3d() {
+        let a = Point3d::<i32>::parse_csv("1, -2,3").unwrap();
+        assert_eq!(a, Point3d::new(1, -2, 3));
+        assert!(Point3d::<i32>::parse_csv("1,2").is_err());
+        assert!(Point3d::<i32>::parse_csv("1,2,3,4").is_err());
+        assert!(Point3d::<i32>::parse_csv("1,x,3").is_err());
+        let b = Point3d::new(4, 0, -1);
+        assert_eq!(a + b, Point3d::new(5, -2, 2));
+        assert_eq!(a - b, Point3d::new(-3, -2, 4));
+        assert_eq!(-a * 2, Point3d::new(-2, 4, -6));
+        assert_eq!(a.manhattan(&b), 9);
+        assert_eq!(a.euclidean_squared(&b), 29);
+        assert_eq!(a.dot(&b), 1);
+        let (x, y) = (Point3d::new(1, 0, 0), Point3d::new(0, 1, 0));
+        assert_eq!(x.cross(&y), Point3d::new(0, 0, 1));
+        assert_eq!(
+            Point3d::<u8>::new(1, 5, 2).manhattan(&Point3d::new(3, 1, 2)),
+            6
+        );
+    }
+
+    #[test]
+    fn coordinate_point3d_neighbours() {
+        let p = Point3d::new(1, 1, 1);
+        assert_eq!(p.face_neighbours().count(), 6);
+        assert!(p.face_neighbours().all(|n| n.manhattan(&p) == 1));
+        assert_eq!(p.edge_neighbours().count(), 12);
+        assert!(p.edge_neighbours().all(|n| n.manhattan(&p) == 2));
+        assert_eq!(p.corner_neighbours().count(), 8);
+        assert!(p.corner_neighbours().all(|n| n.manhattan(&p) == 3));
+        assert_eq!(p.neighbours().collect::<HashSet<_>>().len(), 26);
+    }
+
+    #[test]
+    fn coordinate_rotations() {
+        let all = Rotation::all();
+        assert_eq!(all.len(), 24);
+        assert_eq!(all[0], Rotation::IDENTITY);
+        let p = Point3d::new(1, 2, 3);
+        let images = all.iter().map(|r| r.apply(&p)).collect::<HashSet<_>>();
+        assert_eq!(images.len(), 24);
+        for a in &all {
+            assert_eq!(a.then(&a.inverse()), Rotation::IDENTITY);
+            for b in &all {
+                let ab = a.then(b);
+                assert!(all.contains(&ab));
+                assert_eq!(ab.apply(&p), b.apply(&a.apply(&p)));
+            }
+        }
+        // 90 degrees around z turns x into y
+        let rz = all
+            .iter()
+            .find(|r| {
+                r.apply(&Point3d::new(1, 0, 0)) == Point3d::new(0, 1, 0)
+                    && r.apply(&Point3d::new(0, 0, 1)) == Point3d::new(0, 0, 1)
+            })
+            .unwrap();
+        assert_eq!(rz.then(rz).then(rz).then(rz), Rotation::IDENTITY);
+    }
 }
@@ -0,0 +1,238 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    ops::{Add, Mul, Sub},
+};
+
+/// Sequence `x0, f(x0), f(f(x0)), ...` that starts repeating after `start` steps
+/// with the period `length`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Smallest step index that has the same state as the given step
+    pub fn index_at(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        }
+    }
+
+    /// Number of full periods passed before the step
+    pub fn periods_at(&self, step: usize) -> usize {
+        step.saturating_sub(self.start) / self.length
+    }
+}
+
+/// Brent's algorithm, suits the cheap states as it keeps only two of them
+pub fn brent<S: Clone + PartialEq>(initial: S, next: impl Fn(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = next(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = next(&hare);
+        length += 1;
+    }
+
+    tortoise = initial.clone();
+    hare = initial;
+    for _ in 0..length {
+        hare = next(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Detected cycle with all states observed till the first repetition,
+/// `states[cycle.start + cycle.length]` is the repeated one
+#[derive(Debug, Clone)]
+pub struct CycleHistory<S> {
+    pub cycle: Cycle,
+    pub states: Vec<S>,
+}
+
+impl<S> CycleHistory<S> {
+    /// State at the arbitrary step, for the projection-key detection
+    /// it's the state which key matches the key at the step
+    pub fn state_at(&self, step: usize) -> &S {
+        &self.states[self.cycle.index_at(step)]
+    }
+
+    /// Extrapolates the value that grows by the constant delta every period,
+    /// e.g. the tower height which top rows are used as the detection key
+    pub fn value_at<V>(&self, step: usize, value: impl Fn(&S) -> V) -> V
+    where
+        V: Copy + Add<Output = V> + Sub<Output = V> + Mul<Output = V> + TryFrom<usize>,
+    {
+        let cycle = self.cycle;
+        let base = value(self.state_at(step));
+        if step < cycle.start {
+            return base;
+        }
+        let delta =
+            value(&self.states[cycle.start + cycle.length]) - value(&self.states[cycle.start]);
+        let Ok(periods) = V::try_from(cycle.periods_at(step)) else {
+            panic!("Number of periods doesn't fit the value type");
+        };
+        base + delta * periods
+    }
+}
+
+/// Hash based detection, every state is computed only once
+pub fn find_cycle<S>(initial: S, next: impl FnMut(&S) -> S) -> CycleHistory<S>
+where
+    S: Clone + Eq + Hash,
+{
+    find_cycle_by_key(initial, next, |state| state.clone())
+}
+
+/// Hash based detection on the projection of the state,
+/// the key must define the future of the sequence (except the values extrapolated with `value_at`)
+pub fn find_cycle_by_key<S, K>(
+    initial: S,
+    mut next: impl FnMut(&S) -> S,
+    key: impl Fn(&S) -> K,
+) -> CycleHistory<S>
+where
+    K: Eq + Hash,
+{
+    let mut seen = HashMap::<K, usize>::new();
+    let mut states = vec![initial];
+    loop {
+        let index = states.len() - 1;
+        let state = &states[index];
+        if let Some(start) = seen.insert(key(state), index) {
+            return CycleHistory {
+                cycle: Cycle {
+                    start,
+                    length: index - start,
+                },
+                states,
+            };
+        }
+        let next_state = next(state);
+        states.push(next_state);
+    }
+}
+
+/// State after the given number of steps, the cycle is detected on the fly
+pub fn nth_state<S>(initial: S, next: impl FnMut(&S) -> S, step: usize) -> S
+where
+    S: Clone + Eq + Hash,
+{
+    find_cycle(initial, next).state_at(step).clone()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 3 ...
+    fn next_value(x: &u32) -> u32 {
+        if *x == 6 {
+            3
+        } else {
+            x + 1
+        }
+    }
+
+    #[test]
+    fn cycle_brent() {
+        assert_eq!(
+            brent(0, next_value),
+            Cycle {
+                start: 3,
+                length: 4
+            }
+        );
+        assert_eq!(
+            brent(4, next_value),
+            Cycle {
+                start: 0,
+                length: 4
+            }
+        );
+        assert_eq!(
+            brent(7u32, |x| *x),
+            Cycle {
+                start: 0,
+                length: 1
+            }
+        );
+    }
+
+    #[test]
+    fn cycle_index_at() {
+        let cycle = Cycle {
+            start: 3,
+            length: 4,
+        };
+        assert_eq!(cycle.index_at(2), 2);
+        assert_eq!(cycle.index_at(3), 3);
+        assert_eq!(cycle.index_at(7), 3);
+        assert_eq!(cycle.index_at(1_000_000_001), 5);
+        assert_eq!(cycle.periods_at(2), 0);
+        assert_eq!(cycle.periods_at(11), 2);
+    }
+
+    #[test]
+    fn cycle_find_by_hash() {
+        let history = find_cycle(0, next_value);
+        assert_eq!(
+            history.cycle,
+            Cycle {
+                start: 3,
+                length: 4
+            }
+        );
+        assert_eq!(history.states, vec![0, 1, 2, 3, 4, 5, 6, 3]);
+        assert_eq!(*history.state_at(1_000_000_001), 5);
+        assert_eq!(nth_state(0, next_value, 1_000_000_002), 6);
+    }
+
+    #[test]
+    fn cycle_find_by_key_and_extrapolate() {
+        // (height, phase): height grows by 5 every 3 steps after the warm-up step
+        let next = |(height, phase): &(u64, u8)| match phase {
+            0 => (height + 10, 1),
+            1 => (height + 1, 2),
+            2 => (height + 2, 3),
+            _ => (height + 2, 1),
+        };
+        let history = find_cycle_by_key((0, 0), next, |(_, phase)| *phase);
+        assert_eq!(
+            history.cycle,
+            Cycle {
+                start: 1,
+                length: 3
+            }
+        );
+        let value = |(height, _): &(u64, u8)| *height;
+        for step in 0..20 {
+            let mut state = (0, 0);
+            for _ in 0..step {
+                state = next(&state);
+            }
+            assert_eq!(history.value_at(step, value), state.0);
+        }
+        assert_eq!(
+            history.value_at(1_000_000_000_000, value),
+            1_666_666_666_675
+        );
+    }
+}
@@ -1,3 +1,14 @@
+use std::{ops::Neg, str::FromStr};
+
+use super::{Integer, Point2d};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DirectionError {
+    Unknown(String),
+    /// Diagonal can't be represented with 4-way direction
+    Diagonal(Compass),
+}
+
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Direction {
     Up,
@@ -12,19 +23,6 @@ impl Direction {
         [Up, Down, Left, Right]
     }
 
-    pub fn circular_directions() -> Vec<Vec<Direction>> {
-        vec![
-            vec![Self::Up],
-            vec![Self::Up, Self::Right],
-            vec![Self::Right],
-            vec![Self::Right, Self::Down],
-            vec![Self::Down],
-            vec![Self::Down, Self::Left],
-            vec![Self::Left],
-            vec![Self::Left, Self::Up],
-        ]
-    }
-
     pub fn is_vertical(&self) -> bool {
         matches!(self, Self::Down | Self::Up)
     }
@@ -59,4 +57,278 @@ impl Direction {
     pub fn is_reversed(&self, other: &Self) -> bool {
         self.reverse() == *other
     }
+
+    /// Rotates by the multiple of 90 degrees, the positive angle is clockwise
+    pub fn rotated(&self, degrees: isize) -> Self {
+        assert!(degrees % 90 == 0, "Angle must be multiple of 90 degrees");
+        Compass::from(*self)
+            .rotated(degrees)
+            .try_into()
+            .expect("Cardinal direction is expected")
+    }
+
+    pub fn turned(&self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+}
+
+/// Accepts the same spellings as `Compass` excluding the diagonals
+impl FromStr for Direction {
+    type Err = DirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<Compass>()?.try_into()
+    }
+}
+
+impl TryFrom<Compass> for Direction {
+    type Error = DirectionError;
+
+    fn try_from(value: Compass) -> Result<Self, Self::Error> {
+        match value {
+            Compass::North => Ok(Self::Up),
+            Compass::East => Ok(Self::Right),
+            Compass::South => Ok(Self::Down),
+            Compass::West => Ok(Self::Left),
+            _ => Err(DirectionError::Diagonal(value)),
+        }
+    }
+}
+
+/// Orientation of the `y` axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YAxis {
+    /// Screen and grid rows, north is `y - 1`
+    Down,
+    /// Math plots, north is `y + 1`
+    Up,
+}
+
+/// 8-way direction, north corresponds to `Direction::Up`
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Compass {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Compass {
+    /// All directions in the clockwise order starting from north
+    pub fn all() -> [Self; 8] {
+        use Compass::*;
+        [
+            North, NorthEast, East, SouthEast, South, SouthWest, West, NorthWest,
+        ]
+    }
+
+    pub fn cardinals() -> [Self; 4] {
+        use Compass::*;
+        [North, East, South, West]
+    }
+
+    pub fn diagonals() -> [Self; 4] {
+        use Compass::*;
+        [NorthEast, SouthEast, SouthWest, NorthWest]
+    }
+
+    /// Number of 45 degree clockwise steps from north
+    fn index(&self) -> isize {
+        *self as isize
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// Rotates by the multiple of 45 degrees, the positive angle is clockwise
+    pub fn rotated(&self, degrees: isize) -> Self {
+        assert!(degrees % 45 == 0, "Angle must be multiple of 45 degrees");
+        let index = (self.index() + degrees / 45).rem_euclid(8);
+        Self::all()[index as usize]
+    }
+
+    pub fn turned(&self, turn: Turn) -> Self {
+        self.rotated(turn.angle(90))
+    }
+
+    pub fn reverse(&self) -> Self {
+        self.rotated(180)
+    }
+
+    /// Unit offset, the diagonal one changes both coordinates
+    pub fn delta<T: Integer + Neg<Output = T>>(&self, axis: YAxis) -> Point2d<T> {
+        let (x, north) = match self {
+            Self::North => (0, 1),
+            Self::NorthEast => (1, 1),
+            Self::East => (1, 0),
+            Self::SouthEast => (1, -1),
+            Self::South => (0, -1),
+            Self::SouthWest => (-1, -1),
+            Self::West => (-1, 0),
+            Self::NorthWest => (-1, 1),
+        };
+        let y = match axis {
+            YAxis::Down => -north,
+            YAxis::Up => north,
+        };
+        let unit = |value: i8| match value {
+            1 => T::ONE,
+            -1 => -T::ONE,
+            _ => T::ZERO,
+        };
+        Point2d::new(unit(x), unit(y))
+    }
+}
+
+impl From<Direction> for Compass {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::Up => Self::North,
+            Direction::Right => Self::East,
+            Direction::Down => Self::South,
+            Direction::Left => Self::West,
+        }
+    }
+}
+
+/// Parses arrows `^v<>`, letters `UDLR` and compass points `N`, `NE`, `E`, etc.
+/// The letters are case-insensitive
+impl FromStr for Compass {
+    type Err = DirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Compass::*;
+        match s.to_ascii_uppercase().as_str() {
+            "^" | "U" | "N" => Ok(North),
+            "V" | "D" | "S" => Ok(South),
+            "<" | "L" | "W" => Ok(West),
+            ">" | "R" | "E" => Ok(East),
+            "NE" => Ok(NorthEast),
+            "SE" => Ok(SouthEast),
+            "SW" => Ok(SouthWest),
+            "NW" => Ok(NorthWest),
+            _ => Err(DirectionError::Unknown(s.to_string())),
+        }
+    }
+}
+
+/// Relative turn, parsed from `L` and `R`
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl Turn {
+    /// Signed angle where clockwise is positive
+    pub fn angle(&self, degrees: isize) -> isize {
+        match self {
+            Self::Left => -degrees,
+            Self::Right => degrees,
+        }
+    }
+}
+
+impl FromStr for Turn {
+    type Err = DirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "L" | "l" => Ok(Self::Left),
+            "R" | "r" => Ok(Self::Right),
+            _ => Err(DirectionError::Unknown(s.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn directions_parse() {
+        for (s, expected) in [
+            ("^", Compass::North),
+            ("v", Compass::South),
+            ("L", Compass::West),
+            ("e", Compass::East),
+            ("NW", Compass::NorthWest),
+        ] {
+            assert_eq!(s.parse::<Compass>(), Ok(expected));
+        }
+        assert_eq!("D".parse::<Direction>(), Ok(Direction::Down));
+        assert_eq!(">".parse::<Direction>(), Ok(Direction::Right));
+        assert_eq!(
+            "se".parse::<Direction>(),
+            Err(DirectionError::Diagonal(Compass::SouthEast))
+        );
+        assert!("X".parse::<Compass>().is_err());
+        assert_eq!("R".parse::<Turn>(), Ok(Turn::Right));
+        assert!("F".parse::<Turn>().is_err());
+    }
+
+    #[test]
+    fn directions_rotation() {
+        assert_eq!(Compass::North.rotated(45), Compass::NorthEast);
+        assert_eq!(Compass::North.rotated(-90), Compass::West);
+        assert_eq!(Compass::SouthWest.rotated(270), Compass::SouthEast);
+        assert_eq!(Compass::East.rotated(720), Compass::East);
+        assert_eq!(Compass::NorthEast.reverse(), Compass::SouthWest);
+        assert_eq!(Compass::West.turned(Turn::Right), Compass::North);
+        assert_eq!(Direction::Up.rotated(-270), Direction::Right);
+        assert_eq!(Direction::Left.turned(Turn::Left), Direction::Down);
+        assert!(Compass::all()
+            .iter()
+            .filter(|x| x.is_diagonal())
+            .eq(Compass::diagonals().iter()));
+        for dir in Direction::all() {
+            assert_eq!(Direction::try_from(Compass::from(dir)), Ok(dir));
+        }
+    }
+
+    #[test]
+    fn directions_delta() {
+        assert_eq!(
+            Compass::North.delta::<i32>(YAxis::Down),
+            Point2d::new(0, -1)
+        );
+        assert_eq!(Compass::North.delta::<i32>(YAxis::Up), Point2d::new(0, 1));
+        assert_eq!(
+            Compass::SouthWest.delta::<i64>(YAxis::Down),
+            Point2d::new(-1, 1)
+        );
+        for dir in Direction::all() {
+            let p = Point2d::new(5, 5);
+            let compass = Compass::from(dir);
+            assert_eq!(p.moved_by(&dir), p.add(&compass.delta(YAxis::Down)));
+        }
+    }
+
+    #[test]
+    fn directions_unsigned_compass_moves() {
+        // `delta` needs the signed type, the unsigned points step by `safe_moved_by_compass`
+        let p = Point2d::<usize>::new(1, 1);
+        for compass in Compass::all() {
+            let delta = compass.delta::<i64>(YAxis::Down);
+            let expected = Point2d::new((1 + delta.x) as usize, (1 + delta.y) as usize);
+            assert_eq!(p.safe_moved_by_compass(&compass), Some(expected));
+        }
+        let origin = Point2d::<usize>::zero();
+        let moved = Compass::all()
+            .iter()
+            .filter_map(|compass| origin.safe_moved_by_compass(compass))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            moved,
+            vec![Point2d::new(1, 0), Point2d::new(1, 1), Point2d::new(0, 1)]
+        );
+    }
 }
@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+
+use super::String2IdMapper;
+
+/// Disjoint-set union with path compression and union by size
+#[derive(Debug, Clone, Default)]
+pub struct Dsu {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl Dsu {
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            components: len,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Adds the new single-element component, returns its id
+    pub fn add(&mut self) -> usize {
+        let id = self.parent.len();
+        self.parent.push(id);
+        self.size.push(1);
+        self.components += 1;
+        id
+    }
+
+    /// Representative element of the component
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut x = x;
+        while self.parent[x] != root {
+            let next = self.parent[x];
+            self.parent[x] = root;
+            x = next;
+        }
+        root
+    }
+
+    /// Merges components, returns `false` if the elements are already connected
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.components -= 1;
+        true
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    pub fn component_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Elements grouped by components, the groups are ordered by their smallest element
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut index_map = HashMap::new();
+        let mut result: Vec<Vec<usize>> = Vec::new();
+        for x in 0..self.len() {
+            let root = self.find(x);
+            let index = *index_map.entry(root).or_insert_with(|| {
+                result.push(Vec::new());
+                result.len() - 1
+            });
+            result[index].push(x);
+        }
+        result
+    }
+}
+
+/// Disjoint-set union over the string keys,
+/// the keys are registered on the first union
+#[derive(Default)]
+pub struct KeyedDsu {
+    names: String2IdMapper,
+    dsu: Dsu,
+}
+
+impl KeyedDsu {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn id(&mut self, key: &str) -> usize {
+        let id = self.names.get_id(key);
+        if id == self.dsu.len() {
+            self.dsu.add();
+        }
+        id
+    }
+
+    pub fn union(&mut self, a: &str, b: &str) -> bool {
+        let (a, b) = (self.id(a), self.id(b));
+        self.dsu.union(a, b)
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.dsu.component_count()
+    }
+
+    /// Unknown key forms the single-element component
+    pub fn component_size(&mut self, key: &str) -> usize {
+        self.names
+            .get(key)
+            .map_or(1, |id| self.dsu.component_size(id))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dsu_union_find() {
+        let mut dsu = Dsu::new(6);
+        assert_eq!(dsu.component_count(), 6);
+        assert!(dsu.union(0, 1));
+        assert!(dsu.union(2, 3));
+        assert!(dsu.union(1, 3));
+        assert!(!dsu.union(0, 2));
+        assert_eq!(dsu.component_count(), 3);
+        assert_eq!(dsu.find(0), dsu.find(3));
+        assert_ne!(dsu.find(0), dsu.find(4));
+        assert_eq!(dsu.component_size(2), 4);
+        assert_eq!(dsu.component_size(5), 1);
+        assert_eq!(dsu.components(), vec![vec![0, 1, 2, 3], vec![4], vec![5]]);
+        let id = dsu.add();
+        assert_eq!(id, 6);
+        assert_eq!(dsu.component_count(), 4);
+    }
+
+    #[test]
+    fn dsu_keyed() {
+        // 2017 day 12 example
+        let pipes = [
+            ("0", "2"),
+            ("1", "1"),
+            ("2", "0"),
+            ("2", "3"),
+            ("2", "4"),
+            ("3", "4"),
+            ("4", "6"),
+            ("5", "6"),
+        ];
+        let mut dsu = KeyedDsu::new();
+        for (a, b) in pipes {
+            dsu.union(a, b);
+        }
+        assert_eq!(dsu.component_size("0"), 6);
+        assert_eq!(dsu.component_size("1"), 1);
+        assert_eq!(dsu.component_count(), 2);
+        // unknown keys are not registered by the queries
+        assert_eq!(dsu.component_size("7"), 1);
+        assert_eq!(dsu.component_count(), 2);
+    }
+}
@@ -0,0 +1,248 @@
+use std::ops::Neg;
+
+use super::{gcd, Integer, Point2d};
+
+/// Position of the point relative to the polygon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointLocation {
+    Inside,
+    Outside,
+    Boundary,
+}
+
+impl PointLocation {
+    /// Inside or on the boundary
+    pub fn is_covered(&self) -> bool {
+        *self != Self::Outside
+    }
+}
+
+fn edges<T: Copy>(polygon: &[Point2d<T>]) -> impl Iterator<Item = (Point2d<T>, Point2d<T>)> + '_ {
+    let len = polygon.len();
+    (0..len).map(move |i| (polygon[i], polygon[(i + 1) % len]))
+}
+
+fn cross<T: Integer>(a: Point2d<T>, b: Point2d<T>, p: Point2d<T>) -> T {
+    (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
+}
+
+fn scaled<T: Integer>(p: Point2d<T>, scale: T) -> Point2d<T> {
+    Point2d::new(p.x * scale, p.y * scale)
+}
+
+/// Doubled signed area by the shoelace formula,
+/// positive for counterclockwise order in the coordinate system with `y` axis pointed up
+pub fn double_signed_area<T: Integer>(polygon: &[Point2d<T>]) -> T {
+    edges(polygon).fold(T::ZERO, |acc, (a, b)| acc + a.x * b.y - b.x * a.y)
+}
+
+/// Polygon area rounded down
+pub fn polygon_area<T: Integer>(polygon: &[Point2d<T>]) -> T {
+    double_signed_area(polygon).abs_value() / (T::ONE + T::ONE)
+}
+
+/// Number of lattice points on the polygon edges
+pub fn boundary_points<T: Integer>(polygon: &[Point2d<T>]) -> T {
+    edges(polygon).fold(T::ZERO, |acc, (a, b)| acc + gcd(b.x - a.x, b.y - a.y))
+}
+
+/// Number of lattice points strictly inside the polygon by Pick's theorem
+pub fn interior_points<T: Integer>(polygon: &[Point2d<T>]) -> T {
+    let two = T::ONE + T::ONE;
+    (double_signed_area(polygon).abs_value() - boundary_points(polygon) + two) / two
+}
+
+/// Number of lattice points inside the polygon including its boundary
+pub fn covered_points<T: Integer>(polygon: &[Point2d<T>]) -> T {
+    interior_points(polygon) + boundary_points(polygon)
+}
+
+/// Point location by the ray casting
+pub fn locate_point<T: Integer>(polygon: &[Point2d<T>], p: Point2d<T>) -> PointLocation {
+    locate_scaled(polygon, T::ONE, p)
+}
+
+/// The same as `locate_point` for the polygon which coordinates are multiplied by `scale`,
+/// allows checking points with fractional coordinates
+fn locate_scaled<T: Integer>(polygon: &[Point2d<T>], scale: T, p: Point2d<T>) -> PointLocation {
+    let mut is_inside = false;
+    for (a, b) in edges(polygon) {
+        let (a, b) = (scaled(a, scale), scaled(b, scale));
+        let cross = cross(a, b, p);
+        if cross == T::ZERO
+            && a.x.min(b.x) <= p.x
+            && p.x <= a.x.max(b.x)
+            && a.y.min(b.y) <= p.y
+            && p.y <= a.y.max(b.y)
+        {
+            return PointLocation::Boundary;
+        }
+        if (a.y > p.y) != (b.y > p.y) {
+            let is_left = if b.y > a.y {
+                cross > T::ZERO
+            } else {
+                cross < T::ZERO
+            };
+            if is_left {
+                is_inside = !is_inside;
+            }
+        }
+    }
+    if is_inside {
+        PointLocation::Inside
+    } else {
+        PointLocation::Outside
+    }
+}
+
+/// How many times the polygon winds around the point counterclockwise,
+/// zero means the point is outside
+pub fn winding_number<T: Integer + Neg<Output = T>>(
+    polygon: &[Point2d<T>],
+    p: Point2d<T>,
+) -> isize {
+    edges(polygon).fold(0, |acc, (a, b)| {
+        let cross = cross(a, b, p);
+        if a.y <= p.y {
+            if b.y > p.y && cross > T::ZERO {
+                return acc + 1;
+            }
+        } else if b.y <= p.y && cross < T::ZERO {
+            return acc - 1;
+        }
+        acc
+    })
+}
+
+/// Checks if the axis-aligned rectangle with the opposite corners `a` and `b`
+/// lies inside the rectilinear polygon, the boundary is considered as inside
+pub fn rectangle_inside<T: Integer>(polygon: &[Point2d<T>], a: Point2d<T>, b: Point2d<T>) -> bool {
+    let (x1, x2) = (a.x.min(b.x), a.x.max(b.x));
+    let (y1, y2) = (a.y.min(b.y), a.y.max(b.y));
+    let two = T::ONE + T::ONE;
+
+    if x1 < x2 && y1 < y2 {
+        // the interior is a single region if no edge passes through it
+        let is_crossed = edges(polygon).any(|(p, q)| {
+            if p.x == q.x {
+                x1 < p.x && p.x < x2 && p.y.min(q.y).max(y1) < p.y.max(q.y).min(y2)
+            } else {
+                y1 < p.y && p.y < y2 && p.x.min(q.x).max(x1) < p.x.max(q.x).min(x2)
+            }
+        });
+        let center = Point2d::new(x1 + x2, y1 + y2);
+        return !is_crossed && locate_scaled(polygon, two, center).is_covered();
+    }
+
+    // degenerate rectangle: the segment location changes only at the vertex coordinates
+    let is_vertical = x1 == x2;
+    let (from, to) = if is_vertical { (y1, y2) } else { (x1, x2) };
+    let mut events = polygon
+        .iter()
+        .map(|p| if is_vertical { p.y } else { p.x })
+        .filter(|v| from < *v && *v < to)
+        .chain([from, to])
+        .collect::<Vec<_>>();
+    events.sort();
+    events.dedup();
+    let point = |v: T| {
+        if is_vertical {
+            Point2d::new(x1 * two, v)
+        } else {
+            Point2d::new(v, y1 * two)
+        }
+    };
+    let is_events_covered = events
+        .iter()
+        .all(|v| locate_scaled(polygon, two, point(*v * two)).is_covered());
+    is_events_covered
+        && events
+            .windows(2)
+            .all(|w| locate_scaled(polygon, two, point(w[0] + w[1])).is_covered())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn make_polygon(points: &[(i64, i64)]) -> Vec<Point2d<i64>> {
+        points.iter().map(|(x, y)| Point2d::new(*x, *y)).collect()
+    }
+
+    #[test]
+    fn geometry_shoelace() {
+        let square = make_polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(double_signed_area(&square), 32);
+        assert_eq!(polygon_area(&square), 16);
+        let clockwise = square.iter().rev().copied().collect::<Vec<_>>();
+        assert_eq!(double_signed_area(&clockwise), -32);
+
+        let polygon = make_polygon(&[(3, 4), (5, 11), (12, 8), (9, 5), (5, 6)]);
+        assert_eq!(polygon_area(&polygon), 30);
+        let polygon = make_polygon(&[(13, -92), (44, 0), (-800, 30), (27, 2), (1, 2)]);
+        assert_eq!(polygon_area(&polygon), 1446);
+    }
+
+    #[test]
+    fn geometry_pick() {
+        let square = make_polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(boundary_points(&square), 16);
+        assert_eq!(interior_points(&square), 9);
+        assert_eq!(covered_points(&square), 25);
+
+        let triangle = make_polygon(&[(0, 0), (4, 0), (0, 4)]);
+        assert_eq!(boundary_points(&triangle), 12);
+        assert_eq!(interior_points(&triangle), 3);
+    }
+
+    #[test]
+    fn geometry_locate_point() {
+        let polygon = make_polygon(&[(0, 0), (6, 0), (6, 6), (3, 3), (0, 6)]);
+        let locate = |x, y| locate_point(&polygon, Point2d::new(x, y));
+        assert_eq!(locate(1, 1), PointLocation::Inside);
+        assert_eq!(locate(3, 0), PointLocation::Boundary);
+        assert_eq!(locate(2, 2), PointLocation::Inside);
+        assert_eq!(locate(4, 4), PointLocation::Boundary);
+        assert_eq!(locate(3, 5), PointLocation::Outside);
+        assert_eq!(locate(-1, 3), PointLocation::Outside);
+        assert_eq!(locate(0, 3), PointLocation::Boundary);
+        assert_eq!(locate(3, 3), PointLocation::Boundary);
+    }
+
+    #[test]
+    fn geometry_winding_number() {
+        let square = make_polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(winding_number(&square, Point2d::new(2, 2)), 1);
+        assert_eq!(winding_number(&square, Point2d::new(5, 2)), 0);
+        let clockwise = square.iter().rev().copied().collect::<Vec<_>>();
+        assert_eq!(winding_number(&clockwise, Point2d::new(2, 2)), -1);
+    }
+
+    #[test]
+    fn geometry_rectangle_inside() {
+        // the example of 2025 day 9
+        let polygon = make_polygon(&[
+            (7, 1),
+            (11, 1),
+            (11, 7),
+            (9, 7),
+            (9, 5),
+            (2, 5),
+            (2, 3),
+            (7, 3),
+        ]);
+        let inside = |a: (i64, i64), b: (i64, i64)| {
+            rectangle_inside(&polygon, Point2d::new(a.0, a.1), Point2d::new(b.0, b.1))
+        };
+        assert!(inside((9, 5), (2, 3)));
+        assert!(inside((7, 3), (11, 1)));
+        assert!(inside((9, 7), (9, 5)));
+        assert!(!inside((2, 5), (11, 1)));
+        assert!(inside((2, 3), (11, 3)));
+        assert!(inside((2, 5), (11, 5)));
+        assert!(!inside((2, 3), (2, 7)));
+        assert!(!inside((2, 3), (11, 7)));
+        assert!(inside((2, 5), (9, 5)));
+        assert!(!inside((7, 1), (2, 5)));
+    }
+}
@@ -0,0 +1,445 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+use super::{bitset::DynBitSet, search::Cost, string2id::InternId};
+
+/// Adjacency lists indexed by the vertex ids, e.g. the ones
+/// given by `String2IdMapper`. Vertices are added on demand by the edges
+#[derive(Debug, Clone)]
+pub struct Graph<I = usize, W = usize> {
+    adjacency: Vec<Vec<(I, W)>>,
+}
+
+impl<I, W> Default for Graph<I, W> {
+    fn default() -> Self {
+        Self {
+            adjacency: Vec::new(),
+        }
+    }
+}
+
+impl<I: InternId, W: Cost> Graph<I, W> {
+    /// Graph with isolated vertices `0..size`
+    pub fn with_vertices(size: usize) -> Self {
+        Self {
+            adjacency: (0..size).map(|_| Vec::new()).collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.adjacency.is_empty()
+    }
+
+    pub fn vertices(&self) -> impl Iterator<Item = I> {
+        (0..self.adjacency.len()).map(I::from_index)
+    }
+
+    fn reserve(&mut self, index: usize) {
+        if index >= self.adjacency.len() {
+            self.adjacency.resize_with(index + 1, Vec::new);
+        }
+    }
+
+    /// Directed edge
+    pub fn add_arc(&mut self, from: I, to: I, weight: W) {
+        self.reserve(from.index().max(to.index()));
+        self.adjacency[from.index()].push((to, weight));
+    }
+
+    /// Undirected edge stored as two arcs
+    pub fn add_edge(&mut self, a: I, b: I, weight: W) {
+        self.add_arc(a, b, weight);
+        self.add_arc(b, a, weight);
+    }
+
+    /// Targets of the outgoing arcs with their weights
+    pub fn arcs(&self, vertex: I) -> &[(I, W)] {
+        &self.adjacency[vertex.index()]
+    }
+
+    pub fn neighbours(&self, vertex: I) -> impl Iterator<Item = I> + '_ {
+        self.arcs(vertex).iter().map(|(to, _)| *to)
+    }
+
+    pub fn in_degrees(&self) -> Vec<usize> {
+        let mut degrees = vec![0; self.len()];
+        self.adjacency
+            .iter()
+            .flatten()
+            .for_each(|(to, _)| degrees[to.index()] += 1);
+        degrees
+    }
+
+    /// Vertices without incoming arcs, e.g. the root of a tree
+    pub fn sources(&self) -> Vec<I> {
+        self.in_degrees()
+            .into_iter()
+            .enumerate()
+            .filter(|(_, degree)| *degree == 0)
+            .map(|(i, _)| I::from_index(i))
+            .collect()
+    }
+
+    /// Kahn's algorithm, the smallest available id goes first.
+    /// Returns `None` if the graph has a cycle
+    pub fn topological_sort(&self) -> Option<Vec<I>> {
+        let mut degrees = self.in_degrees();
+        let mut ready = self
+            .sources()
+            .into_iter()
+            .map(|x| Reverse(x.index()))
+            .collect::<BinaryHeap<_>>();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(Reverse(vertex)) = ready.pop() {
+            order.push(I::from_index(vertex));
+            for (to, _) in &self.adjacency[vertex] {
+                degrees[to.index()] -= 1;
+                if degrees[to.index()] == 0 {
+                    ready.push(Reverse(to.index()));
+                }
+            }
+        }
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// Tarjan's algorithm without recursion, the components
+    /// come in the reverse topological order
+    pub fn strongly_connected_components(&self) -> Vec<Vec<I>> {
+        let size = self.len();
+        let mut order = vec![usize::MAX; size];
+        let mut low = vec![0; size];
+        let mut on_stack = vec![false; size];
+        let mut stack = Vec::new();
+        let mut counter = 0;
+        let mut components = Vec::new();
+        for root in 0..size {
+            if order[root] != usize::MAX {
+                continue;
+            }
+            // vertex and the position of the next arc to visit
+            let mut calls = vec![(root, 0)];
+            order[root] = counter;
+            low[root] = counter;
+            counter += 1;
+            stack.push(root);
+            on_stack[root] = true;
+            while let Some((vertex, next)) = calls.last_mut() {
+                let vertex = *vertex;
+                if let Some((to, _)) = self.adjacency[vertex].get(*next) {
+                    *next += 1;
+                    let to = to.index();
+                    if order[to] == usize::MAX {
+                        order[to] = counter;
+                        low[to] = counter;
+                        counter += 1;
+                        stack.push(to);
+                        on_stack[to] = true;
+                        calls.push((to, 0));
+                    } else if on_stack[to] {
+                        low[vertex] = low[vertex].min(order[to]);
+                    }
+                    continue;
+                }
+                calls.pop();
+                if let Some((parent, _)) = calls.last() {
+                    low[*parent] = low[*parent].min(low[vertex]);
+                }
+                if low[vertex] == order[vertex] {
+                    let mut component = Vec::new();
+                    while let Some(x) = stack.pop() {
+                        on_stack[x] = false;
+                        component.push(I::from_index(x));
+                        if x == vertex {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+        components
+    }
+
+    /// Bron–Kerbosch with pivoting, the graph is treated as undirected
+    pub fn maximum_clique(&self) -> Vec<I> {
+        let mut neighbours = vec![DynBitSet::new(); self.len()];
+        for (from, arcs) in self.adjacency.iter().enumerate() {
+            for to in arcs
+                .iter()
+                .map(|(to, _)| to.index())
+                .filter(|to| *to != from)
+            {
+                neighbours[from].insert(to);
+                neighbours[to].insert(from);
+            }
+        }
+        let mut best = Vec::new();
+        bron_kerbosch(
+            &neighbours,
+            &mut Vec::new(),
+            (0..self.len()).collect(),
+            DynBitSet::new(),
+            &mut best,
+        );
+        best.sort_unstable();
+        best.into_iter().map(I::from_index).collect()
+    }
+
+    /// Stoer–Wagner global minimum cut of the undirected graph,
+    /// returns its weight and the vertices of one side.
+    /// The disconnected graph gives the zero cut around the component of the first vertex.
+    /// `None` for the graph with less than two vertices
+    pub fn min_cut(&self) -> Option<(W, Vec<I>)> {
+        if self.len() < 2 {
+            return None;
+        }
+        let mut weights = vec![HashMap::<usize, W>::new(); self.len()];
+        for (from, arcs) in self.adjacency.iter().enumerate() {
+            for (to, weight) in arcs.iter().filter(|(to, _)| to.index() != from) {
+                let entry = weights[from].entry(to.index()).or_default();
+                *entry = *entry + *weight;
+            }
+        }
+        let component = connected_component(&weights, 0);
+        if component.len() < self.len() {
+            let side = component.into_iter().map(I::from_index).collect();
+            return Some((W::default(), side));
+        }
+        let mut groups = (0..self.len()).map(|x| vec![x]).collect::<Vec<_>>();
+        let mut is_active = vec![true; self.len()];
+        let mut best: Option<(W, Vec<usize>)> = None;
+        for phase in 0..self.len() - 1 {
+            let (s, t, cut) = minimum_cut_phase(&weights, &is_active, self.len() - phase);
+            if best.as_ref().is_none_or(|(weight, _)| cut < *weight) {
+                best = Some((cut, groups[t].clone()));
+            }
+            // merges t into s
+            let merged = std::mem::take(&mut groups[t]);
+            groups[s].extend(merged);
+            is_active[t] = false;
+            for (other, weight) in std::mem::take(&mut weights[t]) {
+                weights[other].remove(&t);
+                if other == s {
+                    continue;
+                }
+                let entry = weights[s].entry(other).or_default();
+                *entry = *entry + weight;
+                let entry = weights[other].entry(s).or_default();
+                *entry = *entry + weight;
+            }
+        }
+        best.map(|(weight, side)| (weight, side.into_iter().map(I::from_index).collect()))
+    }
+
+    /// The heaviest path of the directed acyclic graph with its vertices,
+    /// `None` if the graph is empty or has a cycle
+    pub fn longest_path(&self) -> Option<(W, Vec<I>)> {
+        let order = self.topological_sort()?;
+        let mut distance = vec![W::default(); self.len()];
+        let mut previous = vec![None; self.len()];
+        for vertex in order {
+            for (to, weight) in self.arcs(vertex) {
+                let candidate = distance[vertex.index()] + *weight;
+                if previous[to.index()].is_none() || candidate > distance[to.index()] {
+                    distance[to.index()] = candidate;
+                    previous[to.index()] = Some(vertex);
+                }
+            }
+        }
+        let last = (0..self.len()).max_by_key(|x| distance[*x])?;
+        let mut path = vec![I::from_index(last)];
+        while let Some(vertex) = previous[path[path.len() - 1].index()] {
+            path.push(vertex);
+        }
+        path.reverse();
+        Some((distance[last], path))
+    }
+}
+
+fn bron_kerbosch(
+    neighbours: &[DynBitSet],
+    clique: &mut Vec<usize>,
+    candidates: DynBitSet,
+    excluded: DynBitSet,
+    best: &mut Vec<usize>,
+) {
+    if candidates.is_empty() {
+        if excluded.is_empty() && clique.len() > best.len() {
+            *best = clique.clone();
+        }
+        return;
+    }
+    if clique.len() + candidates.len() <= best.len() {
+        return;
+    }
+    // the pivot with the most candidates around leaves the fewest branches
+    let pivot = candidates
+        .iter()
+        .chain(excluded.iter())
+        .max_by_key(|x| neighbours[*x].intersection(&candidates).len())
+        .expect("Candidates are not empty");
+    let (mut candidates, mut excluded) = (candidates, excluded);
+    for vertex in candidates.difference(&neighbours[pivot]).iter() {
+        clique.push(vertex);
+        bron_kerbosch(
+            neighbours,
+            clique,
+            candidates.intersection(&neighbours[vertex]),
+            excluded.intersection(&neighbours[vertex]),
+            best,
+        );
+        clique.pop();
+        candidates.remove(vertex);
+        excluded.insert(vertex);
+    }
+}
+
+/// Vertices reachable from the start, in the ascending order
+fn connected_component<W>(weights: &[HashMap<usize, W>], start: usize) -> Vec<usize> {
+    let mut is_visited = vec![false; weights.len()];
+    is_visited[start] = true;
+    let mut stack = vec![start];
+    let mut component = Vec::new();
+    while let Some(vertex) = stack.pop() {
+        component.push(vertex);
+        for other in weights[vertex].keys() {
+            if !is_visited[*other] {
+                is_visited[*other] = true;
+                stack.push(*other);
+            }
+        }
+    }
+    component.sort_unstable();
+    component
+}
+
+/// Maximum adjacency ordering over the active vertices,
+/// returns the last two vertices and the cut of the last one
+fn minimum_cut_phase<W: Cost>(
+    weights: &[HashMap<usize, W>],
+    is_active: &[bool],
+    active_count: usize,
+) -> (usize, usize, W) {
+    let start = is_active.iter().position(|x| *x).expect("Active vertex");
+    let mut connectivity = vec![W::default(); weights.len()];
+    let mut is_added = vec![false; weights.len()];
+    let mut heap = BinaryHeap::from([(W::default(), start)]);
+    let (mut previous, mut last) = (start, start);
+    let mut added = 0;
+    while let Some((weight, vertex)) = heap.pop() {
+        if is_added[vertex] || weight != connectivity[vertex] {
+            continue;
+        }
+        is_added[vertex] = true;
+        added += 1;
+        (previous, last) = (last, vertex);
+        if added == active_count {
+            break;
+        }
+        for (other, weight) in &weights[vertex] {
+            if !is_added[*other] {
+                connectivity[*other] = connectivity[*other] + *weight;
+                heap.push((connectivity[*other], *other));
+            }
+        }
+    }
+    assert_eq!(added, active_count, "Graph must be connected");
+    (previous, last, connectivity[last])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn directed(edges: &[(usize, usize)]) -> Graph {
+        let mut graph = Graph::default();
+        edges.iter().for_each(|(a, b)| graph.add_arc(*a, *b, 1));
+        graph
+    }
+
+    #[test]
+    fn graph_topological_sort() {
+        // C -> A, C -> F, A -> B, A -> D, B -> E, D -> E, F -> E
+        let graph = directed(&[(2, 0), (2, 5), (0, 1), (0, 3), (1, 4), (3, 4), (5, 4)]);
+        assert_eq!(graph.topological_sort(), Some(vec![2, 0, 1, 3, 5, 4]));
+        assert_eq!(graph.sources(), vec![2]);
+        assert_eq!(
+            graph.vertices().collect::<Vec<_>>(),
+            (0..6).collect::<Vec<_>>()
+        );
+        assert!(!graph.is_empty() && Graph::<usize, usize>::with_vertices(0).is_empty());
+        assert_eq!(graph.longest_path(), Some((3, vec![2, 0, 1, 4])));
+        let cyclic = directed(&[(0, 1), (1, 0)]);
+        assert_eq!(cyclic.topological_sort(), None);
+        assert_eq!(cyclic.longest_path(), None);
+    }
+
+    #[test]
+    fn graph_scc() {
+        let graph = directed(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3), (5, 4)]);
+        let mut components = graph
+            .strongly_connected_components()
+            .into_iter()
+            .map(|mut x| {
+                x.sort();
+                x
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(components[0], vec![3, 4]);
+        components.sort();
+        assert_eq!(components, vec![vec![0, 1, 2], vec![3, 4], vec![5]]);
+    }
+
+    #[test]
+    fn graph_maximum_clique() {
+        let mut graph = Graph::<usize, usize>::default();
+        let edges = [
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (1, 4),
+            (2, 4),
+            (3, 4),
+            (4, 5),
+        ];
+        edges.iter().for_each(|(a, b)| graph.add_edge(*a, *b, 1));
+        assert_eq!(graph.maximum_clique(), vec![1, 2, 3, 4]);
+        assert_eq!(
+            Graph::<usize, usize>::with_vertices(1).maximum_clique(),
+            vec![0]
+        );
+        // the single arcs are enough to connect the vertices
+        let graph = directed(&[(0, 1), (2, 0), (1, 2), (2, 3)]);
+        assert_eq!(graph.maximum_clique(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn graph_min_cut() {
+        // two squares with diagonals joined by two light edges
+        let mut graph = Graph::<usize, usize>::default();
+        for base in [0, 4] {
+            for (a, b) in [(0, 1), (1, 2), (2, 3), (3, 0), (0, 2), (1, 3)] {
+                graph.add_edge(base + a, base + b, 3);
+            }
+        }
+        graph.add_edge(1, 4, 1);
+        graph.add_edge(2, 7, 2);
+        let (weight, mut side) = graph.min_cut().unwrap();
+        side.sort();
+        assert_eq!(weight, 3);
+        assert!(side == vec![0, 1, 2, 3] || side == vec![4, 5, 6, 7]);
+        assert_eq!(Graph::<usize, usize>::with_vertices(1).min_cut(), None);
+        let mut graph = Graph::<usize, usize>::with_vertices(5);
+        graph.add_edge(0, 3, 2);
+        graph.add_edge(1, 2, 4);
+        assert_eq!(graph.min_cut(), Some((0, vec![0, 3])));
+    }
+}
@@ -0,0 +1,400 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use super::{Compass, Direction, Point2d, Vec2};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridError {
+    /// Rows have different lengths, contains index of the first mismatched row
+    RaggedRows(usize),
+    /// Char mapping failed at the position
+    InvalidChar(char, Point2d<usize>),
+}
+
+/// Rectangular grid with row-major storage,
+/// `x` of the position is a column and `y` is a row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(rows: usize, cols: usize, value: T) -> Self {
+        Self {
+            rows,
+            cols,
+            cells: vec![value; rows * cols],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds grid from the lines mapping each char to the cell value
+    pub fn parse<S: AsRef<str>>(
+        lines: &[S],
+        map: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, GridError> {
+        let mut cells = Vec::new();
+        let mut cols = None;
+        for (y, line) in lines.iter().enumerate() {
+            let before = cells.len();
+            for (x, ch) in line.as_ref().chars().enumerate() {
+                let value = map(ch).ok_or(GridError::InvalidChar(ch, Point2d::new(x, y)))?;
+                cells.push(value);
+            }
+            let len = cells.len() - before;
+            if *cols.get_or_insert(len) != len {
+                return Err(GridError::RaggedRows(y));
+            }
+        }
+        Ok(Self {
+            rows: lines.len(),
+            cols: cols.unwrap_or_default(),
+            cells,
+        })
+    }
+
+    /// Same as `parse` but skips empty lines
+    pub fn parse_str(data: &str, map: impl Fn(char) -> Option<T>) -> Result<Self, GridError> {
+        let lines = data.lines().filter(|s| !s.is_empty()).collect::<Vec<_>>();
+        Self::parse(&lines, map)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Grid size where `x` is the number of columns and `y` is the number of rows
+    pub fn size(&self) -> Point2d<usize> {
+        Point2d::new(self.cols, self.rows)
+    }
+
+    pub fn contains(&self, p: &Point2d<usize>) -> bool {
+        p.x < self.cols && p.y < self.rows
+    }
+
+    pub fn contains_signed(&self, p: &Point2d<isize>) -> bool {
+        self.to_unsigned(p).is_some()
+    }
+
+    fn offset(&self, p: &Point2d<usize>) -> Option<usize> {
+        if self.contains(p) {
+            Some(p.y * self.cols + p.x)
+        } else {
+            None
+        }
+    }
+
+    fn to_unsigned(&self, p: &Point2d<isize>) -> Option<Point2d<usize>> {
+        let p = Point2d::new(usize::try_from(p.x).ok()?, usize::try_from(p.y).ok()?);
+        if self.contains(&p) {
+            Some(p)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, p: &Point2d<usize>) -> Option<&T> {
+        self.offset(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: &Point2d<usize>) -> Option<&mut T> {
+        self.offset(p).map(|i| &mut self.cells[i])
+    }
+
+    pub fn get_signed(&self, p: &Point2d<isize>) -> Option<&T> {
+        self.get(&self.to_unsigned(p)?)
+    }
+
+    /// Returns `false` if the position is out of bounds
+    pub fn set(&mut self, p: &Point2d<usize>, value: T) -> bool {
+        let Some(cell) = self.get_mut(p) else {
+            return false;
+        };
+        *cell = value;
+        true
+    }
+
+    /// Maps any position to the grid as if it repeats infinitely in all directions
+    pub fn wrapped(&self, p: &Point2d<isize>) -> Point2d<usize> {
+        assert!(!self.cells.is_empty(), "Can't wrap position in empty grid");
+        Point2d::new(
+            p.x.rem_euclid(self.cols as isize) as usize,
+            p.y.rem_euclid(self.rows as isize) as usize,
+        )
+    }
+
+    pub fn get_wrapped(&self, p: &Point2d<isize>) -> &T {
+        &self[self.wrapped(p)]
+    }
+
+    pub fn neighbour(&self, p: &Point2d<usize>, direction: &Direction) -> Option<Point2d<usize>> {
+        p.safe_moved_by(direction).filter(|x| self.contains(x))
+    }
+
+    /// Orthogonal neighbours within the grid
+    pub fn neighbours4<'a>(
+        &'a self,
+        p: &'a Point2d<usize>,
+    ) -> impl Iterator<Item = Point2d<usize>> + 'a {
+        self.neighbours4_with_directions(p).map(|(_, x)| x)
+    }
+
+    pub fn neighbours4_with_directions<'a>(
+        &'a self,
+        p: &'a Point2d<usize>,
+    ) -> impl Iterator<Item = (Direction, Point2d<usize>)> + 'a {
+        Direction::all()
+            .into_iter()
+            .filter_map(move |dir| self.neighbour(p, &dir).map(|x| (dir, x)))
+    }
+
+    /// Orthogonal and diagonal neighbours within the grid
+    pub fn neighbours8<'a>(
+        &'a self,
+        p: &'a Point2d<usize>,
+    ) -> impl Iterator<Item = Point2d<usize>> + 'a {
+        Compass::all()
+            .into_iter()
+            .filter_map(move |dir| p.safe_moved_by_compass(&dir))
+            .filter(move |x| self.contains(x))
+    }
+
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Point2d<usize>> {
+        self.find_all(predicate).next()
+    }
+
+    pub fn find_all<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point2d<usize>> + 'a {
+        self.iter()
+            .filter(move |(_, value)| predicate(value))
+            .map(|(p, _)| p)
+    }
+
+    /// Iterates over all cells in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = (Point2d<usize>, &T)> {
+        let cols = self.cols.max(1);
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, value)| (Point2d::new(i % cols, i / cols), value))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.cols..(y + 1) * self.cols]
+    }
+
+    pub fn rows_iter(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.rows).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.cols, "Column index out of bounds");
+        self.cells.iter().skip(x).step_by(self.cols)
+    }
+
+    pub fn columns_iter(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.cols).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Renders grid to string with the char per cell
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        self.rows_iter()
+            .map(|row| row.iter().map(&f).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars<S: AsRef<str>>(lines: &[S]) -> Result<Self, GridError> {
+        Self::parse(lines, Some)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn to_vec2(&self) -> Vec2<T> {
+        self.rows_iter().map(|row| row.to_vec()).collect()
+    }
+}
+
+impl<T> TryFrom<Vec2<T>> for Grid<T> {
+    type Error = GridError;
+
+    fn try_from(value: Vec2<T>) -> Result<Self, Self::Error> {
+        let rows = value.len();
+        let cols = value.first().map(|row| row.len()).unwrap_or_default();
+        if let Some(y) = value.iter().position(|row| row.len() != cols) {
+            return Err(GridError::RaggedRows(y));
+        }
+        Ok(Self {
+            rows,
+            cols,
+            cells: value.into_iter().flatten().collect(),
+        })
+    }
+}
+
+impl<T> From<Grid<T>> for Vec2<T> {
+    fn from(value: Grid<T>) -> Self {
+        let mut iter = value.cells.into_iter();
+        (0..value.rows)
+            .map(|_| iter.by_ref().take(value.cols).collect())
+            .collect()
+    }
+}
+
+impl<T> Index<Point2d<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: Point2d<usize>) -> &Self::Output {
+        self.get(&index).expect("Grid position out of bounds")
+    }
+}
+
+impl<T> IndexMut<Point2d<usize>> for Grid<T> {
+    fn index_mut(&mut self, index: Point2d<usize>) -> &mut Self::Output {
+        self.get_mut(&index).expect("Grid position out of bounds")
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows_iter().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for value in row {
+                write!(f, "{value}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn make_grid() -> Grid<char> {
+        Grid::parse_chars(&["#..", ".S.", "..#", "#.."]).unwrap()
+    }
+
+    #[test]
+    fn grid_parse() {
+        let grid = make_grid();
+        assert_eq!(grid.rows(), 4);
+        assert_eq!(grid.cols(), 3);
+        assert_eq!(grid.size(), Point2d::new(3, 4));
+        assert_eq!(grid[Point2d::new(1, 1)], 'S');
+        assert_eq!(grid.get(&Point2d::new(3, 0)), None);
+        assert_eq!(grid.get_signed(&Point2d::new(-1, 0)), None);
+        assert_eq!(grid.get_signed(&Point2d::new(2, 2)), Some(&'#'));
+    }
+
+    #[test]
+    fn grid_parse_errors() {
+        let ragged = Grid::parse_chars(&["..", "..."]);
+        assert_eq!(ragged, Err(GridError::RaggedRows(1)));
+        let invalid = Grid::parse(&["01", "2x"], |ch| ch.to_digit(10));
+        assert_eq!(
+            invalid,
+            Err(GridError::InvalidChar('x', Point2d::new(1, 1)))
+        );
+        let digits = Grid::parse_str("01\n23\n\n", |ch| ch.to_digit(10)).unwrap();
+        assert_eq!(digits.row(1), &[2, 3]);
+    }
+
+    #[test]
+    fn grid_neighbours() {
+        let grid = make_grid();
+        let corner = grid.neighbours4(&Point2d::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(corner, [Point2d::new(0, 1), Point2d::new(1, 0)]);
+        assert_eq!(grid.neighbours4(&Point2d::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(&Point2d::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8(&Point2d::new(2, 3)).count(), 3);
+        let dirs = grid
+            .neighbours4_with_directions(&Point2d::new(2, 3))
+            .map(|(dir, _)| dir)
+            .collect::<Vec<_>>();
+        assert_eq!(dirs, [Direction::Up, Direction::Left]);
+    }
+
+    #[test]
+    fn grid_find() {
+        let grid = make_grid();
+        assert_eq!(grid.find(|ch| *ch == 'S'), Some(Point2d::new(1, 1)));
+        assert_eq!(grid.find(|ch| *ch == 'E'), None);
+        let walls = grid.find_all(|ch| *ch == '#').collect::<Vec<_>>();
+        assert_eq!(
+            walls,
+            [Point2d::new(0, 0), Point2d::new(2, 2), Point2d::new(0, 3)]
+        );
+    }
+
+    #[test]
+    fn grid_rows_and_columns() {
+        let grid = make_grid();
+        assert_eq!(grid.row(2), &['.', '.', '#']);
+        let column = grid.column(0).collect::<String>();
+        assert_eq!(column, "#..#");
+        let columns = grid
+            .columns_iter()
+            .map(|col| col.collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, ["#..#", ".S..", "..#."]);
+        assert_eq!(grid.rows_iter().count(), 4);
+    }
+
+    #[test]
+    fn grid_wrapped() {
+        let grid = make_grid();
+        assert_eq!(grid.wrapped(&Point2d::new(-1, -1)), Point2d::new(2, 3));
+        assert_eq!(grid.wrapped(&Point2d::new(4, 9)), Point2d::new(1, 1));
+        assert_eq!(*grid.get_wrapped(&Point2d::new(-3, 4)), '#');
+    }
+
+    #[test]
+    fn grid_display_and_render() {
+        let mut grid = make_grid();
+        assert!(grid.set(&Point2d::new(2, 0), 'E'));
+        assert!(!grid.set(&Point2d::new(3, 0), 'E'));
+        assert_eq!(grid.to_string(), "#.E\n.S.\n..#\n#..");
+        let walls = grid.map(|ch| *ch == '#');
+        assert_eq!(
+            walls.render(|x| if *x { '█' } else { ' ' }),
+            "█  \n   \n  █\n█  "
+        );
+    }
+
+    #[test]
+    fn grid_vec2_conversions() {
+        let src = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        let grid = Grid::try_from(src.clone()).unwrap();
+        assert_eq!(grid[Point2d::new(2, 1)], 6);
+        assert_eq!(grid.to_vec2(), src);
+        assert_eq!(Vec2::from(grid), src);
+        assert_eq!(
+            Grid::try_from(vec![vec![1], vec![]]),
+            Err(GridError::RaggedRows(1))
+        );
+    }
+}
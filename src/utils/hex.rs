@@ -0,0 +1,329 @@
+use std::{
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+use super::Point2d;
+
+type Int = isize;
+
+/// Hexagon in axial coordinates, the third cube coordinate is `s = -q - r`
+/// https://www.redblobgames.com/grids/hexagons/
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Hex {
+    pub q: Int,
+    pub r: Int,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HexParseError {
+    UnknownDirection(String),
+}
+
+/// Offsets to the adjacent hexagons in the counterclockwise order
+const NEIGHBOUR_OFFSETS: [Hex; 6] = [
+    Hex::new(1, 0),
+    Hex::new(1, -1),
+    Hex::new(0, -1),
+    Hex::new(-1, 0),
+    Hex::new(-1, 1),
+    Hex::new(0, 1),
+];
+
+impl Hex {
+    pub const fn new(q: Int, r: Int) -> Self {
+        Self { q, r }
+    }
+
+    pub fn from_cube(q: Int, r: Int, s: Int) -> Self {
+        assert_eq!(q + r + s, 0, "Cube coordinates must have zero sum");
+        Self { q, r }
+    }
+
+    pub fn s(&self) -> Int {
+        -self.q - self.r
+    }
+
+    pub fn moved_by(&self, direction: &impl HexDirection) -> Self {
+        *self + direction.offset()
+    }
+
+    pub fn neighbours(&self) -> [Hex; 6] {
+        NEIGHBOUR_OFFSETS.map(|offset| *self + offset)
+    }
+
+    pub fn distance(&self, other: &Self) -> usize {
+        let d = *self - *other;
+        (d.q.unsigned_abs() + d.r.unsigned_abs() + d.s().unsigned_abs()) / 2
+    }
+
+    /// Distance to the origin
+    pub fn length(&self) -> usize {
+        self.distance(&Self::default())
+    }
+
+    fn scaled(&self, factor: Int) -> Self {
+        Self::new(self.q * factor, self.r * factor)
+    }
+
+    /// Hexagons on the given distance from the center
+    pub fn ring(&self, radius: usize) -> Vec<Hex> {
+        if radius == 0 {
+            return vec![*self];
+        }
+        let mut result = Vec::with_capacity(6 * radius);
+        let mut hex = *self + NEIGHBOUR_OFFSETS[4].scaled(radius as Int);
+        for offset in NEIGHBOUR_OFFSETS {
+            for _ in 0..radius {
+                result.push(hex);
+                hex = hex + offset;
+            }
+        }
+        result
+    }
+
+    /// All hexagons within the radius ordered by the distance from the center
+    pub fn spiral(&self, radius: usize) -> Vec<Hex> {
+        (0..=radius).flat_map(|r| self.ring(r)).collect()
+    }
+
+    /// Position for rendering the flat-top layout, neighbours in a column have `y` differ by 2
+    pub fn to_flat_point(self) -> Point2d<Int> {
+        Point2d::new(self.q, 2 * self.r + self.q)
+    }
+
+    /// Position for rendering the pointy-top layout, neighbours in a row have `x` differ by 2
+    pub fn to_pointy_point(self) -> Point2d<Int> {
+        Point2d::new(2 * self.q + self.r, self.r)
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+pub trait HexDirection: Sized + Copy + FromStr<Err = HexParseError> {
+    fn offset(&self) -> Hex;
+
+    fn all() -> [Self; 6];
+
+    /// Parses directions written without separators, e.g. `nwwswee`
+    fn parse_joined(s: &str) -> Result<Vec<Self>, HexParseError> {
+        let mut result = Vec::new();
+        let mut rest = s.trim();
+        while let Some(ch) = rest.chars().next() {
+            let single = ch.len_utf8();
+            if let Some(next) = rest[single..]
+                .chars()
+                .next()
+                .filter(|_| ch == 'n' || ch == 's')
+            {
+                let double = single + next.len_utf8();
+                if let Ok(dir) = rest[..double].parse::<Self>() {
+                    result.push(dir);
+                    rest = &rest[double..];
+                    continue;
+                }
+            }
+            result.push(rest[..single].parse::<Self>()?);
+            rest = &rest[single..];
+        }
+        Ok(result)
+    }
+
+    /// Parses directions separated by the delimiter, e.g. `ne,ne,s`
+    fn parse_separated(s: &str, delimiter: char) -> Result<Vec<Self>, HexParseError> {
+        s.trim()
+            .split(delimiter)
+            .map(|token| token.trim().parse::<Self>())
+            .collect()
+    }
+}
+
+/// Directions of the flat-top hexagons: `n`, `ne`, `se`, `s`, `sw`, `nw`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlatHexDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+impl HexDirection for FlatHexDirection {
+    fn offset(&self) -> Hex {
+        match self {
+            Self::North => Hex::new(0, -1),
+            Self::NorthEast => Hex::new(1, -1),
+            Self::SouthEast => Hex::new(1, 0),
+            Self::South => Hex::new(0, 1),
+            Self::SouthWest => Hex::new(-1, 1),
+            Self::NorthWest => Hex::new(-1, 0),
+        }
+    }
+
+    fn all() -> [Self; 6] {
+        [
+            Self::North,
+            Self::NorthEast,
+            Self::SouthEast,
+            Self::South,
+            Self::SouthWest,
+            Self::NorthWest,
+        ]
+    }
+}
+
+impl FromStr for FlatHexDirection {
+    type Err = HexParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "n" => Ok(Self::North),
+            "ne" => Ok(Self::NorthEast),
+            "se" => Ok(Self::SouthEast),
+            "s" => Ok(Self::South),
+            "sw" => Ok(Self::SouthWest),
+            "nw" => Ok(Self::NorthWest),
+            _ => Err(HexParseError::UnknownDirection(s.to_string())),
+        }
+    }
+}
+
+/// Directions of the pointy-top hexagons: `e`, `ne`, `nw`, `w`, `sw`, `se`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointyHexDirection {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast,
+}
+
+impl HexDirection for PointyHexDirection {
+    fn offset(&self) -> Hex {
+        match self {
+            Self::East => Hex::new(1, 0),
+            Self::NorthEast => Hex::new(1, -1),
+            Self::NorthWest => Hex::new(0, -1),
+            Self::West => Hex::new(-1, 0),
+            Self::SouthWest => Hex::new(-1, 1),
+            Self::SouthEast => Hex::new(0, 1),
+        }
+    }
+
+    fn all() -> [Self; 6] {
+        [
+            Self::East,
+            Self::NorthEast,
+            Self::NorthWest,
+            Self::West,
+            Self::SouthWest,
+            Self::SouthEast,
+        ]
+    }
+}
+
+impl FromStr for PointyHexDirection {
+    type Err = HexParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "e" => Ok(Self::East),
+            "ne" => Ok(Self::NorthEast),
+            "nw" => Ok(Self::NorthWest),
+            "w" => Ok(Self::West),
+            "sw" => Ok(Self::SouthWest),
+            "se" => Ok(Self::SouthEast),
+            _ => Err(HexParseError::UnknownDirection(s.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn walk<D: HexDirection>(path: &[D]) -> Hex {
+        path.iter()
+            .fold(Hex::default(), |hex, dir| hex.moved_by(dir))
+    }
+
+    #[test]
+    fn hex_flat_distance() {
+        let cases = [
+            ("ne,ne,ne", 3),
+            ("ne,ne,sw,sw", 0),
+            ("ne,ne,s,s", 2),
+            ("se,sw,se,sw,sw", 3),
+        ];
+        for (input, expected) in cases {
+            let path = FlatHexDirection::parse_separated(input, ',').unwrap();
+            assert_eq!(walk(&path).length(), expected);
+        }
+        assert!(FlatHexDirection::parse_separated("n,e", ',').is_err());
+    }
+
+    #[test]
+    fn hex_pointy_parse() {
+        let path = PointyHexDirection::parse_joined("nwwswee").unwrap();
+        assert_eq!(path.len(), 5);
+        assert_eq!(walk(&path), Hex::default());
+        let path = PointyHexDirection::parse_joined("esew").unwrap();
+        assert_eq!(walk(&path), Hex::new(0, 1));
+        assert!(PointyHexDirection::parse_joined("enx").is_err());
+        assert!(PointyHexDirection::parse_joined("n").is_err());
+    }
+
+    #[test]
+    fn hex_neighbours_and_directions() {
+        let hex = Hex::new(2, -3);
+        let neighbours = hex.neighbours();
+        assert!(neighbours.iter().all(|x| x.distance(&hex) == 1));
+        for dir in FlatHexDirection::all() {
+            assert!(neighbours.contains(&hex.moved_by(&dir)));
+        }
+        for dir in PointyHexDirection::all() {
+            assert!(neighbours.contains(&hex.moved_by(&dir)));
+        }
+        assert_eq!(Hex::from_cube(1, 2, -3).s(), -3);
+    }
+
+    #[test]
+    fn hex_rings_and_spiral() {
+        let center = Hex::new(1, 1);
+        assert_eq!(center.ring(0), vec![center]);
+        for radius in 1..4 {
+            let ring = center.ring(radius);
+            assert_eq!(ring.len(), 6 * radius);
+            assert!(ring.iter().all(|x| x.distance(&center) == radius));
+        }
+        let spiral = center.spiral(2);
+        assert_eq!(spiral.len(), 19);
+        assert_eq!(spiral[0], center);
+    }
+
+    #[test]
+    fn hex_to_point() {
+        let hex = Hex::default().moved_by(&FlatHexDirection::South);
+        assert_eq!(hex.to_flat_point(), Point2d::new(0, 2));
+        let hex = Hex::default().moved_by(&PointyHexDirection::East);
+        assert_eq!(hex.to_pointy_point(), Point2d::new(2, 0));
+        let hex = Hex::default().moved_by(&PointyHexDirection::SouthWest);
+        assert_eq!(hex.to_pointy_point(), Point2d::new(-1, 1));
+    }
+}
@@ -0,0 +1,199 @@
+use super::{Integer, PlainInterval};
+
+/// Set of integers stored as sorted disjoint closed intervals,
+/// adjacent intervals are merged as well
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    intervals: Vec<PlainInterval<T>>,
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, PlainInterval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Inserts the closed interval `[begin, end]`, the empty one (`begin > end`) is ignored
+    pub fn insert(&mut self, begin: T, end: T) {
+        if begin > end {
+            return;
+        }
+        // first interval that may be merged with the new one
+        let from = self
+            .intervals
+            .partition_point(|x| x.end < begin && x.end + T::ONE < begin);
+        let mut merged = PlainInterval::new(begin, end);
+        let mut to = from;
+        while to < self.intervals.len() {
+            let x = &self.intervals[to];
+            if x.begin > end && x.begin - T::ONE > end {
+                break;
+            }
+            merged.begin = merged.begin.min(x.begin);
+            merged.end = merged.end.max(x.end);
+            to += 1;
+        }
+        self.intervals.splice(from..to, [merged]);
+    }
+
+    /// Removes the closed interval `[begin, end]` from the set
+    pub fn remove(&mut self, begin: T, end: T) {
+        if begin > end {
+            return;
+        }
+        let from = self.intervals.partition_point(|x| x.end < begin);
+        let to = self.intervals.partition_point(|x| x.begin <= end);
+        if from >= to {
+            return;
+        }
+        let mut rest = Vec::new();
+        let first = self.intervals[from];
+        if first.begin < begin {
+            rest.push(PlainInterval::new(first.begin, begin - T::ONE));
+        }
+        let last = self.intervals[to - 1];
+        if last.end > end {
+            rest.push(PlainInterval::new(end + T::ONE, last.end));
+        }
+        self.intervals.splice(from..to, rest);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.find(value).is_some()
+    }
+
+    /// Interval that contains the value
+    pub fn find(&self, value: T) -> Option<&PlainInterval<T>> {
+        let index = self.intervals.partition_point(|x| x.end < value);
+        self.intervals.get(index).filter(|x| x.begin <= value)
+    }
+
+    /// Total number of the elements in the set
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::ZERO, |acc, x| acc + x.end - x.begin + T::ONE)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        other
+            .intervals
+            .iter()
+            .for_each(|x| result.insert(x.begin, x.end));
+        result
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (&self.intervals[i], &other.intervals[j]);
+            if let Some(x) = a.intersection(b) {
+                intervals.push(x);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { intervals }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        other
+            .intervals
+            .iter()
+            .for_each(|x| result.remove(x.begin, x.end));
+        result
+    }
+
+    /// Elements of the `bounds` interval that don't belong to the set
+    pub fn complement(&self, bounds: PlainInterval<T>) -> Self {
+        let mut result = Self::new();
+        result.insert(bounds.begin, bounds.end);
+        result.difference(self)
+    }
+}
+
+impl<T: Integer> FromIterator<PlainInterval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = PlainInterval<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        iter.into_iter().for_each(|x| set.insert(x.begin, x.end));
+        set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn make_set(arr: &[(i32, i32)]) -> IntervalSet<i32> {
+        arr.iter()
+            .map(|(b, e)| PlainInterval::new(*b, *e))
+            .collect()
+    }
+
+    fn as_pairs(set: &IntervalSet<i32>) -> Vec<(i32, i32)> {
+        set.iter().map(|x| (x.begin, x.end)).collect()
+    }
+
+    #[test]
+    fn interval_set_insert_merges() {
+        let set = make_set(&[(5, 8), (0, 2), (3, 3), (10, 12), (7, 9), (20, 20)]);
+        assert_eq!(as_pairs(&set), vec![(0, 3), (5, 12), (20, 20)]);
+        assert_eq!(set.len(), 13);
+        let set = make_set(&[(1, 2), (5, 6), (9, 10), (0, 20)]);
+        assert_eq!(as_pairs(&set), vec![(0, 20)]);
+        assert!(make_set(&[(3, 1)]).is_empty());
+    }
+
+    #[test]
+    fn interval_set_remove() {
+        let mut set = make_set(&[(0, 10), (20, 30)]);
+        set.remove(5, 22);
+        assert_eq!(as_pairs(&set), vec![(0, 4), (23, 30)]);
+        set.remove(0, 0);
+        set.remove(30, 40);
+        assert_eq!(as_pairs(&set), vec![(1, 4), (23, 29)]);
+        set.remove(11, 15);
+        assert_eq!(as_pairs(&set), vec![(1, 4), (23, 29)]);
+        set.remove(-5, 100);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn interval_set_lookup() {
+        let set = make_set(&[(0, 3), (10, 12)]);
+        assert!(set.contains(0));
+        assert!(set.contains(11));
+        assert!(!set.contains(5));
+        assert!(!set.contains(13));
+        assert_eq!(set.find(2).map(|x| (x.begin, x.end)), Some((0, 3)));
+        assert!(set.find(-1).is_none());
+    }
+
+    #[test]
+    fn interval_set_operations() {
+        let a = make_set(&[(0, 10), (20, 30)]);
+        let b = make_set(&[(5, 25), (40, 50)]);
+        assert_eq!(as_pairs(&a.union(&b)), vec![(0, 30), (40, 50)]);
+        assert_eq!(as_pairs(&a.intersection(&b)), vec![(5, 10), (20, 25)]);
+        assert_eq!(as_pairs(&a.difference(&b)), vec![(0, 4), (26, 30)]);
+        assert_eq!(
+            as_pairs(&a.complement(PlainInterval::new(-5, 35))),
+            vec![(-5, -1), (11, 19), (31, 35)]
+        );
+    }
+}
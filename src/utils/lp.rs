@@ -0,0 +1,541 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use super::rational::Rational;
+
+/// Handle of the variable added to the linear program
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Variable(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    LessEq,
+    GreaterEq,
+    Equal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Goal {
+    Minimize,
+    Maximize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LpResult {
+    Optimal(LpSolution),
+    Infeasible,
+    Unbounded,
+}
+
+impl LpResult {
+    pub fn optimal(self) -> Option<LpSolution> {
+        match self {
+            Self::Optimal(solution) => Some(solution),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LpSolution {
+    objective: Rational,
+    values: Vec<Rational>,
+}
+
+impl LpSolution {
+    pub fn objective(&self) -> Rational {
+        self.objective
+    }
+
+    /// Values of all variables in the order they were added
+    pub fn values(&self) -> &[Rational] {
+        &self.values
+    }
+
+    pub fn value(&self, var: Variable) -> Rational {
+        self.values[var.0]
+    }
+
+    /// Value of the integer variable
+    pub fn int_value(&self, var: Variable) -> i128 {
+        self.values[var.0]
+            .to_integer()
+            .expect("Variable must be integer")
+    }
+}
+
+type Bounds = Vec<(Option<Rational>, Option<Rational>)>;
+
+#[derive(Debug, Clone)]
+struct Constraint {
+    terms: Vec<(usize, Rational)>,
+    relation: Relation,
+    rhs: Rational,
+}
+
+/// Linear program with exact arithmetic, the variables marked as integer
+/// are resolved with branch and bound.
+///
+/// ```ignore
+/// let mut lp = LinearProgram::new(Goal::Maximize);
+/// let x = lp.add_variable();
+/// let y = lp.add_integer_variable();
+/// lp.set_objective(&[(x, 3), (y, 5)]);
+/// lp.add_constraint(&[(x, 3), (y, 2)], Relation::LessEq, 18);
+/// let result = lp.solve();
+/// ```
+#[derive(Debug, Clone)]
+pub struct LinearProgram {
+    goal: Goal,
+    bounds: Bounds,
+    is_integer: Vec<bool>,
+    objective: Vec<(usize, Rational)>,
+    constraints: Vec<Constraint>,
+}
+
+fn to_terms<T: Into<Rational> + Copy>(terms: &[(Variable, T)]) -> Vec<(usize, Rational)> {
+    terms.iter().map(|(var, k)| (var.0, (*k).into())).collect()
+}
+
+impl LinearProgram {
+    pub fn new(goal: Goal) -> Self {
+        Self {
+            goal,
+            bounds: Vec::new(),
+            is_integer: Vec::new(),
+            objective: Vec::new(),
+            constraints: Vec::new(),
+        }
+    }
+
+    /// Adds the non-negative continuous variable
+    pub fn add_variable(&mut self) -> Variable {
+        self.bounds.push((Some(Rational::ZERO), None));
+        self.is_integer.push(false);
+        Variable(self.bounds.len() - 1)
+    }
+
+    /// Adds the non-negative integer variable
+    pub fn add_integer_variable(&mut self) -> Variable {
+        let var = self.add_variable();
+        self.is_integer[var.0] = true;
+        var
+    }
+
+    pub fn set_lower_bound(&mut self, var: Variable, value: impl Into<Rational>) -> &mut Self {
+        self.bounds[var.0].0 = Some(value.into());
+        self
+    }
+
+    pub fn set_upper_bound(&mut self, var: Variable, value: impl Into<Rational>) -> &mut Self {
+        self.bounds[var.0].1 = Some(value.into());
+        self
+    }
+
+    /// Removes both bounds of the variable, so it may take negative values as well
+    pub fn set_free(&mut self, var: Variable) -> &mut Self {
+        self.bounds[var.0] = (None, None);
+        self
+    }
+
+    pub fn set_objective<T: Into<Rational> + Copy>(
+        &mut self,
+        terms: &[(Variable, T)],
+    ) -> &mut Self {
+        self.objective = to_terms(terms);
+        self
+    }
+
+    pub fn add_constraint<T: Into<Rational> + Copy>(
+        &mut self,
+        terms: &[(Variable, T)],
+        relation: Relation,
+        rhs: T,
+    ) -> &mut Self {
+        self.constraints.push(Constraint {
+            terms: to_terms(terms),
+            relation,
+            rhs: rhs.into(),
+        });
+        self
+    }
+
+    /// Best-bound branch and bound over the integer variables
+    pub fn solve(&self) -> LpResult {
+        let mut incumbent: Option<LpSolution> = None;
+        let mut heap = BinaryHeap::new();
+        let mut nodes = vec![self.bounds.clone()];
+        // the bound is the minimized objective of the parent relaxation
+        heap.push(Reverse((Rational::ZERO, 0)));
+        while let Some(Reverse((bound, index))) = heap.pop() {
+            if incumbent
+                .as_ref()
+                .is_some_and(|x| self.minimized(x) <= bound)
+            {
+                continue;
+            }
+            let bounds = std::mem::take(&mut nodes[index]);
+            let solution = match self.relaxation(&bounds) {
+                Relaxation::Optimal(solution) => solution,
+                Relaxation::Infeasible => continue,
+                Relaxation::Unbounded => return LpResult::Unbounded,
+            };
+            let value = self.minimized(&solution);
+            if incumbent
+                .as_ref()
+                .is_some_and(|x| self.minimized(x) <= value)
+            {
+                continue;
+            }
+            let fractional = (0..self.bounds.len())
+                .filter(|i| self.is_integer[*i] && !solution.values[*i].is_integer())
+                .max_by_key(|i| {
+                    // the most fractional one, its distance to the nearest integer is maximal
+                    let x = solution.values[*i];
+                    let frac = x - Rational::from(x.floor());
+                    frac.min(Rational::ONE - frac)
+                });
+            let Some(i) = fractional else {
+                incumbent = Some(solution);
+                continue;
+            };
+            let x = solution.values[i];
+            let mut down = bounds.clone();
+            down[i].1 = Some(Rational::from(x.floor()));
+            let mut up = bounds;
+            up[i].0 = Some(Rational::from(x.ceil()));
+            for child in [down, up] {
+                nodes.push(child);
+                heap.push(Reverse((value, nodes.len() - 1)));
+            }
+        }
+        incumbent.map_or(LpResult::Infeasible, LpResult::Optimal)
+    }
+
+    fn minimized(&self, solution: &LpSolution) -> Rational {
+        match self.goal {
+            Goal::Minimize => solution.objective,
+            Goal::Maximize => -solution.objective,
+        }
+    }
+
+    /// Solves the continuous problem with the given bounds
+    fn relaxation(&self, bounds: &Bounds) -> Relaxation {
+        // each variable is `offset + sum(sign * y)` where `y >= 0` are the simplex columns
+        let mut mapping = Vec::with_capacity(bounds.len());
+        let mut rows = Vec::new();
+        let mut columns = 0;
+        for bound in bounds {
+            match *bound {
+                (Some(lower), upper) => {
+                    if upper.is_some_and(|upper| upper < lower) {
+                        return Relaxation::Infeasible;
+                    }
+                    if let Some(upper) = upper {
+                        rows.push((
+                            vec![(columns, Rational::ONE)],
+                            Relation::LessEq,
+                            upper - lower,
+                        ));
+                    }
+                    mapping.push((lower, vec![(columns, Rational::ONE)]));
+                    columns += 1;
+                }
+                (None, Some(upper)) => {
+                    mapping.push((upper, vec![(columns, -Rational::ONE)]));
+                    columns += 1;
+                }
+                (None, None) => {
+                    let terms = vec![(columns, Rational::ONE), (columns + 1, -Rational::ONE)];
+                    mapping.push((Rational::ZERO, terms));
+                    columns += 2;
+                }
+            }
+        }
+        let substitute = |terms: &[(usize, Rational)]| {
+            let mut offset = Rational::ZERO;
+            let mut row = vec![Rational::ZERO; columns];
+            for (var, k) in terms {
+                let (var_offset, var_terms) = &mapping[*var];
+                offset = offset + *k * *var_offset;
+                for (col, sign) in var_terms {
+                    row[*col] = row[*col] + *k * *sign;
+                }
+            }
+            (row, offset)
+        };
+        let mut table_rows = rows
+            .into_iter()
+            .map(|(terms, relation, rhs)| {
+                let mut row = vec![Rational::ZERO; columns];
+                terms.into_iter().for_each(|(col, k)| row[col] = k);
+                (row, relation, rhs)
+            })
+            .collect::<Vec<_>>();
+        for constraint in &self.constraints {
+            let (row, offset) = substitute(&constraint.terms);
+            table_rows.push((row, constraint.relation, constraint.rhs - offset));
+        }
+        let (mut cost, _) = substitute(&self.objective);
+        if self.goal == Goal::Maximize {
+            cost.iter_mut().for_each(|x| *x = -*x);
+        }
+        let y = match simplex(&table_rows, &cost) {
+            Relaxation::Optimal(y) => y,
+            Relaxation::Infeasible => return Relaxation::Infeasible,
+            Relaxation::Unbounded => return Relaxation::Unbounded,
+        };
+        let values = mapping
+            .iter()
+            .map(|(var_offset, terms)| {
+                terms
+                    .iter()
+                    .fold(*var_offset, |acc, (col, sign)| acc + *sign * y[*col])
+            })
+            .collect::<Vec<_>>();
+        let objective = self
+            .objective
+            .iter()
+            .fold(Rational::ZERO, |acc, (var, k)| acc + *k * values[*var]);
+        Relaxation::Optimal(LpSolution { objective, values })
+    }
+}
+
+enum Relaxation<T = LpSolution> {
+    Optimal(T),
+    Infeasible,
+    Unbounded,
+}
+
+type Row = Vec<Rational>;
+
+/// Subtracts the pivot row from the others so the column becomes the basis one
+fn pivot(table: &mut [Row], r: usize, c: usize) {
+    let k = table[r][c];
+    table[r].iter_mut().for_each(|x| *x = *x / k);
+    let pivot_row = table[r].clone();
+    for (i, row) in table.iter_mut().enumerate() {
+        let factor = row[c];
+        if i == r || factor.is_zero() {
+            continue;
+        }
+        row.iter_mut()
+            .zip(&pivot_row)
+            .for_each(|(x, p)| *x = *x - factor * *p);
+    }
+}
+
+/// Minimizes the objective in the last row of the table using the first `limit` columns,
+/// Bland's rule prevents cycling. Returns `false` if the objective is unbounded
+fn optimize(table: &mut [Row], basis: &mut [usize], limit: usize) -> bool {
+    let m = basis.len();
+    let rhs = table[0].len() - 1;
+    loop {
+        let Some(c) = (0..limit).find(|j| table[m][*j] < Rational::ZERO) else {
+            return true;
+        };
+        let Some(r) = (0..m)
+            .filter(|i| table[*i][c] > Rational::ZERO)
+            .min_by_key(|i| (table[*i][rhs] / table[*i][c], basis[*i]))
+        else {
+            return false;
+        };
+        pivot(table, r, c);
+        basis[r] = c;
+    }
+}
+
+/// Two-phase simplex, minimizes `cost * y` subject to the rows and `y >= 0`
+fn simplex(rows: &[(Row, Relation, Rational)], cost: &[Rational]) -> Relaxation<Row> {
+    let n = cost.len();
+    let m = rows.len();
+    let slacks = rows.iter().filter(|row| row.1 != Relation::Equal).count();
+    let artificial = n + slacks;
+    let width = artificial + m;
+    let mut table = Vec::with_capacity(m + 1);
+    let mut basis = Vec::with_capacity(m);
+    let mut slack = n;
+    for (i, (coefficients, relation, rhs)) in rows.iter().enumerate() {
+        let mut row = vec![Rational::ZERO; width + 1];
+        row[..n].copy_from_slice(coefficients);
+        row[width] = *rhs;
+        let mut relation = *relation;
+        if *rhs < Rational::ZERO {
+            row.iter_mut().for_each(|x| *x = -*x);
+            relation = match relation {
+                Relation::LessEq => Relation::GreaterEq,
+                Relation::GreaterEq => Relation::LessEq,
+                Relation::Equal => Relation::Equal,
+            };
+        }
+        match relation {
+            Relation::LessEq => {
+                row[slack] = Rational::ONE;
+                basis.push(slack);
+                slack += 1;
+            }
+            Relation::GreaterEq => {
+                row[slack] = -Rational::ONE;
+                row[artificial + i] = Rational::ONE;
+                basis.push(artificial + i);
+                slack += 1;
+            }
+            Relation::Equal => {
+                row[artificial + i] = Rational::ONE;
+                basis.push(artificial + i);
+            }
+        }
+        table.push(row);
+    }
+    // phase 1: minimize the sum of the artificial variables
+    let mut objective = vec![Rational::ZERO; width + 1];
+    for (row, b) in table.iter().zip(&basis) {
+        if *b >= artificial {
+            objective
+                .iter_mut()
+                .zip(row)
+                .for_each(|(x, a)| *x = *x - *a);
+        }
+    }
+    (artificial..width).for_each(|j| objective[j] = objective[j] + Rational::ONE);
+    table.push(objective);
+    optimize(&mut table, &mut basis, width);
+    if !table[m][width].is_zero() {
+        return Relaxation::Infeasible;
+    }
+    // artificial variables left in the basis are zero, they are replaced or their rows are redundant
+    let mut redundant = Vec::new();
+    for r in 0..m {
+        if basis[r] < artificial {
+            continue;
+        }
+        match (0..artificial).find(|j| !table[r][*j].is_zero()) {
+            Some(c) => {
+                pivot(&mut table, r, c);
+                basis[r] = c;
+            }
+            None => redundant.push(r),
+        }
+    }
+    for r in redundant.into_iter().rev() {
+        table.remove(r);
+        basis.remove(r);
+    }
+    // phase 2: the original objective
+    let m = basis.len();
+    let mut objective = vec![Rational::ZERO; width + 1];
+    objective[..n].copy_from_slice(cost);
+    for (row, b) in table.iter().zip(&basis) {
+        let c = if *b < n { cost[*b] } else { Rational::ZERO };
+        if !c.is_zero() {
+            objective
+                .iter_mut()
+                .zip(row)
+                .for_each(|(x, a)| *x = *x - c * *a);
+        }
+    }
+    table[m] = objective;
+    if !optimize(&mut table, &mut basis, artificial) {
+        return Relaxation::Unbounded;
+    }
+    let mut y = vec![Rational::ZERO; n];
+    for (row, b) in table.iter().zip(&basis) {
+        if *b < n {
+            y[*b] = row[width];
+        }
+    }
+    Relaxation::Optimal(y)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn lp_continuous() {
+        let mut lp = LinearProgram::new(Goal::Maximize);
+        let x = lp.add_variable();
+        let y = lp.add_variable();
+        lp.set_objective(&[(x, 3), (y, 5)])
+            .add_constraint(&[(x, 1)], Relation::LessEq, 4)
+            .add_constraint(&[(y, 2)], Relation::LessEq, 12)
+            .add_constraint(&[(x, 3), (y, 2)], Relation::LessEq, 18);
+        let solution = lp.solve().optimal().unwrap();
+        assert_eq!(solution.objective(), Rational::from(36));
+        assert_eq!(solution.int_value(x), 2);
+        assert_eq!(solution.int_value(y), 6);
+
+        // fractional optimum with the equality and the lower bound row
+        let mut lp = LinearProgram::new(Goal::Minimize);
+        let x = lp.add_variable();
+        let y = lp.add_variable();
+        lp.set_objective(&[(x, 1), (y, 1)])
+            .add_constraint(&[(x, 1), (y, -1)], Relation::Equal, 1)
+            .add_constraint(&[(x, 2), (y, 4)], Relation::GreaterEq, 3);
+        let solution = lp.solve().optimal().unwrap();
+        assert_eq!(solution.value(x), Rational::new(7, 6));
+        assert_eq!(solution.value(y), Rational::new(1, 6));
+        assert_eq!(solution.objective(), Rational::new(4, 3));
+    }
+
+    #[test]
+    fn lp_infeasible_and_unbounded() {
+        let mut lp = LinearProgram::new(Goal::Maximize);
+        let x = lp.add_variable();
+        lp.set_objective(&[(x, 1)])
+            .add_constraint(&[(x, 1)], Relation::GreaterEq, 2);
+        assert_eq!(lp.solve(), LpResult::Unbounded);
+        lp.add_constraint(&[(x, 1)], Relation::LessEq, 1);
+        assert_eq!(lp.solve(), LpResult::Infeasible);
+
+        let mut lp = LinearProgram::new(Goal::Minimize);
+        let x = lp.add_integer_variable();
+        lp.set_objective(&[(x, 1)])
+            .add_constraint(&[(x, 3)], Relation::Equal, 2);
+        assert_eq!(lp.solve(), LpResult::Infeasible);
+    }
+
+    #[test]
+    fn lp_bounds() {
+        let mut lp = LinearProgram::new(Goal::Minimize);
+        let x = lp.add_variable();
+        let y = lp.add_variable();
+        lp.set_free(x)
+            .set_lower_bound(y, -5)
+            .set_upper_bound(y, 3)
+            .set_objective(&[(x, 1), (y, 2)])
+            .add_constraint(&[(x, 1), (y, 1)], Relation::GreaterEq, -20);
+        let solution = lp.solve().optimal().unwrap();
+        assert_eq!(solution.int_value(y), -5);
+        assert_eq!(solution.int_value(x), -15);
+        assert_eq!(solution.objective(), Rational::from(-25));
+        // the objective is `x + 2y >= y - 20`
+        lp.set_free(y).set_upper_bound(y, -30);
+        assert_eq!(lp.solve(), LpResult::Unbounded);
+    }
+
+    #[test]
+    fn lp_integer() {
+        // the relaxation optimum is 21 at (3, 1.5)
+        let mut lp = LinearProgram::new(Goal::Maximize);
+        let x = lp.add_integer_variable();
+        let y = lp.add_integer_variable();
+        lp.set_objective(&[(x, 5), (y, 4)])
+            .add_constraint(&[(x, 6), (y, 4)], Relation::LessEq, 24)
+            .add_constraint(&[(x, 1), (y, 2)], Relation::LessEq, 6);
+        let solution = lp.solve().optimal().unwrap();
+        assert_eq!(solution.objective(), Rational::from(20));
+        assert_eq!((solution.int_value(x), solution.int_value(y)), (4, 0));
+        assert_eq!(solution.values(), [4, 0].map(Rational::from));
+
+        // 2024 day 13, the first claw machine
+        let mut lp = LinearProgram::new(Goal::Minimize);
+        let a = lp.add_integer_variable();
+        let b = lp.add_integer_variable();
+        lp.set_objective(&[(a, 3), (b, 1)])
+            .add_constraint(&[(a, 94), (b, 22)], Relation::Equal, 8400)
+            .add_constraint(&[(a, 34), (b, 67)], Relation::Equal, 5400);
+        let solution = lp.solve().optimal().unwrap();
+        assert_eq!(solution.objective().to_integer(), Some(280));
+        assert_eq!((solution.int_value(a), solution.int_value(b)), (80, 40));
+    }
+}
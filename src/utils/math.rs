@@ -1,52 +1,292 @@
-/// TODO: make these functions generic
-/// Greatest Common Divisor
-/// Implemented in Rust from https://www.geeksforgeeks.org/steins-algorithm-for-finding-gcd/
-pub fn gcd(a: usize, b: usize) -> usize {
-    if a == b {
-        return a;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// Common operations of the primitive integer types
+pub trait Integer:
+    Copy
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs_value(self) -> Self;
+
+    fn is_odd(self) -> bool {
+        self % (Self::ONE + Self::ONE) != Self::ZERO
     }
-    if a == 0 {
-        return b;
+}
+
+macro_rules! impl_integer {
+    (signed: $($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs_value(self) -> Self {
+                self.abs()
+            }
+        })*
+    };
+    (unsigned: $($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs_value(self) -> Self {
+                self
+            }
+        })*
+    };
+}
+
+impl_integer!(signed: i8, i16, i32, i64, i128, isize);
+impl_integer!(unsigned: u8, u16, u32, u64, u128, usize);
+
+/// Greatest Common Divisor, the result is non-negative
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs_value(), b.abs_value());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
     }
-    if b == 0 {
-        return a;
+    a
+}
+
+/// Least common multiple
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    (a / gcd(a, b) * b).abs_value()
+}
+
+/// Extended Euclidean algorithm, returns `(g, x, y)` where `a * x + b * y = g = gcd(a, b)`
+pub fn egcd<T: Integer + Neg<Output = T>>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
     }
-    // Finding K, where K is the greatest power of 2 that divides both a and b
-    let mut k = 0;
-    let mut a = a;
-    let mut b = b;
-    while ((a | b) & 1) == 0 {
-        a >>= 1;
-        b >>= 1;
-        k += 1;
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
     }
-    // Dividing a by 2 until a becomes odd
-    while (a & 1) == 0 {
-        a >>= 1;
+}
+
+/// Non-negative remainder of the division
+pub fn modulo<T: Integer>(a: T, m: T) -> T {
+    let r = a % m;
+    if r < T::ZERO {
+        r + m.abs_value()
+    } else {
+        r
+    }
+}
+
+/// Modular multiplicative inverse, exists only if `a` and `m` are coprime
+pub fn mod_inv<T: Integer + Neg<Output = T>>(a: T, m: T) -> Option<T> {
+    let (g, x, _) = egcd(modulo(a, m), m);
+    if g != T::ONE {
+        return None;
     }
-    // From here on, 'a' is always odd
-    loop {
-        // If b is even, remove all factor of 2 in b
-        while (b & 1) == 0 {
-            b >>= 1;
+    Some(modulo(x, m))
+}
+
+/// Modular exponentiation, `(m - 1)^2` must fit the type
+pub fn mod_pow<T: Integer>(base: T, exp: T, m: T) -> T {
+    if m == T::ONE {
+        return T::ZERO;
+    }
+    let two = T::ONE + T::ONE;
+    let mut result = T::ONE;
+    let mut base = modulo(base, m);
+    let mut exp = exp;
+    while exp > T::ZERO {
+        if exp.is_odd() {
+            result = result * base % m;
         }
-        // Now a and b are both odd. Swap if necessary so a <= b, then set
-        // b = b - a (which is even)
-        if a > b {
-            std::mem::swap(&mut a, &mut b);
+        base = base * base % m;
+        exp = exp / two;
+    }
+    result
+}
+
+/// Chinese remainder theorem for the `(residue, modulus)` pairs,
+/// moduli don't need to be coprime.
+/// Returns `(x, lcm)` where `x` is the smallest non-negative solution
+/// or `None` if the system is inconsistent
+pub fn crt<T: Integer + Neg<Output = T>>(pairs: &[(T, T)]) -> Option<(T, T)> {
+    pairs
+        .iter()
+        .try_fold((T::ZERO, T::ONE), |(r1, m1), &(r2, m2)| {
+            let r2 = modulo(r2, m2);
+            let (g, p, _) = egcd(m1, m2);
+            let diff = r2 - r1;
+            if diff % g != T::ZERO {
+                return None;
+            }
+            let step = m2 / g;
+            let k = modulo(diff / g % step * modulo(p, step), step);
+            let m = m1 * step;
+            Some((modulo(r1 + m1 * k, m), m))
+        })
+}
+
+/// Linear function `x -> a * x + b` modulo `m`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AffineMap<T> {
+    pub a: T,
+    pub b: T,
+    pub m: T,
+}
+
+impl<T: Integer> AffineMap<T> {
+    pub fn new(a: T, b: T, m: T) -> Self {
+        Self {
+            a: modulo(a, m),
+            b: modulo(b, m),
+            m,
         }
-        b -= a;
-        if b == 0 {
-            break;
+    }
+
+    pub fn identity(m: T) -> Self {
+        Self::new(T::ONE, T::ZERO, m)
+    }
+
+    pub fn apply(&self, x: T) -> T {
+        modulo(self.a * x + self.b, self.m)
+    }
+
+    /// Composition that applies `self` first and `other` next
+    pub fn then(&self, other: &Self) -> Self {
+        Self::new(other.a * self.a, other.a * self.b + other.b, self.m)
+    }
+
+    /// Function applied `n` times
+    pub fn pow(&self, n: u128) -> Self {
+        let mut result = Self::identity(self.m);
+        let mut base = *self;
+        let mut n = n;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result.then(&base);
+            }
+            base = base.then(&base);
+            n >>= 1;
         }
+        result
     }
-    // restore common factors of 2
-    a << k
 }
 
-/// Least common multiple
-pub fn lcm(a: usize, b: usize) -> usize {
-    a * b / gcd(a, b)
+impl<T: Integer + Neg<Output = T>> AffineMap<T> {
+    pub fn inverse(&self) -> Option<Self> {
+        let inv = mod_inv(self.a, self.m)?;
+        Some(Self::new(inv, -inv * self.b, self.m))
+    }
+}
+
+/// Prime factors with their powers in ascending order
+pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+    let mut result = Vec::new();
+    let mut n = n;
+    let mut p = 2;
+    while p <= n / p {
+        let mut power = 0;
+        while n.is_multiple_of(p) {
+            n /= p;
+            power += 1;
+        }
+        if power > 0 {
+            result.push((p, power));
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        result.push((n, 1));
+    }
+    result
+}
+
+/// All divisors of the number in ascending order, zero has no divisors
+pub fn divisors(n: u64) -> Vec<u64> {
+    if n == 0 {
+        return Vec::new();
+    }
+    let mut result = vec![1];
+    for (p, power) in factorize(n) {
+        let count = result.len();
+        let mut factor = 1;
+        for _ in 0..power {
+            factor *= p;
+            for i in 0..count {
+                result.push(result[i] * factor);
+            }
+        }
+    }
+    result.sort();
+    result
+}
+
+/// Sum of all divisors including the number itself, zero has no divisors.
+/// Overflows only when the sum itself doesn't fit into `u64`
+pub fn divisor_sum(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    factorize(n)
+        .into_iter()
+        .map(|(p, power)| {
+            // 1 + p + ... + p^power, every term divides n so none overflows
+            let mut term = 1;
+            let mut sum = 1;
+            for _ in 0..power {
+                term *= p;
+                sum += term;
+            }
+            sum
+        })
+        .product()
+}
+
+/// Deterministic Miller-Rabin test for the whole `u64` range
+pub fn is_prime(n: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    for p in WITNESSES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    let n = n as u128;
+    let mut d = n - 1;
+    let mut s = 0;
+    while d.is_multiple_of(2) {
+        d /= 2;
+        s += 1;
+    }
+    WITNESSES.iter().all(|&a| {
+        let mut x = mod_pow(a as u128, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = x * x % n;
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
 }
 
 #[cfg(test)]
@@ -59,5 +299,94 @@ mod test {
         assert_eq!(4, gcd(8, 12));
         assert_eq!(6, gcd(54, 24));
         assert_eq!(6, gcd(48, 18));
+        assert_eq!(6, gcd(-48i32, 18));
+        assert_eq!(5, gcd(0u8, 5));
+    }
+
+    #[test]
+    fn math_lcm() {
+        assert_eq!(36, lcm(12, 18));
+        assert_eq!(36, lcm(-12i64, 18));
+        assert_eq!(0, lcm(0usize, 18));
+    }
+
+    #[test]
+    fn math_egcd_and_inverse() {
+        let (g, x, y) = egcd(240i64, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+        assert_eq!(mod_inv(3i32, 11), Some(4));
+        assert_eq!(mod_inv(-3i32, 11), Some(7));
+        assert_eq!(mod_inv(4i32, 8), None);
+    }
+
+    #[test]
+    fn math_mod_pow() {
+        assert_eq!(mod_pow(4u64, 13, 497), 445);
+        assert_eq!(mod_pow(-2i64, 3, 5), 2);
+        assert_eq!(mod_pow(7u32, 0, 13), 1);
+        assert_eq!(mod_pow(7u32, 5, 1), 0);
+    }
+
+    #[test]
+    fn math_crt() {
+        assert_eq!(crt(&[(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // non-coprime moduli
+        assert_eq!(crt(&[(2i64, 6), (8, 10)]), Some((8, 30)));
+        assert_eq!(crt(&[(1i64, 6), (2, 4)]), None);
+        // 2020 day 13 example: 17,x,13,19
+        assert_eq!(
+            crt(&[(0i64, 17), (-2, 13), (-3, 19)]),
+            Some((3417, 17 * 13 * 19))
+        );
+    }
+
+    #[test]
+    fn math_affine_map() {
+        let m = 10007i64;
+        let f = AffineMap::new(3, 5, m);
+        let g = AffineMap::new(-1, -1, m);
+        assert_eq!(f.then(&g).apply(7), g.apply(f.apply(7)));
+        let mut x = 123;
+        for _ in 0..1000 {
+            x = f.apply(x);
+        }
+        assert_eq!(f.pow(1000).apply(123), x);
+        let inv = f.inverse().unwrap();
+        assert_eq!(inv.apply(f.apply(42)), 42);
+        assert_eq!(f.then(&inv), AffineMap::identity(m));
+    }
+
+    #[test]
+    fn math_divisors_and_primes() {
+        assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(factorize(97), vec![(97, 1)]);
+        assert!(factorize(1).is_empty());
+        assert_eq!(
+            factorize(u64::MAX),
+            vec![
+                (3, 1),
+                (5, 1),
+                (17, 1),
+                (257, 1),
+                (641, 1),
+                (65537, 1),
+                (6700417, 1)
+            ]
+        );
+        assert_eq!(divisors(12), vec![1, 2, 3, 4, 6, 12]);
+        assert_eq!(divisors(1), vec![1]);
+        assert!(divisors(0).is_empty());
+        assert_eq!(divisor_sum(12), 28);
+        assert_eq!(divisor_sum(4_294_967_311), 4_294_967_312);
+        assert_eq!(divisor_sum(1 << 40), (1 << 41) - 1);
+        assert_eq!(divisor_sum(1), 1);
+        assert_eq!(divisor_sum(0), 0);
+        assert!(is_prime(2));
+        assert!(is_prime(1_000_000_007));
+        assert!(is_prime(18_446_744_073_709_551_557));
+        assert!(!is_prime(1));
+        assert!(!is_prime(561));
+        assert!(!is_prime(1_000_000_007 * 998_244_353));
     }
 }
@@ -0,0 +1,296 @@
+use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
+
+use super::rational::Rational;
+
+const EPS: f64 = 1e-9;
+
+/// Number type that supports the exact or approximate division
+pub trait Field:
+    Copy
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn is_zero(&self) -> bool;
+
+    /// Pivot with the largest magnitude is preferred during the elimination
+    fn magnitude(&self) -> f64;
+}
+
+impl Field for f64 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    fn is_zero(&self) -> bool {
+        self.abs() < EPS
+    }
+
+    fn magnitude(&self) -> f64 {
+        self.abs()
+    }
+}
+
+impl Field for Rational {
+    const ZERO: Self = Rational::ZERO;
+    const ONE: Self = Rational::ONE;
+
+    fn is_zero(&self) -> bool {
+        Rational::is_zero(self)
+    }
+
+    fn magnitude(&self) -> f64 {
+        self.abs().to_f64()
+    }
+}
+
+/// Dense matrix with row-major storage, indexed by `(row, col)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Copy> Matrix<T> {
+    pub fn from_rows(rows: &[Vec<T>]) -> Self {
+        let cols = rows.first().map(|row| row.len()).unwrap_or_default();
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "Rows must have the same length"
+        );
+        Self {
+            rows: rows.len(),
+            cols,
+            cells: rows.concat(),
+        }
+    }
+
+    fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for col in 0..self.cols {
+            self.cells.swap(a * self.cols + col, b * self.cols + col);
+        }
+    }
+
+    /// Appends the columns of other matrix on the right
+    fn augmented(&self, other: &Self) -> Self {
+        assert_eq!(self.rows, other.rows);
+        let cells = (0..self.rows)
+            .flat_map(|row| [self.row(row), other.row(row)].concat())
+            .collect();
+        Self {
+            rows: self.rows,
+            cols: self.cols + other.cols,
+            cells,
+        }
+    }
+}
+
+/// Result of the Gauss-Jordan elimination
+struct Elimination<T> {
+    /// Columns of the leading elements, the rank is its length
+    pivots: Vec<usize>,
+    /// Product of the pivots before normalization with the sign of row swaps
+    determinant: T,
+}
+
+impl<T: Field> Matrix<T> {
+    fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            cells: vec![T::ZERO; rows * cols],
+        }
+    }
+
+    fn identity(size: usize) -> Self {
+        let mut matrix = Self::new(size, size);
+        (0..size).for_each(|i| matrix[(i, i)] = T::ONE);
+        matrix
+    }
+
+    /// Transforms the first `cols` columns to the reduced row echelon form,
+    /// the rest columns are transformed along
+    fn eliminate(&mut self, cols: usize) -> Elimination<T> {
+        let mut pivots = Vec::new();
+        let mut determinant = T::ONE;
+        for col in 0..cols {
+            let top = pivots.len();
+            if top == self.rows {
+                break;
+            }
+            let Some(best) = (top..self.rows)
+                .filter(|row| !self[(*row, col)].is_zero())
+                .max_by(|a, b| {
+                    self[(*a, col)]
+                        .magnitude()
+                        .total_cmp(&self[(*b, col)].magnitude())
+                })
+            else {
+                determinant = T::ZERO;
+                continue;
+            };
+            if best != top {
+                self.swap_rows(best, top);
+                determinant = -determinant;
+            }
+            let pivot = self[(top, col)];
+            determinant = determinant * pivot;
+            for c in col..self.cols {
+                self[(top, c)] = self[(top, c)] / pivot;
+            }
+            for row in (0..self.rows).filter(|row| *row != top) {
+                let factor = self[(row, col)];
+                if factor.is_zero() {
+                    continue;
+                }
+                for c in col..self.cols {
+                    self[(row, c)] = self[(row, c)] - factor * self[(top, c)];
+                }
+            }
+            pivots.push(col);
+        }
+        Elimination {
+            pivots,
+            determinant,
+        }
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().eliminate(self.cols).pivots.len()
+    }
+
+    pub fn determinant(&self) -> T {
+        assert_eq!(self.rows, self.cols, "Square matrix is expected");
+        let mut matrix = self.clone();
+        let elimination = matrix.eliminate(self.cols);
+        if elimination.pivots.len() < self.rows {
+            return T::ZERO;
+        }
+        elimination.determinant
+    }
+
+    /// Returns `None` for the singular matrix
+    pub fn inverse(&self) -> Option<Self> {
+        assert_eq!(self.rows, self.cols, "Square matrix is expected");
+        let mut matrix = self.augmented(&Self::identity(self.rows));
+        let elimination = matrix.eliminate(self.cols);
+        if elimination.pivots.len() < self.rows {
+            return None;
+        }
+        let cells = (0..self.rows)
+            .flat_map(|row| matrix.row(row)[self.cols..].to_vec())
+            .collect();
+        Some(Self {
+            rows: self.rows,
+            cols: self.cols,
+            cells,
+        })
+    }
+
+    /// Solves `A * x = b`, returns `None` if the system is inconsistent
+    /// or doesn't have the unique solution
+    pub fn solve(&self, b: &[T]) -> Option<Vec<T>> {
+        assert_eq!(self.rows, b.len());
+        let rhs = Matrix {
+            rows: b.len(),
+            cols: 1,
+            cells: b.to_vec(),
+        };
+        let mut matrix = self.augmented(&rhs);
+        let elimination = matrix.eliminate(self.cols);
+        let rank = elimination.pivots.len();
+        if rank < self.cols || (rank..self.rows).any(|row| !matrix[(row, self.cols)].is_zero()) {
+            return None;
+        }
+        Some((0..rank).map(|row| matrix[(row, self.cols)]).collect())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(row < self.rows && col < self.cols);
+        &self.cells[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        assert!(row < self.rows && col < self.cols);
+        &mut self.cells[row * self.cols + col]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rational_matrix(rows: &[Vec<i64>]) -> Matrix<Rational> {
+        let rows = rows
+            .iter()
+            .map(|row| row.iter().map(|x| Rational::from(*x)).collect())
+            .collect::<Vec<_>>();
+        Matrix::from_rows(&rows)
+    }
+
+    #[test]
+    fn matrix_rank_and_determinant() {
+        let m = rational_matrix(&[vec![2, -3, 1], vec![2, 0, -1], vec![1, 4, 5]]);
+        assert_eq!(m.determinant(), Rational::from(49));
+        assert_eq!(m.rank(), 3);
+        let m = rational_matrix(&[vec![0, 1], vec![1, 0]]);
+        assert_eq!(m.determinant(), Rational::from(-1));
+        let m = rational_matrix(&[vec![1, 2, 3], vec![2, 4, 6], vec![1, 0, 1]]);
+        assert_eq!(m.determinant(), Rational::ZERO);
+        assert_eq!(m.rank(), 2);
+        assert_eq!(rational_matrix(&[vec![1, 2, 3], vec![2, 4, 7]]).rank(), 2);
+        let m = Matrix::from_rows(&[vec![4.0, 3.0], vec![6.0, 3.0]]);
+        assert!((m.determinant() + 6.0).abs() < EPS);
+    }
+
+    #[test]
+    fn matrix_inverse() {
+        let m = rational_matrix(&[vec![2, 1], vec![7, 4]]);
+        let inv = m.inverse().unwrap();
+        assert_eq!(inv, rational_matrix(&[vec![4, -1], vec![-7, 2]]));
+        assert_eq!(inv.inverse(), Some(m));
+        let m = rational_matrix(&[vec![1, 2], vec![3, 4]]);
+        let inv = m.inverse().unwrap();
+        assert_eq!(inv[(1, 0)], Rational::new(3, 2));
+        assert!(rational_matrix(&[vec![1, 2], vec![2, 4]])
+            .inverse()
+            .is_none());
+    }
+
+    #[test]
+    fn matrix_solve() {
+        // x + y + z = 6, 2y + 5z = -4, 2x + 5y - z = 27
+        let m = rational_matrix(&[vec![1, 1, 1], vec![0, 2, 5], vec![2, 5, -1]]);
+        let b = [6, -4, 27].map(Rational::from);
+        let x = m.solve(&b).unwrap();
+        assert_eq!(x, [5, 3, -2].map(Rational::from).to_vec());
+        // consistent overdetermined system
+        let m = rational_matrix(&[vec![1, 1], vec![1, -1], vec![2, 1]]);
+        let x = m.solve(&[3, 1, 5].map(Rational::from)).unwrap();
+        assert_eq!(x, [2, 1].map(Rational::from).to_vec());
+        assert!(m.solve(&[3, 1, 6].map(Rational::from)).is_none());
+        // underdetermined system
+        let m = rational_matrix(&[vec![1, 1]]);
+        assert!(m.solve(&[Rational::ONE]).is_none());
+        // the same in floating point
+        let m = Matrix::from_rows(&[vec![1.0, 1.0], vec![1.0, -1.0]]);
+        let x = m.solve(&[3.0, 1.0]).unwrap();
+        assert!((x[0] - 2.0).abs() < EPS && (x[1] - 1.0).abs() < EPS);
+    }
+}
@@ -7,9 +7,13 @@ pub use options::*;
 pub mod string2id;
 pub use string2id::*;
 
+// k-permutations, multiset permutations and next_permutation wait for their first day
+#[allow(dead_code)]
 pub mod permutations;
 pub use permutations::*;
 
+// days only take plain combinations, the other iterators and n_choose_k are unused
+#[allow(dead_code)]
 pub mod combinations;
 pub use combinations::*;
 
@@ -19,6 +23,8 @@ pub use arrays::*;
 pub mod strings;
 pub use strings::*;
 
+// no day uses the Point3d products, edge and corner neighbours or rotation composition
+#[allow(dead_code)]
 pub mod coordinate;
 pub use coordinate::*;
 
@@ -36,8 +42,54 @@ pub use bounds::*;
 
 pub mod checked_ops;
 
+// no day needs the product over heterogeneous slices yet
+#[allow(dead_code)]
 pub mod cartesian;
 
 pub mod hyper_point;
 
-pub mod simplex;
+pub mod grid;
+
+pub mod search;
+
+pub mod cycle;
+
+// polygon area, point location and winding number aren't used by any day yet
+#[allow(dead_code)]
+pub mod geometry;
+
+pub mod interval_set;
+
+pub mod box_n;
+
+// rings, spirals and the Point2d layouts are kept for future hex puzzles
+#[allow(dead_code)]
+pub mod hex;
+
+pub mod automaton;
+
+pub mod dsu;
+
+pub mod rational;
+
+// days only solve linear systems, rank, determinant and inverse are unused
+#[allow(dead_code)]
+pub mod matrix;
+
+// 2025 day 10 needs only the objective, the bounds and values are unused yet
+#[allow(dead_code)]
+pub mod lp;
+
+pub mod parse;
+
+pub mod ocr;
+
+// 2015 day 15 uses only the compositions
+#[allow(dead_code)]
+pub mod partitions;
+
+pub mod bitset;
+
+// SCC and the DAG longest path have no day yet
+#[allow(dead_code)]
+pub mod graph;
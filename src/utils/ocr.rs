@@ -0,0 +1,292 @@
+use super::{bounds, math::Integer, Point2d};
+
+/// Letters 4 pixels wide and 6 tall, `Y` takes the spacing column as well
+const SMALL_FONT: [(char, [&str; 6]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Letters 6 pixels wide and 10 tall
+const LARGE_FONT: [(char, [&str; 10]); 15] = [
+    (
+        'A',
+        [
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+            "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+            "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        [
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        [
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+            "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        [
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+            "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        [
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        [
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+            "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        [
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        [
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+];
+
+/// Letter shape as the column bitmasks, the blank columns around are dropped
+struct Glyph {
+    letter: char,
+    columns: Vec<u16>,
+}
+
+impl Glyph {
+    fn new(letter: char, rows: &[&str]) -> Self {
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or_default();
+        let columns = (0..width)
+            .map(|col| {
+                rows.iter()
+                    .enumerate()
+                    .filter(|(_, row)| row.as_bytes().get(col) == Some(&b'#'))
+                    .fold(0, |acc, (i, _)| acc | 1 << i)
+            })
+            .collect();
+        Self {
+            letter,
+            columns: trimmed(columns),
+        }
+    }
+}
+
+fn trimmed(mut columns: Vec<u16>) -> Vec<u16> {
+    while columns.last() == Some(&0) {
+        columns.pop();
+    }
+    let start = columns.iter().take_while(|x| **x == 0).count();
+    columns.split_off(start)
+}
+
+/// Glyphs of the font that fits the height, the widest ones go first
+fn font(height: usize) -> Option<Vec<Glyph>> {
+    let mut glyphs = match height {
+        6 => SMALL_FONT
+            .iter()
+            .map(|(letter, rows)| Glyph::new(*letter, rows))
+            .collect::<Vec<_>>(),
+        10 => LARGE_FONT
+            .iter()
+            .map(|(letter, rows)| Glyph::new(*letter, rows))
+            .collect::<Vec<_>>(),
+        _ => return None,
+    };
+    glyphs.sort_by_key(|glyph| std::cmp::Reverse(glyph.columns.len()));
+    Some(glyphs)
+}
+
+/// Reads the capital letters drawn by the lit pixels, the text may be placed
+/// anywhere on the screen. Returns `None` if some shape isn't a known letter
+pub fn recognize(pixels: &[Vec<bool>]) -> Option<String> {
+    let lit_rows = pixels
+        .iter()
+        .enumerate()
+        .filter(|(_, row)| row.iter().any(|x| *x))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    let (top, bottom) = (*lit_rows.first()?, *lit_rows.last()?);
+    let glyphs = font(bottom - top + 1)?;
+    let width = pixels.iter().map(|row| row.len()).max().unwrap_or_default();
+    let columns = (0..width)
+        .map(|col| {
+            (top..=bottom)
+                .filter(|row| pixels[*row].get(col).copied().unwrap_or_default())
+                .fold(0u16, |acc, row| acc | 1 << (row - top))
+        })
+        .collect::<Vec<_>>();
+
+    let mut result = String::new();
+    let mut col = 0;
+    while col < columns.len() {
+        if columns[col] == 0 {
+            col += 1;
+            continue;
+        }
+        let glyph = glyphs
+            .iter()
+            .find(|glyph| columns[col..].starts_with(&glyph.columns))?;
+        result.push(glyph.letter);
+        col += glyph.columns.len();
+    }
+    Some(result)
+}
+
+/// The same as `recognize` for the coordinates of lit pixels, `y` grows downwards
+pub fn recognize_points<T>(points: &[Point2d<T>]) -> Option<String>
+where
+    T: Integer + TryInto<usize>,
+{
+    let bounds = bounds(points)?;
+    let index = |value: T, low: T| (value - low).try_into().ok();
+    let width = index(bounds.high.x, bounds.low.x)? + 1;
+    let height = index(bounds.high.y, bounds.low.y)? + 1;
+    let mut pixels = vec![vec![false; width]; height];
+    for p in points {
+        pixels[index(p.y, bounds.low.y)?][index(p.x, bounds.low.x)?] = true;
+    }
+    recognize(&pixels)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn to_pixels(rows: &[&str]) -> Vec<Vec<bool>> {
+        rows.iter()
+            .map(|row| row.chars().map(|ch| ch == '#').collect())
+            .collect()
+    }
+
+    #[test]
+    fn ocr_small_font() {
+        let rows = [
+            "####...##.#..#.###..#..#..##..###..#....#...#...##.",
+            "...#....#.#..#.#..#.#.#..#..#.#..#.#....#...#....#.",
+            "..#.....#.####.#..#.##...#....#..#.#.....#.#.....#.",
+            ".#......#.#..#.###..#.#..#....###..#......#......#.",
+            "#....#..#.#..#.#.#..#.#..#..#.#....#......#...#..#.",
+            "####..##..#..#.#..#.#..#..##..#....####...#....##..",
+        ];
+        assert_eq!(recognize(&to_pixels(&rows)), Some("ZJHRKCPLYJ".to_string()));
+        let mut pixels = to_pixels(&rows);
+        pixels.insert(0, vec![false; 3]);
+        pixels.iter_mut().for_each(|row| row.insert(0, false));
+        assert_eq!(recognize(&pixels), Some("ZJHRKCPLYJ".to_string()));
+        let unknown = ["#.#.", ".#..", "#.#.", ".#..", "#.#.", ".#.."];
+        assert_eq!(recognize(&to_pixels(&unknown)), None);
+        assert_eq!(recognize(&to_pixels(&["....", "...."])), None);
+    }
+
+    #[test]
+    fn ocr_large_font() {
+        let rows = [
+            "#....#..#####.",
+            "#....#..#....#",
+            "#....#..#....#",
+            "#....#..#....#",
+            "######..#####.",
+            "#....#..#..#..",
+            "#....#..#...#.",
+            "#....#..#...#.",
+            "#....#..#....#",
+            "#....#..#....#",
+        ];
+        assert_eq!(recognize(&to_pixels(&rows)), Some("HR".to_string()));
+        let points = to_pixels(&rows)
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_, lit)| **lit)
+                    .map(move |(x, _)| Point2d::new(x as i32 - 50, y as i32 + 7))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(recognize_points(&points), Some("HR".to_string()));
+    }
+}
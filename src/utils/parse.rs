@@ -0,0 +1,288 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// Text doesn't match the pattern or the expected format
+    Mismatch(String),
+    /// Number of the captured values differs from the expected one
+    CaptureCount { expected: usize, found: usize },
+    /// Captured value can't be converted to the requested type
+    InvalidValue(String),
+}
+
+/// Parsing failure, the line number starts from 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    fn new(line: usize, kind: ParseErrorKind) -> Self {
+        Self { line, kind }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            ParseErrorKind::Mismatch(text) => write!(f, "line {}: unexpected '{text}'", self.line),
+            ParseErrorKind::CaptureCount { expected, found } => write!(
+                f,
+                "line {}: expected {expected} values, found {found}",
+                self.line
+            ),
+            ParseErrorKind::InvalidValue(value) => {
+                write!(f, "line {}: invalid value '{value}'", self.line)
+            }
+        }
+    }
+}
+
+/// All integers in the text, the minus is treated as a sign
+/// only if it isn't preceded by a letter or digit, so `2-4` gives `2` and `4`
+pub fn integers<T: FromStr>(s: &str) -> Vec<T> {
+    let bytes = s.as_bytes();
+    let mut result = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let is_sign = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_alphanumeric());
+        if !is_sign && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if let Ok(value) = s[start..i].parse::<T>() {
+            result.push(value);
+        }
+    }
+    result
+}
+
+/// Groups of lines separated by the blank ones, empty groups are skipped
+pub fn sections(data: &str) -> Vec<Vec<&str>> {
+    let mut result = vec![Vec::new()];
+    for line in data.lines() {
+        if line.trim().is_empty() {
+            if result.last().is_some_and(|x| !x.is_empty()) {
+                result.push(Vec::new());
+            }
+        } else if let Some(section) = result.last_mut() {
+            section.push(line);
+        }
+    }
+    result.retain(|x| !x.is_empty());
+    result
+}
+
+/// Splits `key: value` at the first colon, both parts are trimmed
+pub fn key_value(line: &str) -> Option<(&str, &str)> {
+    line.split_once(':')
+        .map(|(key, value)| (key.trim(), value.trim()))
+}
+
+/// Record of the `key: value` lines, blank lines are ignored
+pub fn record(text: &str) -> Result<HashMap<&str, &str>, ParseError> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            key_value(line)
+                .ok_or_else(|| ParseError::new(i + 1, ParseErrorKind::Mismatch(line.to_string())))
+        })
+        .collect()
+}
+
+/// Typed values built from the pattern captures
+pub trait FromCaptures: Sized {
+    fn from_captures(captures: &[&str]) -> Result<Self, ParseErrorKind>;
+}
+
+fn parse_value<T: FromStr>(value: &str) -> Result<T, ParseErrorKind> {
+    value
+        .trim()
+        .parse::<T>()
+        .map_err(|_| ParseErrorKind::InvalidValue(value.to_string()))
+}
+
+impl<T: FromStr> FromCaptures for Vec<T> {
+    fn from_captures(captures: &[&str]) -> Result<Self, ParseErrorKind> {
+        captures.iter().map(|x| parse_value(x)).collect()
+    }
+}
+
+macro_rules! impl_from_captures {
+    ($count:expr; $($t:ident $i:tt),+) => {
+        impl<$($t: FromStr),+> FromCaptures for ($($t,)+) {
+            fn from_captures(captures: &[&str]) -> Result<Self, ParseErrorKind> {
+                if captures.len() != $count {
+                    return Err(ParseErrorKind::CaptureCount {
+                        expected: $count,
+                        found: captures.len(),
+                    });
+                }
+                Ok(($(parse_value::<$t>(captures[$i])?,)+))
+            }
+        }
+    };
+}
+
+impl_from_captures!(1; A 0);
+impl_from_captures!(2; A 0, B 1);
+impl_from_captures!(3; A 0, B 1, C 2);
+impl_from_captures!(4; A 0, B 1, C 2, D 3);
+impl_from_captures!(5; A 0, B 1, C 2, D 3, E 4);
+impl_from_captures!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// Format string where each `{}` captures the shortest text
+/// up to the next literal part, e.g. `"move {} from {} to {}"`
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    literals: Vec<String>,
+}
+
+impl Pattern {
+    pub fn new(format: &str) -> Self {
+        Self {
+            literals: format.split("{}").map(str::to_string).collect(),
+        }
+    }
+
+    /// Captured parts of the text, `None` if the text doesn't match
+    pub fn captures<'a>(&self, text: &'a str) -> Option<Vec<&'a str>> {
+        let (first, rest) = self.literals.split_first()?;
+        let mut text = text.strip_prefix(first.as_str())?;
+        let mut result = Vec::with_capacity(rest.len());
+        for (i, literal) in rest.iter().enumerate() {
+            let is_last = i + 1 == rest.len();
+            let end = if is_last {
+                text.strip_suffix(literal.as_str())?.len()
+            } else if literal.is_empty() {
+                // adjacent placeholders are ambiguous, the first one is empty
+                0
+            } else {
+                text.find(literal.as_str())?
+            };
+            result.push(&text[..end]);
+            text = &text[end + literal.len()..];
+        }
+        // the pattern without placeholders must match the whole text
+        text.is_empty().then_some(result)
+    }
+
+    pub fn parse<T: FromCaptures>(&self, text: &str) -> Result<T, ParseError> {
+        self.parse_line(1, text)
+    }
+
+    /// Parses every non-empty line reporting the first failure
+    pub fn parse_lines<T: FromCaptures, S: AsRef<str>>(
+        &self,
+        lines: &[S],
+    ) -> Result<Vec<T>, ParseError> {
+        lines
+            .iter()
+            .map(|x| x.as_ref())
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(i, line)| self.parse_line(i + 1, line))
+            .collect()
+    }
+
+    fn parse_line<T: FromCaptures>(&self, line: usize, text: &str) -> Result<T, ParseError> {
+        let captures = self
+            .captures(text)
+            .ok_or_else(|| ParseError::new(line, ParseErrorKind::Mismatch(text.to_string())))?;
+        T::from_captures(&captures).map_err(|kind| ParseError::new(line, kind))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_integers() {
+        assert_eq!(integers::<i32>("x=-2, y=15: at x=3"), vec![-2, 15, 3]);
+        assert_eq!(integers::<i32>("2-4,6-8"), vec![2, 4, 6, 8]);
+        assert_eq!(integers::<i64>("<x=-1, y=0, z=2>"), vec![-1, 0, 2]);
+        assert_eq!(integers::<u8>("a1b22c-3 -"), vec![1, 22, 3]);
+        assert!(integers::<i32>("no numbers").is_empty());
+    }
+
+    #[test]
+    fn parse_sections_and_records() {
+        let data = "a\nb\n\n\n  \nc\n\n";
+        assert_eq!(sections(data), vec![vec!["a", "b"], vec!["c"]]);
+        assert!(sections("\n\n").is_empty());
+        let rec = record("Monkey 0:\n  Starting items: 79, 98\n\n  Test: divisible by 23").unwrap();
+        assert_eq!(rec["Starting items"], "79, 98");
+        assert_eq!(rec["Monkey 0"], "");
+        assert_eq!(rec.len(), 3);
+        let err = record("a: 1\nb").unwrap_err();
+        assert_eq!(err.line, 2);
+    }
+
+    #[test]
+    fn parse_pattern() {
+        let pattern = Pattern::new("Sensor at x={}, y={}: closest beacon is at x={}, y={}");
+        let line = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
+        assert_eq!(pattern.captures(line), Some(vec!["2", "18", "-2", "15"]));
+        let values: (i32, i32, i32, i32) = pattern.parse(line).unwrap();
+        assert_eq!(values, (2, 18, -2, 15));
+        let values: Vec<i64> = pattern.parse(line).unwrap();
+        assert_eq!(values, vec![2, 18, -2, 15]);
+
+        let pattern = Pattern::new("{} -> {}");
+        let (name, targets): (String, String) = pattern.parse("broadcaster -> a, b").unwrap();
+        assert_eq!((name.as_str(), targets.as_str()), ("broadcaster", "a, b"));
+        assert!(pattern.captures("a -").is_none());
+
+        let pattern = Pattern::new("abc");
+        assert_eq!(pattern.captures("abc"), Some(vec![]));
+        assert!(pattern.captures("abcdef").is_none());
+    }
+
+    #[test]
+    fn parse_pattern_errors() {
+        let pattern = Pattern::new("move {} from {} to {}");
+        let lines = [
+            "move 1 from 2 to 1",
+            "",
+            "move 3 from 1 to 3",
+            "move x from 1 to 3",
+        ];
+        let err = pattern
+            .parse_lines::<(u32, u32, u32), _>(&lines)
+            .unwrap_err();
+        assert_eq!(err.line, 4);
+        assert_eq!(err.kind, ParseErrorKind::InvalidValue("x".to_string()));
+        assert_eq!(err.to_string(), "line 4: invalid value 'x'");
+        let err = pattern
+            .parse::<(u32, u32)>("move 1 from 2 to 1")
+            .unwrap_err();
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::CaptureCount {
+                expected: 2,
+                found: 3
+            }
+        );
+        let err = pattern
+            .parse::<(u32, u32, u32)>("put 1 from 2 to 1")
+            .unwrap_err();
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::Mismatch("put 1 from 2 to 1".to_string())
+        );
+        let parsed = pattern
+            .parse_lines::<(u32, u32, u32), _>(&lines[..3])
+            .unwrap();
+        assert_eq!(parsed, vec![(1, 2, 1), (3, 1, 3)]);
+    }
+}
@@ -0,0 +1,150 @@
+//
+// Ordered ways to split n into k parts not less than the minimum,
+// in lexicographic order
+//
+pub struct Compositions {
+    parts: Vec<usize>,
+    min: usize,
+    is_first: bool,
+    is_done: bool,
+}
+
+impl Compositions {
+    /// Parts are positive
+    pub fn new(n: usize, k: usize) -> Self {
+        Self::with_min(n, k, 1)
+    }
+
+    pub fn with_min(n: usize, k: usize, min: usize) -> Self {
+        let is_done = if k == 0 { n != 0 } else { n < k * min };
+        let mut parts = vec![min; k];
+        if let Some(last) = parts.last_mut().filter(|_| !is_done) {
+            *last = n - (k - 1) * min;
+        }
+        Self {
+            parts,
+            min,
+            is_first: true,
+            is_done,
+        }
+    }
+}
+
+impl Iterator for Compositions {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+        if self.is_first {
+            self.is_first = false;
+            return Some(self.parts.clone());
+        }
+        let k = self.parts.len();
+        let mut suffix = self.parts.last().copied().unwrap_or_default();
+        for i in (0..k.saturating_sub(1)).rev() {
+            // one unit moves to the i-th part, the rest keep the minimum
+            if suffix > (k - 1 - i) * self.min {
+                self.parts[i] += 1;
+                self.parts[i + 1..k - 1].fill(self.min);
+                self.parts[k - 1] = suffix - 1 - (k - 2 - i) * self.min;
+                return Some(self.parts.clone());
+            }
+            suffix += self.parts[i];
+        }
+        self.is_done = true;
+        None
+    }
+}
+
+//
+// Splits of n into k positive parts regardless of the order,
+// each one is yielded in non-decreasing order
+//
+pub struct Partitions {
+    parts: Vec<usize>,
+    is_first: bool,
+    is_done: bool,
+}
+
+impl Partitions {
+    pub fn new(n: usize, k: usize) -> Self {
+        let is_done = if k == 0 { n != 0 } else { n < k };
+        let mut parts = vec![1; k];
+        if let Some(last) = parts.last_mut().filter(|_| !is_done) {
+            *last = n + 1 - k;
+        }
+        Self {
+            parts,
+            is_first: true,
+            is_done,
+        }
+    }
+}
+
+impl Iterator for Partitions {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+        if self.is_first {
+            self.is_first = false;
+            return Some(self.parts.clone());
+        }
+        let k = self.parts.len();
+        let mut total = self.parts.last().copied().unwrap_or_default();
+        for i in (0..k.saturating_sub(1)).rev() {
+            total += self.parts[i];
+            // parts from i on get the increased value, the last one takes the rest
+            let value = self.parts[i] + 1;
+            if total >= value * (k - i) {
+                self.parts[i..k - 1].fill(value);
+                self.parts[k - 1] = total - value * (k - 1 - i);
+                return Some(self.parts.clone());
+            }
+        }
+        self.is_done = true;
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utils_compositions() {
+        let all = Compositions::new(5, 3).collect::<Vec<_>>();
+        let expected = [
+            [1, 1, 3],
+            [1, 2, 2],
+            [1, 3, 1],
+            [2, 1, 2],
+            [2, 2, 1],
+            [3, 1, 1],
+        ];
+        assert_eq!(all, expected.map(|x| x.to_vec()).to_vec());
+        assert_eq!(Compositions::with_min(3, 2, 0).count(), 4);
+        assert_eq!(Compositions::new(100, 4).count(), 156849);
+        assert_eq!(Compositions::new(2, 3).count(), 0);
+        assert_eq!(Compositions::new(7, 1).collect::<Vec<_>>(), vec![vec![7]]);
+        assert_eq!(Compositions::new(0, 0).count(), 1);
+        assert_eq!(Compositions::new(1, 0).count(), 0);
+    }
+
+    #[test]
+    fn utils_partitions() {
+        let all = Partitions::new(8, 3).collect::<Vec<_>>();
+        let expected = [[1, 1, 6], [1, 2, 5], [1, 3, 4], [2, 2, 4], [2, 3, 3]];
+        assert_eq!(all, expected.map(|x| x.to_vec()).to_vec());
+        let total = (1..=10)
+            .map(|k| Partitions::new(10, k).count())
+            .sum::<usize>();
+        assert_eq!(total, 42);
+        assert_eq!(Partitions::new(2, 3).count(), 0);
+        assert_eq!(Partitions::new(0, 0).count(), 1);
+    }
+}
@@ -8,7 +8,7 @@ pub struct PermutationIterator<T> {
     i: usize,      // stack pointer
 }
 
-impl<T: Copy> PermutationIterator<T> {
+impl<T: Clone> PermutationIterator<T> {
     pub fn from_array(array: &[T]) -> Self {
         Self {
             a: array.to_owned(),
@@ -18,7 +18,7 @@ impl<T: Copy> PermutationIterator<T> {
     }
 }
 
-impl<T: Copy> Iterator for PermutationIterator<T> {
+impl<T: Clone> Iterator for PermutationIterator<T> {
     type Item = Vec<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -45,14 +45,127 @@ impl<T: Copy> Iterator for PermutationIterator<T> {
     }
 }
 
+/// Rearranges the items into the lexicographically next permutation in place,
+/// returns `false` and restores the sorted order after the last one
+pub fn next_permutation<T: Ord>(arr: &mut [T]) -> bool {
+    let Some(i) = (1..arr.len()).rev().find(|i| arr[i - 1] < arr[*i]) else {
+        arr.reverse();
+        return false;
+    };
+    let j = (i..arr.len())
+        .rev()
+        .find(|j| arr[i - 1] < arr[*j])
+        .expect("Successor must exist");
+    arr.swap(i - 1, j);
+    arr[i..].reverse();
+    true
+}
+
+//
+// Distinct permutations of the items with repetitions in lexicographic order
+//
+pub struct MultisetPermutations<T> {
+    a: Vec<T>,
+    is_first: bool,
+}
+
+impl<T: Ord + Clone> MultisetPermutations<T> {
+    pub fn from_array(array: &[T]) -> Self {
+        let mut a = array.to_vec();
+        a.sort();
+        Self { a, is_first: true }
+    }
+}
+
+impl<T: Ord + Clone> Iterator for MultisetPermutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_first {
+            self.is_first = false;
+        } else if !next_permutation(&mut self.a) {
+            return None;
+        }
+        Some(self.a.clone())
+    }
+}
+
+//
+// Ordered selections of k items out of n, the same algorithm as
+// Python's itertools.permutations
+//
+pub struct KPermutations<'a, T> {
+    array: &'a [T],
+    indices: Vec<usize>,
+    cycles: Vec<usize>,
+    k: usize,
+    is_first: bool,
+    is_done: bool,
+}
+
+impl<'a, T: Clone> KPermutations<'a, T> {
+    pub fn from_array(array: &'a [T], k: usize) -> Self {
+        let n = array.len();
+        Self {
+            array,
+            indices: (0..n).collect(),
+            cycles: (0..k.min(n)).map(|i| n - i).collect(),
+            k,
+            is_first: true,
+            is_done: k > n,
+        }
+    }
+
+    fn get_permutation(&self) -> Vec<T> {
+        self.indices[..self.k]
+            .iter()
+            .map(|i| self.array[*i].clone())
+            .collect()
+    }
+}
+
+impl<T: Clone> Iterator for KPermutations<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+        if self.is_first {
+            self.is_first = false;
+            return Some(self.get_permutation());
+        }
+        let n = self.indices.len();
+        for i in (0..self.k).rev() {
+            self.cycles[i] -= 1;
+            if self.cycles[i] == 0 {
+                self.indices[i..].rotate_left(1);
+                self.cycles[i] = n - i;
+            } else {
+                let j = n - self.cycles[i];
+                self.indices.swap(i, j);
+                return Some(self.get_permutation());
+            }
+        }
+        self.is_done = true;
+        None
+    }
+}
+
 pub trait Permutable<T> {
     fn permut_iter(&self) -> PermutationIterator<T>;
+
+    fn k_permut_iter(&self, k: usize) -> KPermutations<'_, T>;
 }
 
-impl<T: Copy> Permutable<T> for Vec<T> {
+impl<T: Clone> Permutable<T> for [T] {
     fn permut_iter(&self) -> PermutationIterator<T> {
         PermutationIterator::from_array(self)
     }
+
+    fn k_permut_iter(&self, k: usize) -> KPermutations<'_, T> {
+        KPermutations::from_array(self, k)
+    }
 }
 
 #[cfg(test)]
@@ -98,4 +211,36 @@ mod tests {
             assert!(set.contains(&v));
         }
     }
+
+    #[test]
+    fn utils_permutations_non_copy() {
+        let names = ["a".to_string(), "b".to_string(), "c".to_string()];
+        assert_eq!(names.permut_iter().count(), 6);
+        let perms = names.k_permut_iter(2).collect::<Vec<_>>();
+        assert_eq!(perms.len(), 6);
+        assert_eq!(perms[0], vec!["a", "b"]);
+        assert_eq!(perms[5], vec!["c", "b"]);
+        assert_eq!(
+            names.k_permut_iter(0).collect::<Vec<_>>(),
+            vec![Vec::<String>::new()]
+        );
+        assert_eq!(names.k_permut_iter(4).count(), 0);
+        assert_eq!([1, 2, 3, 4, 5].k_permut_iter(3).count(), 60);
+    }
+
+    #[test]
+    fn utils_permutations_multiset() {
+        let perms = MultisetPermutations::from_array(&[2, 1, 1]).collect::<Vec<_>>();
+        assert_eq!(perms, vec![vec![1, 1, 2], vec![1, 2, 1], vec![2, 1, 1]]);
+        assert_eq!(
+            MultisetPermutations::from_array(&[1, 1, 2, 2, 3]).count(),
+            30
+        );
+        let mut arr = [1, 3, 2];
+        assert!(next_permutation(&mut arr));
+        assert_eq!(arr, [2, 1, 3]);
+        let mut arr = [3, 2, 1];
+        assert!(!next_permutation(&mut arr));
+        assert_eq!(arr, [1, 2, 3]);
+    }
 }
@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PlainInterval<T> {
     pub begin: T,
     pub end: T,
@@ -43,23 +43,6 @@ where
         })
     }
 
-    pub fn union(&self, other: &Self) -> Option<Self> {
-        let (l, r) = if self.begin < other.begin {
-            (self, other)
-        } else {
-            (other, self)
-        };
-
-        if r.begin > l.end {
-            return None;
-        }
-
-        Some(PlainInterval {
-            begin: self.begin.min(other.begin),
-            end: self.end.max(other.end),
-        })
-    }
-
     pub fn custom_contain(&self, value: T, include_begin: bool, include_end: bool) -> bool {
         ((self.begin < value) || include_begin && (self.begin == value))
             && ((self.end > value) || include_end && (self.end == value))
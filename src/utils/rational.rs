@@ -0,0 +1,259 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use super::{checked_ops::CheckedOps, gcd};
+
+type Int = i128;
+
+/// Exact fraction kept in the lowest terms with the positive denominator,
+/// `i128::MIN` is excluded from the range to keep negation safe.
+/// Arithmetic operators panic on overflow, `checked_*` methods return `None` instead
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: Int,
+    den: Int,
+}
+
+impl Rational {
+    pub const ZERO: Self = Self { num: 0, den: 1 };
+    pub const ONE: Self = Self { num: 1, den: 1 };
+
+    pub fn new(num: Int, den: Int) -> Self {
+        assert_ne!(den, 0, "Zero denominator");
+        Self::checked_new(num, den).expect("Rational overflow")
+    }
+
+    /// Returns `None` on zero denominator or overflow
+    pub fn checked_new(num: Int, den: Int) -> Option<Self> {
+        if den == 0 || num == Int::MIN || den == Int::MIN {
+            return None;
+        }
+        let g = gcd(num, den) * den.signum();
+        Some(Self {
+            num: num / g,
+            den: den / g,
+        })
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den == 1
+    }
+
+    pub fn to_integer(self) -> Option<Int> {
+        self.is_integer().then_some(self.num)
+    }
+
+    pub fn floor(&self) -> Int {
+        self.num.div_euclid(self.den)
+    }
+
+    pub fn ceil(&self) -> Int {
+        -(-self.num).div_euclid(self.den)
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.num as f64 / self.den as f64
+    }
+
+    pub fn abs(&self) -> Self {
+        if self.num < 0 {
+            -*self
+        } else {
+            *self
+        }
+    }
+
+    pub fn checked_recip(&self) -> Option<Self> {
+        Self::checked_new(self.den, self.num)
+    }
+}
+
+impl CheckedOps for Rational {
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        let g = gcd(self.den, rhs.den);
+        let num = self
+            .num
+            .checked_mul(rhs.den / g)?
+            .checked_add(rhs.num.checked_mul(self.den / g)?)?;
+        Self::checked_new(num, (self.den / g).checked_mul(rhs.den)?)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(-rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // cross reduction keeps the intermediate values small
+        let g1 = gcd(self.num, rhs.den);
+        let g2 = gcd(rhs.num, self.den);
+        let num = (self.num / g1).checked_mul(rhs.num / g2)?;
+        let den = (self.den / g2).checked_mul(rhs.den / g1)?;
+        Self::checked_new(num, den)
+    }
+
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.checked_recip()?)
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("Rational overflow")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).expect("Rational overflow")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs).expect("Rational overflow")
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        assert!(!rhs.is_zero(), "Division by zero");
+        self.checked_div(rhs).expect("Rational overflow")
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // compares the continued fraction expansions, so nothing can overflow
+        let (mut a, mut b) = ((self.num, self.den), (other.num, other.den));
+        let mut is_reversed = false;
+        let ordering = loop {
+            let (qa, qb) = (a.0.div_euclid(a.1), b.0.div_euclid(b.1));
+            if qa != qb {
+                break qa.cmp(&qb);
+            }
+            let (ra, rb) = (a.0.rem_euclid(a.1), b.0.rem_euclid(b.1));
+            if ra == 0 || rb == 0 {
+                break ra.cmp(&rb);
+            }
+            // the fractional parts compare in the reverse order of their reciprocals
+            (a, b) = ((a.1, ra), (b.1, rb));
+            is_reversed = !is_reversed;
+        };
+        if is_reversed {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Rational {
+            fn from(value: $t) -> Self {
+                Self::new(value as Int, 1)
+            }
+        })*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rational_normalization() {
+        let x = Rational::new(6, -4);
+        assert_eq!((x.num, x.den), (-3, 2));
+        assert_eq!(Rational::new(0, -5), Rational::ZERO);
+        assert_eq!(x.to_string(), "-3/2");
+        assert_eq!(Rational::from(7).to_string(), "7");
+        assert_eq!((x.floor(), x.ceil()), (-2, -1));
+        assert!(Rational::checked_new(1, 0).is_none());
+        assert!(Rational::checked_new(i128::MIN, -1).is_none());
+        assert_eq!(Rational::new(i128::MAX, -1).num, -i128::MAX);
+    }
+
+    #[test]
+    fn rational_arithmetic() {
+        let a = Rational::new(1, 3);
+        let b = Rational::new(1, 6);
+        assert_eq!(a + b, Rational::new(1, 2));
+        assert_eq!(a - b, b);
+        assert_eq!(a * b, Rational::new(1, 18));
+        assert_eq!(a / b, Rational::from(2));
+        assert_eq!(-a, Rational::new(-1, 3));
+        assert_eq!((Rational::ONE / b).to_integer(), Some(6));
+        assert_eq!(a.to_integer(), None);
+        assert!(Rational::ZERO.checked_recip().is_none());
+        let big = Rational::from(i128::MAX);
+        assert!(big.checked_add(Rational::ONE).is_none());
+        assert_eq!(
+            big.checked_mul(Rational::new(1, i128::MAX)),
+            Some(Rational::ONE)
+        );
+    }
+
+    #[test]
+    fn rational_ordering() {
+        let mut values = [
+            Rational::new(2, 3),
+            Rational::new(-1, 2),
+            Rational::new(3, 5),
+            Rational::from(1),
+            Rational::new(-2, 3),
+            Rational::new(5, 8),
+        ];
+        values.sort();
+        let expected = ["-2/3", "-1/2", "3/5", "5/8", "2/3", "1"];
+        assert_eq!(values.map(|x| x.to_string()), expected);
+        let a = Rational::new(i128::MAX - 1, i128::MAX);
+        let b = Rational::new(i128::MAX - 2, i128::MAX - 1);
+        assert!(a > b);
+        assert_eq!(a.cmp(&a), Ordering::Equal);
+    }
+}
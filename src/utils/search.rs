@@ -0,0 +1,465 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Path cost, `Default` value is used as zero
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T> Cost for T where T: Copy + Ord + Add<Output = T> + Default {}
+
+/// Distances from the start state and the single predecessor of each visited state
+#[derive(Debug, Clone)]
+pub struct SearchResult<S, C> {
+    distances: HashMap<S, C>,
+    predecessors: HashMap<S, S>,
+}
+
+impl<S, C> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy,
+{
+    fn with_starts(starts: impl IntoIterator<Item = S>, zero: C) -> Self {
+        let distances = starts.into_iter().map(|s| (s, zero)).collect();
+        Self {
+            distances,
+            predecessors: HashMap::new(),
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn is_reached(&self, state: &S) -> bool {
+        self.distances.contains_key(state)
+    }
+
+    /// Path from the start state to the target including both ends
+    pub fn path_to(&self, target: &S) -> Option<Vec<S>> {
+        if !self.is_reached(target) {
+            return None;
+        }
+        let mut path = vec![target.clone()];
+        let mut current = target;
+        while let Some(prev) = self.predecessors.get(current) {
+            path.push(prev.clone());
+            current = prev;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Found path and its cost
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchPath<S, C> {
+    pub path: Vec<S>,
+    pub cost: C,
+}
+
+/// Breadth-first traversal of all states reachable from the start states
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+) -> SearchResult<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    bfs_until(starts, &mut neighbours, |_| false).0
+}
+
+/// Breadth-first search of the nearest state that satisfies the goal predicate
+pub fn bfs_path<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    is_goal: impl Fn(&S) -> bool,
+) -> Option<SearchPath<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let (result, target) = bfs_until(starts, &mut neighbours, is_goal);
+    let target = target?;
+    Some(SearchPath {
+        cost: result.distance(&target)?,
+        path: result.path_to(&target)?,
+    })
+}
+
+fn bfs_until<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: &mut impl FnMut(&S) -> I,
+    is_goal: impl Fn(&S) -> bool,
+) -> (SearchResult<S, usize>, Option<S>)
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut result = SearchResult::with_starts(starts, 0);
+    let mut deque = result.distances.keys().cloned().collect::<VecDeque<_>>();
+    while let Some(state) = deque.pop_front() {
+        if is_goal(&state) {
+            return (result, Some(state));
+        }
+        let distance = result.distances[&state];
+        for next in neighbours(&state) {
+            if result.is_reached(&next) {
+                continue;
+            }
+            result.distances.insert(next.clone(), distance + 1);
+            result.predecessors.insert(next.clone(), state.clone());
+            deque.push_back(next);
+        }
+    }
+    (result, None)
+}
+
+/// Min-heap entry ordered by the priority only, so the state doesn't need to implement `Ord`
+struct HeapItem<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for HeapItem<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for HeapItem<S, C> {}
+
+impl<S, C: Ord> PartialOrd for HeapItem<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for HeapItem<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Dijkstra search of the cheapest state that satisfies the goal predicate
+pub fn dijkstra_path<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl Fn(&S) -> bool,
+) -> Option<SearchPath<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search, the heuristic must not overestimate the remaining cost to the goal.
+/// The state is expanded again when a cheaper path to it turns up,
+/// so the heuristic doesn't have to be consistent
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    heuristic: impl Fn(&S) -> C,
+    is_goal: impl Fn(&S) -> bool,
+) -> Option<SearchPath<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (result, target) = best_first(starts, &mut neighbours, heuristic, is_goal);
+    let target = target?;
+    Some(SearchPath {
+        cost: result.distance(&target)?,
+        path: result.path_to(&target)?,
+    })
+}
+
+fn best_first<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: &mut impl FnMut(&S) -> I,
+    heuristic: impl Fn(&S) -> C,
+    is_goal: impl Fn(&S) -> bool,
+) -> (SearchResult<S, C>, Option<S>)
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let zero = C::default();
+    let mut result = SearchResult::with_starts(starts, zero);
+    let mut heap = result
+        .distances
+        .keys()
+        .map(|state| HeapItem {
+            priority: heuristic(state),
+            cost: zero,
+            state: state.clone(),
+        })
+        .collect::<BinaryHeap<_>>();
+    while let Some(HeapItem { cost, state, .. }) = heap.pop() {
+        // outdated entry, the state was pushed again with the lower cost
+        if result
+            .distances
+            .get(&state)
+            .is_some_and(|best| *best < cost)
+        {
+            continue;
+        }
+        if is_goal(&state) {
+            return (result, Some(state));
+        }
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            if result
+                .distances
+                .get(&next)
+                .is_some_and(|best| *best <= next_cost)
+            {
+                continue;
+            }
+            result.distances.insert(next.clone(), next_cost);
+            result.predecessors.insert(next.clone(), state.clone());
+            heap.push(HeapItem {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                state: next,
+            });
+        }
+    }
+    (result, None)
+}
+
+/// Distances from the start states and all predecessors lying on the shortest paths
+#[derive(Debug, Clone)]
+pub struct AllShortestPaths<S, C> {
+    distances: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S, C> AllShortestPaths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy,
+{
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors
+            .get(state)
+            .map(|arr| arr.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// All states that belong to any shortest path ending at the target
+    pub fn states_on_paths(&self, target: &S) -> HashSet<S> {
+        let mut states = HashSet::new();
+        if !self.distances.contains_key(target) {
+            return states;
+        }
+        let mut stack = vec![target.clone()];
+        while let Some(state) = stack.pop() {
+            if !states.insert(state.clone()) {
+                continue;
+            }
+            stack.extend(self.predecessors(&state).iter().cloned());
+        }
+        states
+    }
+}
+
+/// Dijkstra traversal that keeps every predecessor with the equal best cost,
+/// so the result describes the DAG of all shortest paths
+pub fn dijkstra_all<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+) -> AllShortestPaths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let zero = C::default();
+    let mut distances = starts
+        .into_iter()
+        .map(|s| (s, zero))
+        .collect::<HashMap<_, _>>();
+    let mut predecessors = HashMap::<S, Vec<S>>::new();
+    let mut heap = distances
+        .keys()
+        .map(|state| HeapItem {
+            priority: zero,
+            cost: zero,
+            state: state.clone(),
+        })
+        .collect::<BinaryHeap<_>>();
+    let mut closed = HashSet::new();
+    while let Some(HeapItem { cost, state, .. }) = heap.pop() {
+        if distances.get(&state).is_some_and(|best| *best < cost) {
+            continue;
+        }
+        if !closed.insert(state.clone()) {
+            continue;
+        }
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            match distances.get(&next).map(|best| next_cost.cmp(best)) {
+                Some(Ordering::Greater) => continue,
+                Some(Ordering::Equal) => {
+                    predecessors.entry(next).or_default().push(state.clone());
+                }
+                _ => {
+                    distances.insert(next.clone(), next_cost);
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+                    heap.push(HeapItem {
+                        priority: next_cost,
+                        cost: next_cost,
+                        state: next,
+                    });
+                }
+            }
+        }
+    }
+    AllShortestPaths {
+        distances,
+        predecessors,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MAZE: [&str; 5] = [
+        "S..#....", //
+        ".#.#.##.", //
+        ".#...#..", //
+        ".####.#.", //
+        "......#E",
+    ];
+
+    type Position = (usize, usize);
+
+    fn maze_neighbours(pos: &Position) -> Vec<Position> {
+        let (r, c) = *pos;
+        let mut result = Vec::new();
+        if r > 0 {
+            result.push((r - 1, c));
+        }
+        if c > 0 {
+            result.push((r, c - 1));
+        }
+        result.push((r + 1, c));
+        result.push((r, c + 1));
+        result
+            .into_iter()
+            .filter(|(r, c)| {
+                MAZE.get(*r)
+                    .and_then(|row| row.as_bytes().get(*c))
+                    .is_some_and(|ch| *ch != b'#')
+            })
+            .collect()
+    }
+
+    fn weighted_graph(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 7), ('c', 9), ('f', 14)],
+            'b' => vec![('a', 7), ('c', 10), ('d', 15)],
+            'c' => vec![('a', 9), ('b', 10), ('d', 11), ('f', 2)],
+            'd' => vec![('b', 15), ('c', 11), ('e', 6)],
+            'e' => vec![('d', 6), ('f', 9)],
+            'f' => vec![('a', 14), ('c', 2), ('e', 9)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn search_bfs_distances() {
+        let result = bfs([(0, 0)], maze_neighbours);
+        assert_eq!(result.distance(&(4, 7)), Some(15));
+        assert_eq!(result.distance(&(0, 0)), Some(0));
+        assert_eq!(result.distance(&(0, 3)), None);
+        let path = result.path_to(&(4, 7)).unwrap();
+        assert_eq!(path.len(), 16);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(4, 7)));
+        assert!(result.path_to(&(0, 3)).is_none());
+    }
+
+    #[test]
+    fn search_bfs_path() {
+        let found = bfs_path([(0, 0)], maze_neighbours, |p| *p == (4, 7)).unwrap();
+        assert_eq!(found.cost, 15);
+        assert_eq!(found.path.last(), Some(&(4, 7)));
+        assert!(bfs_path([(0, 0)], maze_neighbours, |p| *p == (0, 3)).is_none());
+
+        // multiple starts
+        let found = bfs_path([(0, 0), (4, 5)], maze_neighbours, |p| *p == (3, 5)).unwrap();
+        assert_eq!(found.path, vec![(4, 5), (3, 5)]);
+    }
+
+    #[test]
+    fn search_dijkstra() {
+        let found = dijkstra_path(['a'], weighted_graph, |n| *n == 'd').unwrap();
+        assert_eq!(found.cost, 20);
+
+        let found = dijkstra_path(['a'], weighted_graph, |n| *n == 'e').unwrap();
+        assert_eq!(found.cost, 20);
+        assert_eq!(found.path, vec!['a', 'c', 'f', 'e']);
+        assert!(dijkstra_path(['a'], weighted_graph, |n| *n == 'z').is_none());
+    }
+
+    #[test]
+    fn search_astar() {
+        let target = (4usize, 7usize);
+        let heuristic = |p: &Position| p.0.abs_diff(target.0) + p.1.abs_diff(target.1);
+        let weighted = |p: &Position| {
+            maze_neighbours(p)
+                .into_iter()
+                .map(|x| (x, 1usize))
+                .collect::<Vec<_>>()
+        };
+        let found = astar([(0, 0)], weighted, heuristic, |p| *p == target).unwrap();
+        assert_eq!(found.cost, 15);
+        assert_eq!(found.path.len(), 16);
+
+        // admissible but inconsistent heuristic makes 'c' reached again cheaper
+        let graph = |n: &char| match n {
+            's' => vec![('a', 1), ('c', 3)],
+            'a' => vec![('c', 1)],
+            'c' => vec![('g', 3)],
+            _ => vec![],
+        };
+        let heuristic = |n: &char| if *n == 'a' { 4 } else { 0 };
+        let found = astar(['s'], graph, heuristic, |n| *n == 'g').unwrap();
+        assert_eq!(found.cost, 5);
+        assert_eq!(found.path, vec!['s', 'a', 'c', 'g']);
+    }
+
+    #[test]
+    fn search_all_shortest_paths() {
+        // diamond: two equal paths from 0 to 3 and a longer one via 4
+        let graph = |n: &u8| -> Vec<(u8, u32)> {
+            match n {
+                0 => vec![(1, 1), (2, 1), (4, 1)],
+                1 => vec![(3, 1)],
+                2 => vec![(3, 1)],
+                4 => vec![(3, 5)],
+                _ => vec![],
+            }
+        };
+        let paths = dijkstra_all([0], graph);
+        assert_eq!(paths.distance(&3), Some(2));
+        assert_eq!(paths.states_on_paths(&3), HashSet::from([0, 1, 2, 3]));
+        assert!(paths.states_on_paths(&7).is_empty());
+    }
+}
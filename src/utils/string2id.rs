@@ -1,27 +1,156 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, hash::Hash, ops::Index};
 
-pub struct String2IdMapper {
-    map: HashMap<String, usize>,
+/// Dense index given to the interned string, suitable for `Vec`-based storage
+pub trait InternId: Copy + Eq + Hash {
+    fn from_index(index: usize) -> Self;
+
+    fn index(self) -> usize;
+}
+
+impl InternId for usize {
+    fn from_index(index: usize) -> Self {
+        index
+    }
+
+    fn index(self) -> usize {
+        self
+    }
+}
+
+/// Declares the newtype ID, e.g. `intern_id!(ValveId);`
+macro_rules! intern_id {
+    ($name:ident) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        struct $name(usize);
+
+        impl $crate::utils::string2id::InternId for $name {
+            fn from_index(index: usize) -> Self {
+                Self(index)
+            }
+
+            fn index(self) -> usize {
+                self.0
+            }
+        }
+    };
+}
+
+pub(crate) use intern_id;
+
+/// Assigns the sequential ids to the strings and resolves them back.
+/// Once frozen, the unknown strings are not accepted anymore
+pub struct String2IdMapper<I = usize> {
+    map: HashMap<String, I>,
+    names: Vec<String>,
+    is_frozen: bool,
 }
 
 impl String2IdMapper {
     pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<I> Default for String2IdMapper<I> {
+    fn default() -> Self {
         Self {
             map: HashMap::new(),
+            names: Vec::new(),
+            is_frozen: false,
         }
     }
+}
 
+impl<I: InternId> String2IdMapper<I> {
     pub fn len(&self) -> usize {
-        self.map.len()
+        self.names.len()
     }
 
-    pub fn get_id(&mut self, s: &str) -> usize {
-        let next_id = self.map.len();
+    /// Returns the id of the string adding it if needed,
+    /// panics on the new string when the mapper is frozen
+    pub fn get_id(&mut self, s: &str) -> I {
         if let Some(id) = self.map.get(s) {
-            *id
-        } else {
-            self.map.insert(s.to_string(), next_id);
-            next_id
+            return *id;
         }
+        assert!(!self.is_frozen, "Unknown string '{s}' after freezing");
+        let id = I::from_index(self.names.len());
+        self.map.insert(s.to_string(), id);
+        self.names.push(s.to_string());
+        id
+    }
+
+    /// Lookup without inserting
+    pub fn get(&self, s: &str) -> Option<I> {
+        self.map.get(s).copied()
+    }
+
+    pub fn name(&self, id: I) -> &str {
+        &self.names[id.index()]
+    }
+
+    pub fn freeze(&mut self) {
+        self.is_frozen = true;
+    }
+
+    /// Ids with their strings in the order of insertion
+    pub fn iter(&self) -> impl Iterator<Item = (I, &str)> {
+        self.names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (I::from_index(i), name.as_str()))
+    }
+}
+
+impl<I: InternId> Index<I> for String2IdMapper<I> {
+    type Output = str;
+
+    fn index(&self, id: I) -> &Self::Output {
+        self.name(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    intern_id!(NodeId);
+
+    #[test]
+    fn string2id_lookup() {
+        let mut mapper = String2IdMapper::new();
+        assert_eq!(mapper.get_id("AA"), 0);
+        assert_eq!(mapper.get_id("BB"), 1);
+        assert_eq!(mapper.get_id("AA"), 0);
+        assert_eq!(mapper.len(), 2);
+        assert_eq!(mapper.get("BB"), Some(1));
+        assert_eq!(mapper.get("CC"), None);
+        assert_eq!(mapper.name(1), "BB");
+        assert_eq!(&mapper[0], "AA");
+        assert_eq!(
+            mapper.iter().collect::<Vec<_>>(),
+            vec![(0, "AA"), (1, "BB")]
+        );
+    }
+
+    #[test]
+    fn string2id_typed_ids() {
+        let mut mapper = String2IdMapper::<NodeId>::default();
+        let a = mapper.get_id("a");
+        let b = mapper.get_id("b");
+        assert_eq!((a, b), (NodeId(0), NodeId(1)));
+        let mut adjacency = vec![Vec::new(); mapper.len()];
+        adjacency[a.index()].push(b);
+        assert_eq!(mapper.name(adjacency[0][0]), "b");
+        mapper.freeze();
+        assert_eq!(mapper.get_id("a"), a);
+    }
+
+    #[test]
+    #[should_panic(expected = "after freezing")]
+    fn string2id_frozen() {
+        let mut mapper = String2IdMapper::new();
+        mapper.get_id("a");
+        mapper.freeze();
+        mapper.get_id("b");
     }
 }